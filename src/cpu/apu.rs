@@ -0,0 +1,837 @@
+//! Audio processing unit
+//!
+//! Emulates the four sound channels: two square waves (the first one
+//! with a frequency sweep), a programmable wave and noise.  The APU is
+//! clocked by the CPU and produces stereo samples at `APU_SAMPLE_RATE`
+//! which the frontend has to pick up with `Apu::take_samples`.  It
+//! does not depend on any audio library.
+
+use std::cmp;
+
+use cpu::Cpu;
+use cpu::constants::*;
+use cpu::savestate::*;
+
+/// Samples per second per channel produced by the APU
+pub const APU_SAMPLE_RATE: u32 = 44100;
+/// Samples (left and right interleaved) kept if nobody takes them
+const MAX_BUFFERED_SAMPLES: usize = APU_SAMPLE_RATE as usize * 2;
+
+const CPU_FREQUENCY: u32 = 4194304;
+/// The frame sequencer runs at 512Hz
+const FRAME_SEQUENCER_PERIOD: u32 = CPU_FREQUENCY / 512;
+
+pub const SOUND_REGISTERS_START: usize = 0xFF10;
+pub const SOUND_REGISTERS_END: usize = 0xFF3F;
+pub const NR50_ADDR: usize = 0xFF24;
+pub const NR51_ADDR: usize = 0xFF25;
+pub const NR52_ADDR: usize = 0xFF26;
+pub const WAVE_RAM_START: usize = 0xFF30;
+
+/// Values the boot ROM leaves in NR10-NR51
+pub const SOUND_REGISTERS_AFTER_BOOT: [(usize, byte); 17] = [
+    (0xFF10, 0x80), (0xFF11, 0xBF), (0xFF12, 0xF3), (0xFF14, 0xBF),
+    (0xFF16, 0x3F), (0xFF17, 0x00), (0xFF19, 0xBF),
+    (0xFF1A, 0x7F), (0xFF1B, 0xFF), (0xFF1C, 0x9F), (0xFF1E, 0xBF),
+    (0xFF20, 0xFF), (0xFF21, 0x00), (0xFF22, 0x00), (0xFF23, 0xBF),
+    (0xFF24, 0x77), (0xFF25, 0xF3)];
+
+/// Bits of NR10-NR52 which always read as 1
+const READ_MASKS: [byte; 0x17] = [0x80, 0x3F, 0x00, 0xFF, 0xBF, // NR10-NR14
+                                  0xFF, 0x3F, 0x00, 0xFF, 0xBF, // NR20-NR24
+                                  0x7F, 0xFF, 0x9F, 0xFF, 0xBF, // NR30-NR34
+                                  0xFF, 0xFF, 0x00, 0x00, 0xBF, // NR40-NR44
+                                  0x00, 0x00, 0x70]; // NR50-NR52
+
+const DUTY_PATTERNS: [[u8; 8]; 4] = [[0, 0, 0, 0, 0, 0, 0, 1],
+                                     [1, 0, 0, 0, 0, 0, 0, 1],
+                                     [1, 0, 0, 0, 0, 1, 1, 1],
+                                     [0, 1, 1, 1, 1, 1, 1, 0]];
+
+const NOISE_DIVISORS: [u32; 8] = [8, 16, 32, 48, 64, 80, 96, 112];
+
+/// Turns the channel off when it runs out
+#[derive(Debug, Clone, Default)]
+struct LengthCounter {
+    counter: u16,
+    enabled: bool,
+    /// 64 for all channels but the wave channel, which has 256
+    max: u16,
+}
+
+impl LengthCounter {
+    fn new(max: u16) -> LengthCounter {
+        LengthCounter { max: max, ..Default::default() }
+    }
+
+    fn load(&mut self, value: byte) {
+        self.counter = self.max - (value as u16);
+    }
+
+    fn trigger(&mut self) {
+        if self.counter == 0 {
+            self.counter = self.max;
+        }
+    }
+
+    /// Returns `false` when the channel has to be turned off
+    fn clock(&mut self) -> bool {
+        if self.enabled && self.counter > 0 {
+            self.counter -= 1;
+            return self.counter != 0;
+        }
+        true
+    }
+}
+
+/// Volume envelope of the square and noise channels
+#[derive(Debug, Clone, Default)]
+struct Envelope {
+    initial_volume: u8,
+    increase: bool,
+    period: u8,
+    volume: u8,
+    timer: u8,
+}
+
+impl Envelope {
+    fn write(&mut self, value: byte) {
+        self.initial_volume = value >> 4;
+        self.increase = value & 0x08 != 0;
+        self.period = value & 0x07;
+    }
+
+    fn trigger(&mut self) {
+        self.volume = self.initial_volume;
+        self.timer = self.period;
+    }
+
+    fn clock(&mut self) {
+        if self.period == 0 {
+            return;
+        }
+        if self.timer > 0 {
+            self.timer -= 1;
+        }
+        if self.timer == 0 {
+            self.timer = self.period;
+            if self.increase && self.volume < 15 {
+                self.volume += 1;
+            } else if !self.increase && self.volume > 0 {
+                self.volume -= 1;
+            }
+        }
+    }
+}
+
+/// Frequency sweep of channel 1
+#[derive(Debug, Clone, Default)]
+struct Sweep {
+    period: u8,
+    negate: bool,
+    shift: u8,
+    timer: u8,
+    enabled: bool,
+    shadow_frequency: u16,
+}
+
+impl Sweep {
+    fn write(&mut self, value: byte) {
+        self.period = (value >> 4) & 0x7;
+        self.negate = value & 0x08 != 0;
+        self.shift = value & 0x07;
+    }
+
+    fn reload_timer(&mut self) {
+        // A period of 0 is treated as 8
+        self.timer = if self.period == 0 { 8 } else { self.period };
+    }
+
+    /// The next frequency, `None` if it overflows
+    fn next_frequency(&self) -> Option<u16> {
+        let delta = self.shadow_frequency >> self.shift;
+        let frequency = if self.negate {
+            self.shadow_frequency - delta
+        } else {
+            self.shadow_frequency + delta
+        };
+        if frequency > 2047 { None } else { Some(frequency) }
+    }
+}
+
+#[derive(Debug, Clone, Default)]
+struct SquareChannel {
+    enabled: bool,
+    dac_enabled: bool,
+    duty: u8,
+    duty_position: u8,
+    frequency: u16,
+    timer: u32,
+    length: LengthCounter,
+    envelope: Envelope,
+    /// Only channel 1 has a sweep unit
+    sweep: Option<Sweep>,
+}
+
+impl SquareChannel {
+    fn new(sweep: bool) -> SquareChannel {
+        SquareChannel {
+            length: LengthCounter::new(64),
+            sweep: if sweep { Some(Sweep::default()) } else { None },
+            ..Default::default()
+        }
+    }
+
+    fn write(&mut self, register: usize, value: byte) {
+        match register {
+            0 => {
+                if let Some(ref mut sweep) = self.sweep {
+                    sweep.write(value);
+                }
+            }
+            1 => {
+                self.duty = value >> 6;
+                self.length.load(value & 0x3F);
+            }
+            2 => {
+                self.envelope.write(value);
+                self.dac_enabled = value & 0xF8 != 0;
+                if !self.dac_enabled {
+                    self.enabled = false;
+                }
+            }
+            3 => self.frequency = (self.frequency & 0x700) | (value as u16),
+            _ => {
+                self.frequency = (self.frequency & 0xFF) | (((value & 0x7) as u16) << 8);
+                self.length.enabled = value & 0x40 != 0;
+                if value & 0x80 != 0 {
+                    self.trigger();
+                }
+            }
+        }
+    }
+
+    fn trigger(&mut self) {
+        self.enabled = self.dac_enabled;
+        self.length.trigger();
+        self.timer = (2048 - self.frequency as u32) * 4;
+        self.envelope.trigger();
+
+        let mut overflow = false;
+        if let Some(ref mut sweep) = self.sweep {
+            sweep.shadow_frequency = self.frequency;
+            sweep.reload_timer();
+            sweep.enabled = sweep.period != 0 || sweep.shift != 0;
+            if sweep.shift != 0 {
+                overflow = sweep.next_frequency().is_none();
+            }
+        }
+        if overflow {
+            self.enabled = false;
+        }
+    }
+
+    fn tick(&mut self) {
+        if self.timer > 0 {
+            self.timer -= 1;
+        }
+        if self.timer == 0 {
+            self.timer = (2048 - self.frequency as u32) * 4;
+            self.duty_position = (self.duty_position + 1) & 0x7;
+        }
+    }
+
+    fn clock_sweep(&mut self) {
+        let mut disable = false;
+        if let Some(ref mut sweep) = self.sweep {
+            if sweep.timer > 0 {
+                sweep.timer -= 1;
+            }
+            if sweep.timer == 0 {
+                sweep.reload_timer();
+                if sweep.enabled && sweep.period != 0 {
+                    match sweep.next_frequency() {
+                        Some(frequency) if sweep.shift != 0 => {
+                            sweep.shadow_frequency = frequency;
+                            self.frequency = frequency;
+                            // The new frequency is checked for overflow again
+                            disable = sweep.next_frequency().is_none();
+                        }
+                        Some(_) => (),
+                        None => disable = true,
+                    }
+                }
+            }
+        }
+        if disable {
+            self.enabled = false;
+        }
+    }
+
+    fn output(&self) -> u8 {
+        if self.enabled && DUTY_PATTERNS[self.duty as usize][self.duty_position as usize] == 1 {
+            self.envelope.volume
+        } else {
+            0
+        }
+    }
+}
+
+#[derive(Debug, Clone, Default)]
+struct WaveChannel {
+    enabled: bool,
+    dac_enabled: bool,
+    /// Right shift applied to samples, 4 mutes the channel
+    volume_shift: u8,
+    frequency: u16,
+    timer: u32,
+    /// Which of the 32 4 bit samples is played
+    position: usize,
+    length: LengthCounter,
+}
+
+impl WaveChannel {
+    fn write(&mut self, register: usize, value: byte) {
+        match register {
+            0 => {
+                self.dac_enabled = value & 0x80 != 0;
+                if !self.dac_enabled {
+                    self.enabled = false;
+                }
+            }
+            1 => self.length.load(value),
+            2 => {
+                self.volume_shift = match (value >> 5) & 0x3 {
+                    0 => 4,
+                    1 => 0,
+                    2 => 1,
+                    _ => 2,
+                }
+            }
+            3 => self.frequency = (self.frequency & 0x700) | (value as u16),
+            _ => {
+                self.frequency = (self.frequency & 0xFF) | (((value & 0x7) as u16) << 8);
+                self.length.enabled = value & 0x40 != 0;
+                if value & 0x80 != 0 {
+                    self.enabled = self.dac_enabled;
+                    self.length.trigger();
+                    self.timer = (2048 - self.frequency as u32) * 2;
+                    self.position = 0;
+                }
+            }
+        }
+    }
+
+    fn tick(&mut self) {
+        if self.timer > 0 {
+            self.timer -= 1;
+        }
+        if self.timer == 0 {
+            self.timer = (2048 - self.frequency as u32) * 2;
+            self.position = (self.position + 1) % 32;
+        }
+    }
+
+    fn output(&self, wave_ram: &[byte]) -> u8 {
+        if !self.enabled {
+            return 0;
+        }
+        let sample_byte = wave_ram[self.position / 2];
+        // The upper nibble is played first
+        let sample = if self.position % 2 == 0 {
+            sample_byte >> 4
+        } else {
+            sample_byte & 0xF
+        };
+        sample >> self.volume_shift
+    }
+}
+
+#[derive(Debug, Clone, Default)]
+struct NoiseChannel {
+    enabled: bool,
+    dac_enabled: bool,
+    /// 15 bit linear feedback shift register
+    lfsr: u16,
+    /// Feed back into bit 6 as well, making the noise more tonal
+    width_7: bool,
+    /// Clock shifts of 14 and 15 stop the LFSR
+    frozen: bool,
+    period: u32,
+    timer: u32,
+    length: LengthCounter,
+    envelope: Envelope,
+}
+
+impl NoiseChannel {
+    fn write(&mut self, register: usize, value: byte) {
+        match register {
+            1 => self.length.load(value & 0x3F),
+            2 => {
+                self.envelope.write(value);
+                self.dac_enabled = value & 0xF8 != 0;
+                if !self.dac_enabled {
+                    self.enabled = false;
+                }
+            }
+            3 => {
+                self.period = NOISE_DIVISORS[(value & 0x7) as usize] << (value >> 4);
+                self.width_7 = value & 0x08 != 0;
+                self.frozen = value >> 4 >= 14;
+            }
+            4 => {
+                self.length.enabled = value & 0x40 != 0;
+                if value & 0x80 != 0 {
+                    self.enabled = self.dac_enabled;
+                    self.length.trigger();
+                    self.timer = self.period;
+                    self.envelope.trigger();
+                    self.lfsr = 0x7FFF;
+                }
+            }
+            _ => (),
+        }
+    }
+
+    fn tick(&mut self) {
+        if self.timer > 0 {
+            self.timer -= 1;
+        }
+        if self.timer == 0 {
+            self.timer = self.period;
+            if self.frozen {
+                return;
+            }
+            let feedback = (self.lfsr ^ (self.lfsr >> 1)) & 1;
+            self.lfsr = (self.lfsr >> 1) | (feedback << 14);
+            if self.width_7 {
+                self.lfsr = (self.lfsr & !0x40) | (feedback << 6);
+            }
+        }
+    }
+
+    fn output(&self) -> u8 {
+        if self.enabled && self.lfsr & 1 == 0 {
+            self.envelope.volume
+        } else {
+            0
+        }
+    }
+}
+
+#[derive(Clone)]
+pub struct Apu {
+    /// Raw values of 0xFF10-0xFF3F as last written
+    registers: [byte; 0x30],
+    powered: bool,
+    square1: SquareChannel,
+    square2: SquareChannel,
+    wave: WaveChannel,
+    noise: NoiseChannel,
+    frame_sequencer_timer: u32,
+    frame_sequencer_step: u8,
+    /// Counts up by `APU_SAMPLE_RATE` every cycle
+    sample_timer: u32,
+    /// Stereo samples between -1 and 1, left first
+    samples: Vec<f32>,
+    /// No samples are produced while set
+    muted: bool,
+}
+
+impl Apu {
+    pub fn new() -> Apu {
+        Apu {
+            registers: [0; 0x30],
+            powered: false,
+            square1: SquareChannel::new(true),
+            square2: SquareChannel::new(false),
+            wave: WaveChannel { length: LengthCounter::new(256), ..Default::default() },
+            noise: NoiseChannel { length: LengthCounter::new(64), ..Default::default() },
+            frame_sequencer_timer: FRAME_SEQUENCER_PERIOD,
+            frame_sequencer_step: 0,
+            sample_timer: 0,
+            samples: vec![],
+            muted: false,
+        }
+    }
+
+    /// Raw register values, in the order of the address space
+    pub fn registers(&self) -> &[byte] {
+        &self.registers[..]
+    }
+
+    /// Reads a register as the CPU sees it
+    pub fn read(&self, addr: usize) -> byte {
+        let index = addr - SOUND_REGISTERS_START;
+        match addr {
+            SOUND_REGISTERS_START...NR52_ADDR => self.registers[index] | READ_MASKS[index],
+            WAVE_RAM_START...SOUND_REGISTERS_END => self.registers[index],
+            // Unused
+            _ => 0xFF,
+        }
+    }
+
+    pub fn write(&mut self, addr: usize, value: byte) {
+        let index = addr - SOUND_REGISTERS_START;
+        match addr {
+            NR52_ADDR => {
+                let powered = value & 0x80 != 0;
+                if self.powered && !powered {
+                    self.power_off();
+                } else if !self.powered && powered {
+                    self.frame_sequencer_step = 0;
+                }
+                self.powered = powered;
+            }
+            WAVE_RAM_START...SOUND_REGISTERS_END => self.registers[index] = value,
+            // Everything else is read only while powered off
+            _ if !self.powered => (),
+            0xFF10...0xFF14 => {
+                self.registers[index] = value;
+                self.square1.write(addr - 0xFF10, value);
+            }
+            0xFF15...0xFF19 => {
+                self.registers[index] = value;
+                self.square2.write(addr - 0xFF15, value);
+            }
+            0xFF1A...0xFF1E => {
+                self.registers[index] = value;
+                self.wave.write(addr - 0xFF1A, value);
+            }
+            0xFF1F...0xFF23 => {
+                self.registers[index] = value;
+                self.noise.write(addr - 0xFF1F, value);
+            }
+            NR50_ADDR | NR51_ADDR => self.registers[index] = value,
+            _ => (),
+        }
+        self.update_status();
+    }
+
+    fn power_off(&mut self) {
+        for reg in self.registers[..(NR52_ADDR - SOUND_REGISTERS_START)].iter_mut() {
+            *reg = 0;
+        }
+        self.square1 = SquareChannel::new(true);
+        self.square2 = SquareChannel::new(false);
+        self.wave = WaveChannel { length: LengthCounter::new(256), ..Default::default() };
+        self.noise = NoiseChannel { length: LengthCounter::new(64), ..Default::default() };
+    }
+
+    /// Keeps NR52 up to date with the power and channel status
+    fn update_status(&mut self) {
+        let mut status = if self.powered { 0x80 } else { 0 };
+        for (i, &on) in [self.square1.enabled,
+                         self.square2.enabled,
+                         self.wave.enabled,
+                         self.noise.enabled]
+            .iter()
+            .enumerate() {
+            if on {
+                status |= 1 << i;
+            }
+        }
+        self.registers[NR52_ADDR - SOUND_REGISTERS_START] = status;
+    }
+
+    /// Whether a channel (0-3) is playing
+    pub fn channel_on(&self, channel: usize) -> bool {
+        (self.registers[NR52_ADDR - SOUND_REGISTERS_START] >> channel) & 1 == 1
+    }
+
+    /// Runs the APU for `cycles` CPU cycles
+    pub fn step(&mut self, cycles: u32) {
+        for _ in 0..cycles {
+            if self.powered {
+                self.square1.tick();
+                self.square2.tick();
+                self.wave.tick();
+                self.noise.tick();
+
+                self.frame_sequencer_timer -= 1;
+                if self.frame_sequencer_timer == 0 {
+                    self.frame_sequencer_timer = FRAME_SEQUENCER_PERIOD;
+                    self.clock_frame_sequencer();
+                }
+            }
+
+            self.sample_timer += APU_SAMPLE_RATE;
+            if self.sample_timer >= CPU_FREQUENCY {
+                self.sample_timer -= CPU_FREQUENCY;
+                self.push_sample();
+            }
+        }
+        self.update_status();
+    }
+
+    /// Clocks length counters at 256Hz, sweep at 128Hz and envelopes
+    /// at 64Hz
+    fn clock_frame_sequencer(&mut self) {
+        let step = self.frame_sequencer_step;
+        if step % 2 == 0 {
+            if !self.square1.length.clock() {
+                self.square1.enabled = false;
+            }
+            if !self.square2.length.clock() {
+                self.square2.enabled = false;
+            }
+            if !self.wave.length.clock() {
+                self.wave.enabled = false;
+            }
+            if !self.noise.length.clock() {
+                self.noise.enabled = false;
+            }
+        }
+        if step == 2 || step == 6 {
+            self.square1.clock_sweep();
+        }
+        if step == 7 {
+            self.square1.envelope.clock();
+            self.square2.envelope.clock();
+            self.noise.envelope.clock();
+        }
+        self.frame_sequencer_step = (step + 1) % 8;
+    }
+
+    /// Current output of each channel between -1 and 1
+    fn channel_outputs(&self) -> [f32; 4] {
+        let wave_ram = &self.registers[(WAVE_RAM_START - SOUND_REGISTERS_START)..];
+        let digital = [(self.square1.dac_enabled, self.square1.output()),
+                       (self.square2.dac_enabled, self.square2.output()),
+                       (self.wave.dac_enabled, self.wave.output(wave_ram)),
+                       (self.noise.dac_enabled, self.noise.output())];
+
+        let mut analog = [0.0; 4];
+        for (i, &(dac_enabled, value)) in digital.iter().enumerate() {
+            // A disabled DAC outputs silence instead of its lowest level
+            if dac_enabled {
+                analog[i] = (value as f32) / 7.5 - 1.0;
+            }
+        }
+        analog
+    }
+
+    fn push_sample(&mut self) {
+        if self.muted {
+            return;
+        }
+        let (left, right) = if self.powered {
+            let outputs = self.channel_outputs();
+            let panning = self.registers[NR51_ADDR - SOUND_REGISTERS_START];
+            let volume = self.registers[NR50_ADDR - SOUND_REGISTERS_START];
+
+            let mut left = 0.0;
+            let mut right = 0.0;
+            for (i, &output) in outputs.iter().enumerate() {
+                if panning & (0x10 << i) != 0 {
+                    left += output;
+                }
+                if panning & (0x01 << i) != 0 {
+                    right += output;
+                }
+            }
+            let left_volume = (((volume >> 4) & 0x7) + 1) as f32 / 8.0;
+            let right_volume = ((volume & 0x7) + 1) as f32 / 8.0;
+            (left / 4.0 * left_volume, right / 4.0 * right_volume)
+        } else {
+            (0.0, 0.0)
+        };
+
+        if self.samples.len() >= MAX_BUFFERED_SAMPLES {
+            let excess = cmp::min(self.samples.len(), MAX_BUFFERED_SAMPLES / 2);
+            self.samples.drain(..excess);
+        }
+        self.samples.push(left);
+        self.samples.push(right);
+    }
+
+    /// Returns the samples produced since the last call
+    pub fn take_samples(&mut self) -> Vec<f32> {
+        ::std::mem::replace(&mut self.samples, vec![])
+    }
+
+    /// Keeps the channels running without producing samples, e.g.
+    /// while the debugger replays the past
+    pub fn set_muted(&mut self, muted: bool) {
+        self.muted = muted;
+    }
+}
+
+impl SaveState for LengthCounter {
+    fn write_state(&self, w: &mut StateWriter) {
+        w.u16(self.counter);
+        w.bool(self.enabled);
+    }
+
+    fn read_state(&mut self, r: &mut StateReader) -> Result<(), SaveStateError> {
+        self.counter = r.u16()?;
+        self.enabled = r.bool()?;
+        Ok(())
+    }
+}
+
+impl SaveState for Envelope {
+    fn write_state(&self, w: &mut StateWriter) {
+        w.u8(self.initial_volume);
+        w.bool(self.increase);
+        w.u8(self.period);
+        w.u8(self.volume);
+        w.u8(self.timer);
+    }
+
+    fn read_state(&mut self, r: &mut StateReader) -> Result<(), SaveStateError> {
+        self.initial_volume = r.u8()?;
+        self.increase = r.bool()?;
+        self.period = r.u8()?;
+        self.volume = r.u8()?;
+        self.timer = r.u8()?;
+        Ok(())
+    }
+}
+
+impl SaveState for Sweep {
+    fn write_state(&self, w: &mut StateWriter) {
+        w.u8(self.period);
+        w.bool(self.negate);
+        w.u8(self.shift);
+        w.u8(self.timer);
+        w.bool(self.enabled);
+        w.u16(self.shadow_frequency);
+    }
+
+    fn read_state(&mut self, r: &mut StateReader) -> Result<(), SaveStateError> {
+        self.period = r.u8()?;
+        self.negate = r.bool()?;
+        self.shift = r.u8()?;
+        self.timer = r.u8()?;
+        self.enabled = r.bool()?;
+        self.shadow_frequency = r.u16()?;
+        Ok(())
+    }
+}
+
+impl SaveState for SquareChannel {
+    fn write_state(&self, w: &mut StateWriter) {
+        w.bool(self.enabled);
+        w.bool(self.dac_enabled);
+        w.u8(self.duty);
+        w.u8(self.duty_position);
+        w.u16(self.frequency);
+        w.u32(self.timer);
+        self.length.write_state(w);
+        self.envelope.write_state(w);
+        if let Some(ref sweep) = self.sweep {
+            sweep.write_state(w);
+        }
+    }
+
+    fn read_state(&mut self, r: &mut StateReader) -> Result<(), SaveStateError> {
+        self.enabled = r.bool()?;
+        self.dac_enabled = r.bool()?;
+        self.duty = r.u8()? & 0x3;
+        self.duty_position = r.u8()? & 0x7;
+        self.frequency = r.u16()?;
+        self.timer = r.u32()?;
+        self.length.read_state(r)?;
+        self.envelope.read_state(r)?;
+        if let Some(ref mut sweep) = self.sweep {
+            sweep.read_state(r)?;
+        }
+        Ok(())
+    }
+}
+
+impl SaveState for WaveChannel {
+    fn write_state(&self, w: &mut StateWriter) {
+        w.bool(self.enabled);
+        w.bool(self.dac_enabled);
+        w.u8(self.volume_shift);
+        w.u16(self.frequency);
+        w.u32(self.timer);
+        w.u8(self.position as u8);
+        self.length.write_state(w);
+    }
+
+    fn read_state(&mut self, r: &mut StateReader) -> Result<(), SaveStateError> {
+        self.enabled = r.bool()?;
+        self.dac_enabled = r.bool()?;
+        self.volume_shift = r.u8()?;
+        self.frequency = r.u16()?;
+        self.timer = r.u32()?;
+        self.position = (r.u8()? & 0x1F) as usize;
+        self.length.read_state(r)
+    }
+}
+
+impl SaveState for NoiseChannel {
+    fn write_state(&self, w: &mut StateWriter) {
+        w.bool(self.enabled);
+        w.bool(self.dac_enabled);
+        w.u16(self.lfsr);
+        w.bool(self.width_7);
+        w.bool(self.frozen);
+        w.u32(self.period);
+        w.u32(self.timer);
+        self.length.write_state(w);
+        self.envelope.write_state(w);
+    }
+
+    fn read_state(&mut self, r: &mut StateReader) -> Result<(), SaveStateError> {
+        self.enabled = r.bool()?;
+        self.dac_enabled = r.bool()?;
+        self.lfsr = r.u16()?;
+        self.width_7 = r.bool()?;
+        self.frozen = r.bool()?;
+        self.period = r.u32()?;
+        self.timer = r.u32()?;
+        self.length.read_state(r)?;
+        self.envelope.read_state(r)
+    }
+}
+
+/// Samples which were not picked up yet are dropped
+impl SaveState for Apu {
+    fn write_state(&self, w: &mut StateWriter) {
+        w.bytes(&self.registers[..]);
+        w.bool(self.powered);
+        self.square1.write_state(w);
+        self.square2.write_state(w);
+        self.wave.write_state(w);
+        self.noise.write_state(w);
+        w.u32(self.frame_sequencer_timer);
+        w.u8(self.frame_sequencer_step);
+        w.u32(self.sample_timer);
+    }
+
+    fn read_state(&mut self, r: &mut StateReader) -> Result<(), SaveStateError> {
+        r.bytes_into(&mut self.registers[..])?;
+        self.powered = r.bool()?;
+        self.square1.read_state(r)?;
+        self.square2.read_state(r)?;
+        self.wave.read_state(r)?;
+        self.noise.read_state(r)?;
+        self.frame_sequencer_timer = r.u32()?;
+        self.frame_sequencer_step = r.u8()? & 0x7;
+        self.sample_timer = r.u32()?;
+        self.samples.clear();
+        Ok(())
+    }
+}
+
+impl Cpu {
+    /// Runs the APU for `cycles` CPU cycles
+    pub fn step_apu(&mut self, cycles: u32) {
+        self.apu.step(cycles);
+    }
+
+    /// Handles writes to 0xFF10-0xFF3F
+    pub fn write_sound_register(&mut self, addr: usize, value: byte) {
+        self.apu.write(addr, value);
+    }
+
+    /// Value last written to a sound register, without the bits which
+    /// read as 1
+    pub fn sound_register(&self, addr: usize) -> byte {
+        self.apu.registers()[addr - SOUND_REGISTERS_START]
+    }
+}
@@ -0,0 +1,75 @@
+//! Boot ROM
+//!
+//! The boot ROM covers the first 256 bytes of the cartridge ROM until
+//! the program writes to 0xFF50.  It scrolls in the logo, checks the
+//! cartridge header and sets up the registers before jumping to the
+//! cartridge at 0x100.  Without one `Cpu::reset` sets the state the
+//! boot ROM would have left behind directly.
+
+use std::fs::File;
+use std::io;
+use std::io::Read;
+use std::path::Path;
+
+use cpu::Cpu;
+use cpu::constants::*;
+use cpu::apu::*;
+use cpu::interrupts::*;
+use cpu::timer::*;
+
+pub const BOOT_ROM_SIZE: usize = 0x100;
+/// Writing 1 here unmaps the boot ROM until the next reset
+pub const BOOT_ROM_DISABLE_ADDR: usize = 0xFF50;
+
+impl Cpu {
+    /// Reads a boot ROM from disk and starts running it, see `boot_with`
+    pub fn load_boot_rom(&mut self, path: &Path) -> io::Result<()> {
+        let mut data = vec![];
+        File::open(path)?.read_to_end(&mut data)?;
+        if data.len() != BOOT_ROM_SIZE {
+            return Err(io::Error::new(io::ErrorKind::InvalidData,
+                                      format!("boot ROM must be {} bytes, not {}",
+                                              BOOT_ROM_SIZE,
+                                              data.len())));
+        }
+        self.boot_with(data);
+        Ok(())
+    }
+
+    /// Puts the machine in its power on state with `boot_rom` mapped
+    /// over the cartridge, so execution starts in the boot ROM
+    pub fn boot_with(&mut self, boot_rom: Vec<byte>) {
+        self.reset();
+        self.a = 0;
+        self.f = 0;
+        self.set_bc(0);
+        self.set_de(0);
+        self.set_hl(0);
+        self.sp = 0;
+        self.pc = 0;
+
+        // Undo the values `reset` set in place of the boot ROM
+        self.timer.divider = 0;
+        self.io[DIV_ADDR] = 0;
+        self.write_sound_register(NR52_ADDR, 0);
+        self.io[0xFF40] = 0;
+        self.io[0xFF47] = 0;
+        self.io[IF_ADDR] = 0xE0;
+        // Only the DMG boot ROM is supported
+        self.reset_cgb(false);
+
+        self.boot_rom = Some(boot_rom);
+        self.reinit_logger();
+    }
+
+    pub fn boot_rom_mapped(&self) -> bool {
+        self.boot_rom.is_some()
+    }
+
+    pub fn write_boot_rom_disable(&mut self, value: byte) {
+        if value & 1 == 1 && self.boot_rom.is_some() {
+            debug!("Boot ROM unmapped");
+            self.boot_rom = None;
+        }
+    }
+}
@@ -0,0 +1,518 @@
+//! Cartridge emulation: ROM, external RAM and memory bank controllers
+//!
+//! The cartridge owns the full ROM image and all external RAM.  What
+//! the CPU sees at 0x0000-0x7FFF and 0xA000-0xBFFF depends on the
+//! state of the memory bank controller (MBC), which is changed by
+//! writing to the ROM address range.
+//!
+//! RAM of cartridges with a battery is kept in a `.sav` file next to
+//! the ROM.  The file is a raw dump of the RAM banks in order, the
+//! same format used by most other emulators.  Cartridges with a real
+//! time clock append the clock state, see `rtc::Rtc::to_save_data`.
+
+use std::fs::File;
+use std::io;
+use std::io::{Read, Write};
+use std::path::{Path, PathBuf};
+use std::rc::Rc;
+
+use cpu::constants::*;
+use cpu::header::*;
+use cpu::memory::MemoryMapped;
+use cpu::rtc::*;
+use cpu::savestate::*;
+
+/// Size of a single ROM bank
+pub const ROM_BANK_SIZE: usize = 0x4000;
+/// Size of a single external RAM bank
+pub const RAM_BANK_SIZE: usize = 0x2000;
+
+/// Start of the switchable ROM bank in the address space
+pub const ROM_BANK_X_START: usize = 0x4000;
+/// Start of the external RAM in the address space
+pub const EXTERNAL_RAM_START: usize = 0xA000;
+pub const EXTERNAL_RAM_END: usize = 0xBFFF;
+
+/// MBC2 has 512 half-bytes of RAM built into the controller
+const MBC2_RAM_SIZE: usize = 0x200;
+
+/// Bank switching registers of the memory bank controller
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum Mbc {
+    /// Up to 32KiB of ROM and optionally 8KiB of RAM, no banking
+    NoMbc,
+    Mbc1 {
+        /// Lower 5 bits of the ROM bank number
+        rom_bank: u8,
+        /// Either the RAM bank or bits 5-6 of the ROM bank number
+        upper_bits: u8,
+        /// When set, `upper_bits` also affects 0x0000-0x3FFF and RAM
+        advanced_mode: bool,
+    },
+    Mbc2 { rom_bank: u8 },
+    Mbc3 {
+        rom_bank: u8,
+        /// 0x00-0x03 select a RAM bank, 0x08-0x0C an RTC register
+        ram_bank: u8,
+    },
+    Mbc5 { rom_bank: u16, ram_bank: u8 },
+}
+
+impl Mbc {
+    /// Picks the controller used by a type of cartridge
+    pub fn for_cartridge_type(cart_type: CartridgeType) -> Mbc {
+        use self::CartridgeType::*;
+
+        match cart_type {
+            RomOnly | RomRam | RomRamBatt => Mbc::NoMbc,
+            RomMBC1 | RomMBC1Ram | RomMBC1RamBatt => Mbc::Mbc1 {
+                rom_bank: 1,
+                upper_bits: 0,
+                advanced_mode: false,
+            },
+            RomMBC2 | RomMBC2Batt => Mbc::Mbc2 { rom_bank: 1 },
+            RomMBC3TimerBatt | RomMBC3TimerRamBatt | RomMBC3 | RomMBC3Ram |
+            RomMBC3RamBatt => {
+                Mbc::Mbc3 { rom_bank: 1, ram_bank: 0 }
+            }
+            RomMBC5 | RomMBC5Ram | RomMBC5RamBatt | RomMBC5RumbleSRam |
+            RomMBC5RumbleSRamBatt => Mbc::Mbc5 { rom_bank: 1, ram_bank: 0 },
+            other => {
+                error!("Cartridge type {:?} is not supported, running it without banking",
+                       other);
+                Mbc::NoMbc
+            }
+        }
+    }
+}
+
+/// A cartridge with its memory bank controller
+#[derive(Clone)]
+pub struct Cartridge {
+    pub cartridge_type: CartridgeType,
+    /// Never changes, so clones of the CPU share it
+    rom: Rc<Vec<byte>>,
+    /// Parsed once, the global checksum covers the whole ROM
+    header: Rc<CartridgeHeader>,
+    ram: Vec<byte>,
+    ram_enabled: bool,
+    pub mbc: Mbc,
+    /// Where battery-backed RAM is persisted
+    pub save_path: Option<PathBuf>,
+    /// RAM or the clock has changed since it was last written to
+    /// `save_path`
+    ram_dirty: bool,
+    /// Real time clock of MBC3 cartridges with a timer
+    pub rtc: Option<Rtc>,
+    /// Host time used to advance `rtc`
+    clock: Clock,
+}
+
+impl Cartridge {
+    /// Creates a cartridge from a ROM image, picking the MBC from the
+    /// cartridge type byte in the header
+    pub fn new(rom: Vec<byte>) -> Cartridge {
+        Cartridge::with_clock(rom, system_clock)
+    }
+
+    /// Like `new`, but the real time clock (if any) follows `clock`
+    /// instead of the host's time
+    pub fn with_clock(mut rom: Vec<byte>, clock: Clock) -> Cartridge {
+        // Every cartridge has at least two ROM banks
+        if rom.len() < 2 * ROM_BANK_SIZE {
+            rom.resize(2 * ROM_BANK_SIZE, 0);
+        }
+
+        let type_byte = rom[0x147];
+        let cartridge_type = match CartridgeType::from_byte(type_byte) {
+            Some(t) => t,
+            None => {
+                error!("Unknown cartridge type 0x{:02X}, treating it as ROM only",
+                       type_byte);
+                CartridgeType::RomOnly
+            }
+        };
+        let mbc = Mbc::for_cartridge_type(cartridge_type);
+
+        let ram_size = match mbc {
+            Mbc::Mbc2 { .. } => MBC2_RAM_SIZE,
+            _ => ram_size_from_header(rom[0x149]),
+        };
+
+        debug!("Cartridge: {:?}, {} ROM banks, {} bytes of RAM",
+               cartridge_type,
+               rom.len() / ROM_BANK_SIZE,
+               ram_size);
+
+        let header = CartridgeHeader::parse(&rom[..]).expect("ROM is padded to two banks");
+        Cartridge {
+            cartridge_type: cartridge_type,
+            header: Rc::new(header),
+            rom: Rc::new(rom),
+            ram: vec![0; ram_size],
+            ram_enabled: false,
+            mbc: mbc,
+            save_path: None,
+            ram_dirty: false,
+            rtc: if cartridge_type.has_rtc() {
+                Some(Rtc::new(clock()))
+            } else {
+                None
+            },
+            clock: clock,
+        }
+    }
+
+    /// A blank ROM-only cartridge, used when no ROM has been loaded
+    pub fn empty() -> Cartridge {
+        Cartridge::new(vec![])
+    }
+
+    /// Number of 16KiB ROM banks
+    pub fn rom_banks(&self) -> usize {
+        self.rom.len() / ROM_BANK_SIZE
+    }
+
+    pub fn rom(&self) -> &[byte] {
+        &self.rom[..]
+    }
+
+    pub fn ram(&self) -> &[byte] {
+        &self.ram[..]
+    }
+
+    /// Replaces the contents of RAM, e.g. with the RAM of a previous
+    /// session.  Extra bytes are ignored, missing ones left as is.
+    pub fn load_ram(&mut self, data: &[byte]) {
+        let len = ::std::cmp::min(data.len(), self.ram.len());
+        self.ram[..len].copy_from_slice(&data[..len]);
+    }
+
+    /// The header of the ROM, which is always long enough to have one
+    pub fn header(&self) -> &CartridgeHeader {
+        &self.header
+    }
+
+    /// Bit 7 of the CGB flag in the header is set for cartridges which
+    /// use the Game Boy Color's features
+    pub fn cgb_supported(&self) -> bool {
+        self.rom[0x143] & 0x80 != 0
+    }
+
+    pub fn has_battery(&self) -> bool {
+        self.cartridge_type.has_battery() && (!self.ram.is_empty() || self.rtc.is_some())
+    }
+
+    /// Sets up persistence of battery-backed RAM in `path`, loading
+    /// RAM (and the clock) from it if it already exists
+    pub fn load_save_file(&mut self, path: &Path) -> io::Result<()> {
+        if !self.has_battery() {
+            return Ok(());
+        }
+        self.save_path = Some(path.to_path_buf());
+
+        if path.exists() {
+            let mut data = vec![];
+            File::open(path).and_then(|mut f| f.read_to_end(&mut data))?;
+            if data.len() < self.ram.len() {
+                warn!("Save file {} is smaller than the cartridge RAM",
+                      path.display());
+            }
+            self.load_ram(&data[..]);
+
+            let ram_len = self.ram.len();
+            if let Some(ref mut rtc) = self.rtc {
+                if data.len() <= ram_len || !rtc.load_save_data(&data[ram_len..]) {
+                    warn!("Save file {} has no valid clock data, resetting the clock",
+                          path.display());
+                }
+            }
+            info!("Loaded save file {}", path.display());
+        }
+        Ok(())
+    }
+
+    /// Writes battery-backed RAM to the save file if it has changed
+    pub fn flush_save_file(&mut self) -> io::Result<()> {
+        if !self.ram_dirty {
+            return Ok(());
+        }
+        if let Some(ref path) = self.save_path {
+            let mut file = File::create(path)?;
+            file.write_all(&self.ram[..])?;
+            if let Some(ref mut rtc) = self.rtc {
+                rtc.update((self.clock)());
+                file.write_all(&rtc.to_save_data()[..])?;
+            }
+            debug!("Wrote save file {}", path.display());
+        }
+        self.ram_dirty = false;
+        Ok(())
+    }
+
+    /// Bank mapped at 0x0000-0x3FFF
+    fn rom_bank_0(&self) -> usize {
+        let bank = match self.mbc {
+            Mbc::Mbc1 { upper_bits, advanced_mode: true, .. } => (upper_bits as usize) << 5,
+            _ => 0,
+        };
+        bank % self.rom_banks()
+    }
+
+    /// Bank mapped at 0x4000-0x7FFF
+    pub fn rom_bank_x(&self) -> usize {
+        let bank = match self.mbc {
+            Mbc::NoMbc => 1,
+            Mbc::Mbc1 { rom_bank, upper_bits, .. } => {
+                ((upper_bits as usize) << 5) | (rom_bank as usize)
+            }
+            Mbc::Mbc2 { rom_bank } |
+            Mbc::Mbc3 { rom_bank, .. } => rom_bank as usize,
+            Mbc::Mbc5 { rom_bank, .. } => rom_bank as usize,
+        };
+        bank % self.rom_banks()
+    }
+
+    /// RAM bank mapped at 0xA000-0xBFFF, `None` if RAM cannot be
+    /// accessed right now
+    fn ram_bank(&self) -> Option<usize> {
+        if !self.ram_enabled || self.ram.is_empty() {
+            return None;
+        }
+        let bank = match self.mbc {
+            Mbc::NoMbc | Mbc::Mbc2 { .. } => 0,
+            Mbc::Mbc1 { upper_bits, advanced_mode: true, .. } => upper_bits as usize,
+            Mbc::Mbc1 { .. } => 0,
+            Mbc::Mbc3 { ram_bank, .. } if ram_bank <= 0x3 => ram_bank as usize,
+            Mbc::Mbc3 { .. } => return None,
+            Mbc::Mbc5 { ram_bank, .. } => ram_bank as usize,
+        };
+        let banks = (self.ram.len() + RAM_BANK_SIZE - 1) / RAM_BANK_SIZE;
+        Some(bank % banks)
+    }
+
+    /// RTC register mapped at 0xA000-0xBFFF instead of RAM
+    fn rtc_register(&self) -> Option<u8> {
+        if !self.ram_enabled || self.rtc.is_none() {
+            return None;
+        }
+        match self.mbc {
+            Mbc::Mbc3 { ram_bank, .. } if ram_bank >= RTC_SECONDS && ram_bank <= RTC_DAY_HIGH => {
+                Some(ram_bank)
+            }
+            _ => None,
+        }
+    }
+
+    /// Index into `ram` for an address in 0xA000-0xBFFF
+    fn ram_index(&self, bank: usize, addr: MemAddr) -> usize {
+        let offset = (addr as usize) - EXTERNAL_RAM_START;
+        match self.mbc {
+            // Only 9 address bits are decoded, the rest is echoed
+            Mbc::Mbc2 { .. } => offset % MBC2_RAM_SIZE,
+            _ => (bank * RAM_BANK_SIZE + offset) % self.ram.len(),
+        }
+    }
+
+    /// Banked read of 0x0000-0x7FFF or 0xA000-0xBFFF
+    pub fn read(&self, addr: MemAddr) -> byte {
+        match addr as usize {
+            a @ 0x0000...0x3FFF => self.rom[self.rom_bank_0() * ROM_BANK_SIZE + a],
+            a @ 0x4000...0x7FFF => {
+                self.rom[self.rom_bank_x() * ROM_BANK_SIZE + (a - ROM_BANK_X_START)]
+            }
+            EXTERNAL_RAM_START...EXTERNAL_RAM_END => {
+                if let (Some(register), Some(rtc)) = (self.rtc_register(), self.rtc.as_ref()) {
+                    return rtc.read(register);
+                }
+                match self.ram_bank() {
+                    Some(bank) => {
+                        let v = self.ram[self.ram_index(bank, addr)];
+                        match self.mbc {
+                            // Upper half of MBC2 RAM is not connected
+                            Mbc::Mbc2 { .. } => v | 0xF0,
+                            _ => v,
+                        }
+                    }
+                    None => 0xFF,
+                }
+            }
+            _ => {
+                error!("Cartridge read from non-cartridge address 0x{:04X}", addr);
+                0xFF
+            }
+        }
+    }
+
+    /// Handles writes to the bank switching registers (0x0000-0x7FFF)
+    /// and to external RAM (0xA000-0xBFFF)
+    pub fn write(&mut self, addr: MemAddr, value: byte) {
+        let addr = addr as usize;
+        match addr {
+            EXTERNAL_RAM_START...EXTERNAL_RAM_END => {
+                if let Some(register) = self.rtc_register() {
+                    let now = (self.clock)();
+                    if let Some(ref mut rtc) = self.rtc {
+                        rtc.write(register, value, now);
+                    }
+                    self.ram_dirty = true;
+                } else if let Some(bank) = self.ram_bank() {
+                    let idx = self.ram_index(bank, addr as MemAddr);
+                    self.ram[idx] = match self.mbc {
+                        Mbc::Mbc2 { .. } => value & 0x0F,
+                        _ => value,
+                    };
+                    self.ram_dirty = true;
+                }
+            }
+            0x0000...0x7FFF => self.write_register(addr, value),
+            _ => error!("Cartridge write to non-cartridge address 0x{:04X}", addr),
+        }
+    }
+
+    fn write_register(&mut self, addr: usize, value: byte) {
+        match self.mbc {
+            Mbc::NoMbc => (),
+            Mbc::Mbc1 { ref mut rom_bank, ref mut upper_bits, ref mut advanced_mode } => {
+                match addr {
+                    0x0000...0x1FFF => self.ram_enabled = value & 0x0F == 0x0A,
+                    0x2000...0x3FFF => {
+                        // Bank 0 cannot be selected here, it reads as 1
+                        let bank = value & 0x1F;
+                        *rom_bank = if bank == 0 { 1 } else { bank };
+                    }
+                    0x4000...0x5FFF => *upper_bits = value & 0x3,
+                    _ => *advanced_mode = value & 1 == 1,
+                }
+            }
+            Mbc::Mbc2 { ref mut rom_bank } => {
+                if addr < 0x4000 {
+                    // Bit 8 of the address selects the register
+                    if addr & 0x100 == 0 {
+                        self.ram_enabled = value & 0x0F == 0x0A;
+                    } else {
+                        let bank = value & 0x0F;
+                        *rom_bank = if bank == 0 { 1 } else { bank };
+                    }
+                }
+            }
+            Mbc::Mbc3 { ref mut rom_bank, ref mut ram_bank } => {
+                match addr {
+                    0x0000...0x1FFF => self.ram_enabled = value & 0x0F == 0x0A,
+                    0x2000...0x3FFF => {
+                        let bank = value & 0x7F;
+                        *rom_bank = if bank == 0 { 1 } else { bank };
+                    }
+                    0x4000...0x5FFF => *ram_bank = value,
+                    _ => {
+                        if let Some(ref mut rtc) = self.rtc {
+                            rtc.write_latch(value, (self.clock)());
+                        }
+                    }
+                }
+            }
+            Mbc::Mbc5 { ref mut rom_bank, ref mut ram_bank } => {
+                match addr {
+                    0x0000...0x1FFF => self.ram_enabled = value & 0x0F == 0x0A,
+                    0x2000...0x2FFF => *rom_bank = (*rom_bank & 0x100) | (value as u16),
+                    0x3000...0x3FFF => {
+                        *rom_bank = (*rom_bank & 0xFF) | (((value & 1) as u16) << 8)
+                    }
+                    0x4000...0x5FFF => *ram_bank = value & 0x0F,
+                    _ => (),
+                }
+            }
+        }
+    }
+}
+
+impl MemoryMapped for Cartridge {
+    fn peek(&self, addr: MemAddr) -> byte {
+        Cartridge::read(self, addr)
+    }
+
+    fn write(&mut self, addr: MemAddr, value: byte) {
+        Cartridge::write(self, addr, value)
+    }
+}
+
+/// The ROM isn't part of the state, it has to be loaded already
+impl SaveState for Cartridge {
+    fn write_state(&self, w: &mut StateWriter) {
+        match self.mbc {
+            Mbc::NoMbc => w.u8(0),
+            Mbc::Mbc1 { rom_bank, upper_bits, advanced_mode } => {
+                w.u8(1);
+                w.u8(rom_bank);
+                w.u8(upper_bits);
+                w.bool(advanced_mode);
+            }
+            Mbc::Mbc2 { rom_bank } => {
+                w.u8(2);
+                w.u8(rom_bank);
+            }
+            Mbc::Mbc3 { rom_bank, ram_bank } => {
+                w.u8(3);
+                w.u8(rom_bank);
+                w.u8(ram_bank);
+            }
+            Mbc::Mbc5 { rom_bank, ram_bank } => {
+                w.u8(5);
+                w.u16(rom_bank);
+                w.u8(ram_bank);
+            }
+        }
+        w.bool(self.ram_enabled);
+        w.bytes(&self.ram[..]);
+        match self.rtc {
+            Some(ref rtc) => w.bytes(&rtc.to_save_data()[..]),
+            None => w.bytes(&[]),
+        }
+    }
+
+    fn read_state(&mut self, r: &mut StateReader) -> Result<(), SaveStateError> {
+        let mbc = match r.u8()? {
+            0 => Mbc::NoMbc,
+            1 => Mbc::Mbc1 {
+                rom_bank: r.u8()?,
+                upper_bits: r.u8()?,
+                advanced_mode: r.bool()?,
+            },
+            2 => Mbc::Mbc2 { rom_bank: r.u8()? },
+            3 => Mbc::Mbc3 { rom_bank: r.u8()?, ram_bank: r.u8()? },
+            5 => Mbc::Mbc5 { rom_bank: r.u16()?, ram_bank: r.u8()? },
+            _ => return Err(SaveStateError::Corrupt),
+        };
+        // The checksum matched, so this should be the same controller
+        match (mbc, self.mbc) {
+            (Mbc::NoMbc, Mbc::NoMbc) |
+            (Mbc::Mbc1 { .. }, Mbc::Mbc1 { .. }) |
+            (Mbc::Mbc2 { .. }, Mbc::Mbc2 { .. }) |
+            (Mbc::Mbc3 { .. }, Mbc::Mbc3 { .. }) |
+            (Mbc::Mbc5 { .. }, Mbc::Mbc5 { .. }) => (),
+            _ => return Err(SaveStateError::Corrupt),
+        }
+        self.mbc = mbc;
+        self.ram_enabled = r.bool()?;
+        r.bytes_into(&mut self.ram[..])?;
+        let rtc_data = r.bytes()?;
+        if let Some(ref mut rtc) = self.rtc {
+            if !rtc.load_save_data(rtc_data) {
+                return Err(SaveStateError::Corrupt);
+            }
+        }
+        self.ram_dirty = true;
+        Ok(())
+    }
+}
+
+/// Decodes the RAM size byte at 0x149 of the cartridge header
+fn ram_size_from_header(size_code: byte) -> usize {
+    match ram_size_from_code(size_code) {
+        Some(size) => size,
+        None => {
+            error!("Unknown RAM size code 0x{:02X}", size_code);
+            0
+        }
+    }
+}
@@ -0,0 +1,279 @@
+//! Game Boy Color features
+//!
+//! Cartridges with bit 7 of 0x143 set run in CGB mode, which adds a
+//! double speed mode, a second VRAM bank holding background map
+//! attributes, 8 work RAM banks, color palettes and DMA transfers to
+//! VRAM.  In DMG mode the registers read as 0xFF and ignore writes.
+
+use cpu::Cpu;
+use cpu::constants::*;
+use cpu::memory::*;
+use cpu::savestate::*;
+
+/// Speed switch
+pub const KEY1_ADDR: usize = 0xFF4D;
+/// VRAM bank
+pub const VBK_ADDR: usize = 0xFF4F;
+/// VRAM DMA source high and low, destination high and low, and
+/// length/mode/start
+pub const HDMA1_ADDR: usize = 0xFF51;
+pub const HDMA2_ADDR: usize = 0xFF52;
+pub const HDMA3_ADDR: usize = 0xFF53;
+pub const HDMA4_ADDR: usize = 0xFF54;
+pub const HDMA5_ADDR: usize = 0xFF55;
+/// Background palette index and data
+pub const BCPS_ADDR: usize = 0xFF68;
+pub const BCPD_ADDR: usize = 0xFF69;
+/// Sprite palette index and data
+pub const OCPS_ADDR: usize = 0xFF6A;
+pub const OCPD_ADDR: usize = 0xFF6B;
+/// Work RAM bank
+pub const SVBK_ADDR: usize = 0xFF70;
+
+/// 8 palettes of 4 colors of 2 bytes
+const PALETTE_RAM_SIZE: usize = 64;
+/// Bytes copied by VRAM DMA at a time
+const HDMA_BLOCK_SIZE: u16 = 0x10;
+/// Cycles (at normal speed) the CPU is stopped per block of a general
+/// purpose DMA
+const HDMA_BLOCK_CYCLES: u8 = 32;
+
+/// A 15 bit color as stored in palette RAM, 5 bits of red in the
+/// lowest bits, then green and blue
+pub type Rgb555 = u16;
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct Cgb {
+    /// Running a CGB cartridge
+    pub enabled: bool,
+    pub double_speed: bool,
+    /// KEY1 bit 0, the next STOP switches speed
+    speed_switch_armed: bool,
+    bg_palettes: Vec<byte>,
+    obj_palettes: Vec<byte>,
+    bcps: byte,
+    ocps: byte,
+    hdma_source: u16,
+    hdma_dest: u16,
+    /// Blocks left to copy
+    hdma_blocks: u8,
+    /// A HBlank DMA is copying a block every HBlank
+    hdma_hblank: bool,
+}
+
+impl Cgb {
+    pub fn new(enabled: bool) -> Cgb {
+        Cgb {
+            enabled: enabled,
+            double_speed: false,
+            speed_switch_armed: false,
+            // The boot ROM sets the background palettes to white
+            bg_palettes: vec![0xFF; PALETTE_RAM_SIZE],
+            obj_palettes: vec![0xFF; PALETTE_RAM_SIZE],
+            bcps: 0,
+            ocps: 0,
+            hdma_source: 0,
+            hdma_dest: 0,
+            hdma_blocks: 0,
+            hdma_hblank: false,
+        }
+    }
+}
+
+impl SaveState for Cgb {
+    fn write_state(&self, w: &mut StateWriter) {
+        w.bool(self.enabled);
+        w.bool(self.double_speed);
+        w.bool(self.speed_switch_armed);
+        w.bytes(&self.bg_palettes[..]);
+        w.bytes(&self.obj_palettes[..]);
+        w.u8(self.bcps);
+        w.u8(self.ocps);
+        w.u16(self.hdma_source);
+        w.u16(self.hdma_dest);
+        w.u8(self.hdma_blocks);
+        w.bool(self.hdma_hblank);
+    }
+
+    fn read_state(&mut self, r: &mut StateReader) -> Result<(), SaveStateError> {
+        self.enabled = r.bool()?;
+        self.double_speed = r.bool()?;
+        self.speed_switch_armed = r.bool()?;
+        r.bytes_into(&mut self.bg_palettes[..])?;
+        r.bytes_into(&mut self.obj_palettes[..])?;
+        self.bcps = r.u8()?;
+        self.ocps = r.u8()?;
+        self.hdma_source = r.u16()?;
+        self.hdma_dest = r.u16()?;
+        self.hdma_blocks = r.u8()?;
+        self.hdma_hblank = r.bool()?;
+        Ok(())
+    }
+}
+
+/// Scales a palette color to 8 bits per channel, as (red, green, blue)
+pub fn rgb555_to_rgb888(color: Rgb555) -> (u8, u8, u8) {
+    let channel = |shift: u16| {
+        let value = ((color >> shift) & 0x1F) as u8;
+        (value << 3) | (value >> 2)
+    };
+    (channel(0), channel(5), channel(10))
+}
+
+/// Color `color` of palette `palette` in palette RAM
+fn palette_color(palettes: &[byte], palette: byte, color: byte) -> Rgb555 {
+    let index = (palette as usize & 0x7) * 8 + (color as usize) * 2;
+    (palettes[index] as u16) | ((palettes[index + 1] as u16 & 0x7F) << 8)
+}
+
+impl Cpu {
+    pub fn cgb_mode(&self) -> bool {
+        self.cgb.enabled
+    }
+
+    pub fn bg_palette_color(&self, palette: byte, color: byte) -> Rgb555 {
+        palette_color(&self.cgb.bg_palettes[..], palette, color)
+    }
+
+    pub fn obj_palette_color(&self, palette: byte, color: byte) -> Rgb555 {
+        palette_color(&self.cgb.obj_palettes[..], palette, color)
+    }
+
+    pub fn read_cgb_register(&self, addr: usize) -> byte {
+        if !self.cgb_mode() {
+            return 0xFF;
+        }
+        let cgb = &self.cgb;
+        match addr {
+            KEY1_ADDR => 0x7E | ((cgb.double_speed as byte) << 7) | cgb.speed_switch_armed as byte,
+            VBK_ADDR => 0xFE | self.vram.selected_bank() as byte,
+            HDMA5_ADDR => {
+                // Bit 7 is set once the transfer is over, 0xFF after
+                // it completed
+                let left = cgb.hdma_blocks.wrapping_sub(1) & 0x7F;
+                if cgb.hdma_hblank { left } else { 0x80 | left }
+            }
+            BCPS_ADDR => 0x40 | cgb.bcps,
+            BCPD_ADDR => cgb.bg_palettes[(cgb.bcps & 0x3F) as usize],
+            OCPS_ADDR => 0x40 | cgb.ocps,
+            OCPD_ADDR => cgb.obj_palettes[(cgb.ocps & 0x3F) as usize],
+            SVBK_ADDR => 0xF8 | self.wram.selected_bank() as byte,
+            _ => 0xFF,
+        }
+    }
+
+    pub fn write_cgb_register(&mut self, addr: usize, value: byte) {
+        if !self.cgb_mode() {
+            return;
+        }
+        match addr {
+            KEY1_ADDR => self.cgb.speed_switch_armed = value & 1 == 1,
+            VBK_ADDR => self.vram.select_bank(value as usize),
+            HDMA1_ADDR => {
+                self.cgb.hdma_source = (self.cgb.hdma_source & 0x00FF) | ((value as u16) << 8)
+            }
+            HDMA2_ADDR => {
+                self.cgb.hdma_source = (self.cgb.hdma_source & 0xFF00) | (value as u16 & 0xF0)
+            }
+            HDMA3_ADDR => {
+                self.cgb.hdma_dest = (self.cgb.hdma_dest & 0x00FF) | ((value as u16 & 0x1F) << 8)
+            }
+            HDMA4_ADDR => {
+                self.cgb.hdma_dest = (self.cgb.hdma_dest & 0xFF00) | (value as u16 & 0xF0)
+            }
+            HDMA5_ADDR => self.write_hdma5(value),
+            BCPS_ADDR => self.cgb.bcps = value & 0xBF,
+            BCPD_ADDR => {
+                let index = self.cgb.bcps;
+                self.cgb.bg_palettes[(index & 0x3F) as usize] = value;
+                self.cgb.bcps = next_palette_index(index);
+            }
+            OCPS_ADDR => self.cgb.ocps = value & 0xBF,
+            OCPD_ADDR => {
+                let index = self.cgb.ocps;
+                self.cgb.obj_palettes[(index & 0x3F) as usize] = value;
+                self.cgb.ocps = next_palette_index(index);
+            }
+            SVBK_ADDR => self.wram.select_bank(value as usize),
+            _ => (),
+        }
+    }
+
+    /// Puts the CGB hardware in its power on state, in CGB mode if
+    /// `enabled`
+    pub fn reset_cgb(&mut self, enabled: bool) {
+        self.cgb = Cgb::new(enabled);
+        self.vram.select_bank(0);
+        self.wram.select_bank(1);
+    }
+
+    /// Called by STOP, returns whether the speed was switched instead
+    /// of stopping
+    pub fn switch_speed(&mut self) -> bool {
+        if !self.cgb_mode() || !self.cgb.speed_switch_armed {
+            return false;
+        }
+        self.cgb.double_speed = !self.cgb.double_speed;
+        self.cgb.speed_switch_armed = false;
+        debug!("Switched to {} speed",
+               if self.cgb.double_speed { "double" } else { "normal" });
+        true
+    }
+
+    fn write_hdma5(&mut self, value: byte) {
+        let blocks = (value & 0x7F) + 1;
+        if value & 0x80 != 0 {
+            self.cgb.hdma_blocks = blocks;
+            self.cgb.hdma_hblank = true;
+        } else if self.cgb.hdma_hblank {
+            // Writing with bit 7 clear stops a HBlank DMA
+            self.cgb.hdma_hblank = false;
+        } else {
+            // General purpose DMA copies everything at once while the
+            // CPU waits
+            self.cgb.hdma_blocks = blocks;
+            let block_cycles = if self.cgb.double_speed {
+                2 * HDMA_BLOCK_CYCLES
+            } else {
+                HDMA_BLOCK_CYCLES
+            };
+            while self.cgb.hdma_blocks > 0 {
+                self.hdma_copy_block();
+                self.advance_cycles(block_cycles);
+            }
+        }
+    }
+
+    /// Copies the next block of a HBlank DMA, called when the PPU
+    /// enters HBlank on a visible line
+    pub fn hdma_hblank(&mut self) {
+        if self.cgb.hdma_hblank {
+            self.hdma_copy_block();
+            if self.cgb.hdma_blocks == 0 {
+                self.cgb.hdma_hblank = false;
+            }
+        }
+    }
+
+    fn hdma_copy_block(&mut self) {
+        for i in 0..HDMA_BLOCK_SIZE {
+            let source = self.cgb.hdma_source.wrapping_add(i);
+            let value = self.read_mem(source);
+            let dest = DISPLAY_RAM_START + ((self.cgb.hdma_dest + i) as usize & 0x1FFF);
+            self.vram.write(dest as MemAddr, value);
+        }
+        self.cgb.hdma_source = self.cgb.hdma_source.wrapping_add(HDMA_BLOCK_SIZE);
+        self.cgb.hdma_dest = (self.cgb.hdma_dest + HDMA_BLOCK_SIZE) & 0x1FF0;
+        self.cgb.hdma_blocks -= 1;
+    }
+}
+
+/// BCPS and OCPS advance after each write to the data register if
+/// bit 7 is set
+fn next_palette_index(index: byte) -> byte {
+    if index & 0x80 != 0 {
+        0x80 | ((index + 1) & 0x3F)
+    } else {
+        index
+    }
+}
@@ -13,11 +13,6 @@ pub const HL: byte = 0x20;
 /// Location of the `C` flag in the `F` register
 pub const CL: byte = 0x10;
 
-/// The size of the Gameboy's memory
-/// Additional 3 bytes to skip bounds check when fetching instr. operands
-pub const MEM_ARRAY_SIZE: usize = 0xFFFF + 1 + 3;
-
-
 /// Where the PC should go when the vblank interupt is handled
 pub const VBLANK_INTERRUPT_ADDRESS: u16 = 0x40;
 pub const LCDC_INTERRUPT_ADDRESS: u16 = 0x48;
@@ -35,12 +30,26 @@ pub const DISPLAY_RAM_END: usize = 0x9FFF;
 /// of the PPU
 pub const OAM_START: usize = 0xFE00;
 pub const OAM_END: usize = 0xFE9F;
-/// TODO: List what STAT contains
+/// LCD status: interrupt enables (bits 3-6), LY=LYC flag (bit 2) and
+/// the PPU mode (bits 0-1)
 pub const STAT_ADDR: usize = 0xFF41;
+/// Scanline currently being drawn
+pub const LY_ADDR: usize = 0xFF44;
 
 
 
 
+/// When the hardware next to the CPU runs during an instruction
+#[derive(Debug,Clone,Copy,PartialEq)]
+pub enum TimingMode {
+    /// After the whole instruction has executed, which is faster
+    PerInstruction,
+    /// Before each memory access, one M-cycle (4 cycles) at a time,
+    /// so the timer, PPU and DMA see reads and writes at the cycle
+    /// they happen
+    PerAccess,
+}
+
 /// The state of the CPU's execution
 #[derive(Debug,Clone,Copy,PartialEq)]
 pub enum CpuState {
@@ -94,9 +103,9 @@ pub enum Cc {
 }
 
 /// The type of ROM
-/// Located in the ROM itself at addr (TODO: this)
-#[derive(Debug, PartialEq)]
-enum CartridgeType {
+/// Located in the ROM itself at addr 0x147
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum CartridgeType {
     /// The only ROM type being targeted for version 0.1.0
     RomOnly = 0,
     RomMBC1 = 1,
@@ -109,6 +118,7 @@ enum CartridgeType {
     RomMMM01 = 0xB,
     RomMMM01SRam = 0xC,
     RomMMM01SRamBatt = 0xD,
+    RomMBC3TimerBatt = 0xF,
     RomMBC3TimerRamBatt = 0x10,
     RomMBC3 = 0x11,
     RomMBC3Ram = 0x12,
@@ -124,6 +134,63 @@ enum CartridgeType {
     HudsonHuC1 = 0xFF,
 }
 
+impl CartridgeType {
+    /// Decodes the cartridge type byte of the ROM header
+    pub fn from_byte(type_byte: u8) -> Option<CartridgeType> {
+        use self::CartridgeType::*;
+
+        Some(match type_byte {
+            0x00 => RomOnly,
+            0x01 => RomMBC1,
+            0x02 => RomMBC1Ram,
+            0x03 => RomMBC1RamBatt,
+            0x05 => RomMBC2,
+            0x06 => RomMBC2Batt,
+            0x08 => RomRam,
+            0x09 => RomRamBatt,
+            0x0B => RomMMM01,
+            0x0C => RomMMM01SRam,
+            0x0D => RomMMM01SRamBatt,
+            0x0F => RomMBC3TimerBatt,
+            0x10 => RomMBC3TimerRamBatt,
+            0x11 => RomMBC3,
+            0x12 => RomMBC3Ram,
+            0x13 => RomMBC3RamBatt,
+            0x19 => RomMBC5,
+            0x1A => RomMBC5Ram,
+            0x1B => RomMBC5RamBatt,
+            0x1D => RomMBC5RumbleSRam,
+            0x1E => RomMBC5RumbleSRamBatt,
+            0x1F => PocketCamera,
+            0xFD => BandaiTAMA5,
+            0xFE => HudsonHuC3,
+            0xFF => HudsonHuC1,
+            _ => return None,
+        })
+    }
+
+    /// Whether the cartridge has a battery keeping its RAM alive
+    pub fn has_battery(&self) -> bool {
+        use self::CartridgeType::*;
+
+        match *self {
+            RomMBC1RamBatt | RomMBC2Batt | RomRamBatt | RomMMM01SRamBatt |
+            RomMBC3TimerBatt | RomMBC3TimerRamBatt | RomMBC3RamBatt | RomMBC5RamBatt |
+            RomMBC5RumbleSRamBatt | HudsonHuC1 => true,
+            _ => false,
+        }
+    }
+
+    /// Whether the cartridge has an MBC3 real time clock
+    pub fn has_rtc(&self) -> bool {
+        match *self {
+            CartridgeType::RomMBC3TimerBatt |
+            CartridgeType::RomMBC3TimerRamBatt => true,
+            _ => false,
+        }
+    }
+}
+
 /// Turns a number into a `CC` code
 /// used in dispatching opcodes
 pub fn cc_dispatch(num: u8) -> Cc {
@@ -0,0 +1,138 @@
+//! OAM DMA
+//!
+//! Writing the upper byte of a source address to 0xFF46 copies 160
+//! bytes from there to OAM, one byte per M-cycle after an M-cycle of
+//! setup.  While the copy runs the DMA owns the bus, so the CPU can
+//! only use the I/O registers and HRAM, reads of anything else return
+//! 0xFF and writes are lost.  This is why games copy a small routine
+//! to HRAM which starts the DMA and waits there until it is done.
+
+use cpu::Cpu;
+use cpu::constants::*;
+use cpu::memory::*;
+use cpu::savestate::*;
+
+pub const DMA_ADDR: usize = 0xFF46;
+/// Bytes copied by a transfer, all of OAM
+pub const DMA_LENGTH: u8 = 0xA0;
+
+/// Cycles before the first byte is copied
+const DMA_SETUP_CYCLES: u32 = 4;
+const DMA_BYTE_CYCLES: u32 = 4;
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct OamDma {
+    /// Address of the first byte copied
+    pub source: MemAddr,
+    /// Bytes copied so far by a running transfer
+    copied: Option<u8>,
+    /// Cycles left of a transfer which was started but hasn't begun
+    /// copying yet
+    setup_cycles: Option<u32>,
+    /// Cycles run which didn't make up a whole byte
+    pending_cycles: u32,
+}
+
+impl OamDma {
+    pub fn new() -> OamDma {
+        OamDma {
+            source: 0,
+            copied: None,
+            setup_cycles: None,
+            pending_cycles: 0,
+        }
+    }
+
+    /// Bytes copied so far while a transfer is running
+    pub fn progress(&self) -> Option<u8> {
+        self.copied
+    }
+}
+
+impl SaveState for OamDma {
+    fn write_state(&self, w: &mut StateWriter) {
+        w.u16(self.source);
+        // Nothing copied yet is stored as 0, not running as 0xFF
+        w.u8(self.copied.unwrap_or(0xFF));
+        w.u32(self.setup_cycles.unwrap_or(0));
+        w.u32(self.pending_cycles);
+    }
+
+    fn read_state(&mut self, r: &mut StateReader) -> Result<(), SaveStateError> {
+        self.source = r.u16()?;
+        self.copied = match r.u8()? {
+            0xFF => None,
+            n if n < DMA_LENGTH => Some(n),
+            _ => return Err(SaveStateError::Corrupt),
+        };
+        self.setup_cycles = match r.u32()? {
+            0 => None,
+            n => Some(n),
+        };
+        self.pending_cycles = r.u32()?;
+        Ok(())
+    }
+}
+
+impl Cpu {
+    /// Starts a transfer from `high << 8`.  A transfer which is still
+    /// running stops, but the bus stays blocked while the new one is
+    /// set up.
+    pub fn start_dma(&mut self, high: byte) {
+        self.io[DMA_ADDR] = high;
+        self.dma.source = (high as MemAddr) << 8;
+        self.dma.setup_cycles = Some(DMA_SETUP_CYCLES);
+    }
+
+    /// Whether the DMA keeps the CPU from accessing `address`
+    pub fn dma_blocks(&self, address: MemAddr) -> bool {
+        self.dma.copied.is_some() && (address as usize) < IO_REGISTERS_START
+    }
+
+    /// Runs the DMA for `cycles` CPU cycles
+    pub fn step_dma(&mut self, cycles: u32) {
+        if self.dma.copied.is_none() && self.dma.setup_cycles.is_none() {
+            return;
+        }
+        self.dma.pending_cycles += cycles;
+        while self.dma.pending_cycles >= DMA_BYTE_CYCLES {
+            self.dma.pending_cycles -= DMA_BYTE_CYCLES;
+
+            if let Some(left) = self.dma.setup_cycles {
+                if left > DMA_BYTE_CYCLES {
+                    self.dma.setup_cycles = Some(left - DMA_BYTE_CYCLES);
+                } else {
+                    self.dma.setup_cycles = None;
+                    self.dma.copied = Some(0);
+                }
+                continue;
+            }
+
+            let index = match self.dma.copied {
+                Some(index) => index,
+                None => break,
+            };
+            self.copy_dma_byte(index);
+            self.dma.copied = if index + 1 < DMA_LENGTH {
+                Some(index + 1)
+            } else {
+                trace!("OAM DMA from 0x{:04X} done", self.dma.source);
+                None
+            };
+        }
+        if self.dma.copied.is_none() && self.dma.setup_cycles.is_none() {
+            self.dma.pending_cycles = 0;
+        }
+    }
+
+    fn copy_dma_byte(&mut self, index: u8) {
+        let mut source = self.dma.source.wrapping_add(index as MemAddr);
+        // The DMA sees work RAM above 0xDFFF, like echo RAM
+        if source as usize >= ECHO_RAM_START {
+            source -= (ECHO_RAM_START - WRAM_START) as MemAddr;
+        }
+        let value = self.read_mem(source);
+        let dest = OAM_START as MemAddr + index as MemAddr;
+        self.oam.write(dest, value);
+    }
+}
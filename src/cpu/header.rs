@@ -0,0 +1,312 @@
+//! Cartridge header
+//!
+//! Every ROM describes itself in the bytes 0x100-0x14F: the entry
+//! point, the Nintendo logo checked by the boot ROM, the title, which
+//! hardware it runs on, its MBC and memory sizes and two checksums.
+//! The boot ROM refuses to start a cartridge with a bad logo or header
+//! checksum, the global checksum isn't checked by anything, but a
+//! mismatch hints at a bad dump.
+
+use std::fmt;
+
+use cpu::constants::*;
+
+/// Everything up to here is part of the header
+pub const HEADER_END: usize = 0x150;
+
+const LOGO_START: usize = 0x104;
+const TITLE_START: usize = 0x134;
+const MANUFACTURER_START: usize = 0x13F;
+const CGB_FLAG_ADDR: usize = 0x143;
+const NEW_LICENSEE_ADDR: usize = 0x144;
+const SGB_FLAG_ADDR: usize = 0x146;
+const CARTRIDGE_TYPE_ADDR: usize = 0x147;
+const ROM_SIZE_ADDR: usize = 0x148;
+const RAM_SIZE_ADDR: usize = 0x149;
+const DESTINATION_ADDR: usize = 0x14A;
+const OLD_LICENSEE_ADDR: usize = 0x14B;
+const VERSION_ADDR: usize = 0x14C;
+const HEADER_CHECKSUM_ADDR: usize = 0x14D;
+const GLOBAL_CHECKSUM_ADDR: usize = 0x14E;
+
+/// Old licensee code telling that the new one is used instead
+const USE_NEW_LICENSEE: byte = 0x33;
+/// SGB flag of cartridges with Super Game Boy functions
+const SGB_SUPPORTED: byte = 0x03;
+
+/// The logo scrolled down by the boot ROM
+pub const NINTENDO_LOGO: [byte; 48] = [0xCE, 0xED, 0x66, 0x66, 0xCC, 0x0D, 0x00, 0x0B, 0x03, 0x73,
+                                       0x00, 0x83, 0x00, 0x0C, 0x00, 0x0D, 0x00, 0x08, 0x11, 0x1F,
+                                       0x88, 0x89, 0x00, 0x0E, 0xDC, 0xCC, 0x6E, 0xE6, 0xDD, 0xDD,
+                                       0xD9, 0x99, 0xBB, 0xBB, 0x67, 0x63, 0x6E, 0x0E, 0xEC, 0xCC,
+                                       0xDD, 0xDC, 0x99, 0x9F, 0xBB, 0xB9, 0x33, 0x3E];
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum HeaderError {
+    /// The ROM ends before the header does, holds its length
+    TooShort(usize),
+}
+
+impl fmt::Display for HeaderError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match *self {
+            HeaderError::TooShort(len) => {
+                write!(f,
+                       "the ROM is {} bytes long, too short to have a header",
+                       len)
+            }
+        }
+    }
+}
+
+/// Who published the game
+#[derive(Debug, Clone, PartialEq)]
+pub enum Licensee {
+    /// Code of older cartridges
+    Old(byte),
+    /// Two character code of cartridges made after the SGB
+    New(String),
+}
+
+impl fmt::Display for Licensee {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match *self {
+            Licensee::Old(code) => write!(f, "0x{:02X}", code),
+            Licensee::New(ref code) => write!(f, "\"{}\"", code),
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct CartridgeHeader {
+    pub title: String,
+    /// Four character code, only in some newer cartridges
+    pub manufacturer_code: Option<String>,
+    pub cgb_flag: byte,
+    pub sgb_flag: byte,
+    pub cartridge_type_code: byte,
+    pub licensee: Licensee,
+    pub rom_size_code: byte,
+    pub ram_size_code: byte,
+    /// Sold in Japan
+    pub japanese: bool,
+    /// Mask ROM version number, usually 0
+    pub version: byte,
+    pub header_checksum: byte,
+    pub global_checksum: u16,
+    pub logo_valid: bool,
+    /// Checksums of the ROM's contents, to compare with the stored ones
+    pub computed_header_checksum: byte,
+    pub computed_global_checksum: u16,
+    /// Size of the ROM the header was read from
+    pub file_size: usize,
+}
+
+impl CartridgeHeader {
+    /// Reads the header of `rom`, which must at least include all of it
+    pub fn parse(rom: &[byte]) -> Result<CartridgeHeader, HeaderError> {
+        if rom.len() < HEADER_END {
+            return Err(HeaderError::TooShort(rom.len()));
+        }
+
+        let cgb_flag = rom[CGB_FLAG_ADDR];
+        // CGB cartridges took the end of the title for the CGB flag
+        // and often a manufacturer code
+        let (title_end, manufacturer_code) = if cgb_flag & 0x80 != 0 {
+            let code = &rom[MANUFACTURER_START..CGB_FLAG_ADDR];
+            let is_code = code.iter().all(|&c| match c {
+                b'A'...b'Z' | b'0'...b'9' => true,
+                _ => false,
+            });
+            if is_code {
+                (MANUFACTURER_START, Some(ascii_string(code)))
+            } else {
+                (CGB_FLAG_ADDR, None)
+            }
+        } else {
+            (CGB_FLAG_ADDR + 1, None)
+        };
+        let title_bytes = &rom[TITLE_START..title_end];
+        let title_len = title_bytes.iter().position(|&c| c == 0).unwrap_or(title_bytes.len());
+
+        let licensee = match rom[OLD_LICENSEE_ADDR] {
+            USE_NEW_LICENSEE => Licensee::New(ascii_string(&rom[NEW_LICENSEE_ADDR..SGB_FLAG_ADDR])),
+            code => Licensee::Old(code),
+        };
+
+        Ok(CartridgeHeader {
+            title: ascii_string(&title_bytes[..title_len]),
+            manufacturer_code: manufacturer_code,
+            cgb_flag: cgb_flag,
+            sgb_flag: rom[SGB_FLAG_ADDR],
+            cartridge_type_code: rom[CARTRIDGE_TYPE_ADDR],
+            licensee: licensee,
+            rom_size_code: rom[ROM_SIZE_ADDR],
+            ram_size_code: rom[RAM_SIZE_ADDR],
+            japanese: rom[DESTINATION_ADDR] == 0,
+            version: rom[VERSION_ADDR],
+            header_checksum: rom[HEADER_CHECKSUM_ADDR],
+            global_checksum: ((rom[GLOBAL_CHECKSUM_ADDR] as u16) << 8) |
+                             rom[GLOBAL_CHECKSUM_ADDR + 1] as u16,
+            logo_valid: rom[LOGO_START..LOGO_START + NINTENDO_LOGO.len()] == NINTENDO_LOGO[..],
+            computed_header_checksum: header_checksum(rom),
+            computed_global_checksum: global_checksum(rom),
+            file_size: rom.len(),
+        })
+    }
+
+    pub fn cartridge_type(&self) -> Option<CartridgeType> {
+        CartridgeType::from_byte(self.cartridge_type_code)
+    }
+
+    /// Uses the Game Boy Color's features
+    pub fn cgb_supported(&self) -> bool {
+        self.cgb_flag & 0x80 != 0
+    }
+
+    /// Doesn't run on the original Game Boy
+    pub fn cgb_only(&self) -> bool {
+        self.cgb_flag == 0xC0
+    }
+
+    pub fn sgb_supported(&self) -> bool {
+        self.sgb_flag == SGB_SUPPORTED
+    }
+
+    /// Size of the ROM in bytes according to the header
+    pub fn rom_size(&self) -> Option<usize> {
+        match self.rom_size_code {
+            n @ 0...8 => Some(0x8000 << n),
+            _ => None,
+        }
+    }
+
+    /// Size of the external RAM in bytes according to the header
+    pub fn ram_size(&self) -> Option<usize> {
+        ram_size_from_code(self.ram_size_code)
+    }
+
+    /// Problems with the header, which may mean that the ROM is a bad
+    /// dump or was modified
+    pub fn warnings(&self) -> Vec<String> {
+        let mut warnings = vec![];
+        if !self.logo_valid {
+            warnings.push("the Nintendo logo is wrong, the boot ROM would lock up".to_string());
+        }
+        if self.header_checksum != self.computed_header_checksum {
+            warnings.push(format!("bad header checksum 0x{:02X}, expected 0x{:02X}",
+                                  self.header_checksum,
+                                  self.computed_header_checksum));
+        }
+        if self.global_checksum != self.computed_global_checksum {
+            warnings.push(format!("bad global checksum 0x{:04X}, expected 0x{:04X}",
+                                  self.global_checksum,
+                                  self.computed_global_checksum));
+        }
+        if self.cartridge_type().is_none() {
+            warnings.push(format!("unknown cartridge type 0x{:02X}", self.cartridge_type_code));
+        }
+        match self.rom_size() {
+            Some(size) if size != self.file_size => {
+                warnings.push(format!("the header gives a ROM size of {} bytes, the file has {}",
+                                      size,
+                                      self.file_size))
+            }
+            Some(_) => (),
+            None => warnings.push(format!("unknown ROM size code 0x{:02X}", self.rom_size_code)),
+        }
+        if self.ram_size().is_none() {
+            warnings.push(format!("unknown RAM size code 0x{:02X}", self.ram_size_code));
+        }
+        warnings
+    }
+}
+
+impl fmt::Display for CartridgeHeader {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        writeln!(f, "Title:           {}", self.title)?;
+        if let Some(ref code) = self.manufacturer_code {
+            writeln!(f, "Manufacturer:    {}", code)?;
+        }
+        writeln!(f, "Licensee:        {}", self.licensee)?;
+        writeln!(f,
+                 "CGB:             {}",
+                 if self.cgb_only() {
+                     "only"
+                 } else if self.cgb_supported() {
+                     "supported"
+                 } else {
+                     "no"
+                 })?;
+        writeln!(f,
+                 "SGB:             {}",
+                 if self.sgb_supported() { "supported" } else { "no" })?;
+        match self.cartridge_type() {
+            Some(t) => writeln!(f, "Cartridge type:  {:?} (0x{:02X})", t, self.cartridge_type_code)?,
+            None => writeln!(f, "Cartridge type:  unknown (0x{:02X})", self.cartridge_type_code)?,
+        }
+        match self.rom_size() {
+            Some(size) => writeln!(f, "ROM size:        {}KiB", size / 1024)?,
+            None => writeln!(f, "ROM size:        unknown (0x{:02X})", self.rom_size_code)?,
+        }
+        match self.ram_size() {
+            Some(size) => writeln!(f, "RAM size:        {}KiB", size / 1024)?,
+            None => writeln!(f, "RAM size:        unknown (0x{:02X})", self.ram_size_code)?,
+        }
+        writeln!(f,
+                 "Destination:     {}",
+                 if self.japanese { "Japan" } else { "elsewhere" })?;
+        writeln!(f, "Version:         {}", self.version)?;
+        writeln!(f,
+                 "Header checksum: 0x{:02X} ({})",
+                 self.header_checksum,
+                 if self.header_checksum == self.computed_header_checksum {
+                     "ok"
+                 } else {
+                     "bad"
+                 })?;
+        write!(f,
+               "Global checksum: 0x{:04X} ({})",
+               self.global_checksum,
+               if self.global_checksum == self.computed_global_checksum {
+                   "ok"
+               } else {
+                   "bad"
+               })
+    }
+}
+
+/// Decodes the RAM size code at 0x149 of the header
+pub fn ram_size_from_code(size_code: byte) -> Option<usize> {
+    match size_code {
+        0 => Some(0),
+        1 => Some(0x800),
+        2 => Some(0x2000),
+        3 => Some(0x8000),
+        4 => Some(0x20000),
+        5 => Some(0x10000),
+        _ => None,
+    }
+}
+
+/// The checksum over 0x134-0x14C checked by the boot ROM
+pub fn header_checksum(rom: &[byte]) -> byte {
+    rom[TITLE_START..HEADER_CHECKSUM_ADDR]
+        .iter()
+        .fold(0u8, |sum, &b| sum.wrapping_sub(b).wrapping_sub(1))
+}
+
+/// The sum of all bytes of the ROM but the global checksum itself
+pub fn global_checksum(rom: &[byte]) -> u16 {
+    rom.iter()
+        .enumerate()
+        .filter(|&(i, _)| i != GLOBAL_CHECKSUM_ADDR && i != GLOBAL_CHECKSUM_ADDR + 1)
+        .fold(0u16, |sum, (_, &b)| sum.wrapping_add(b as u16))
+}
+
+/// Printable ASCII, anything else becomes '?'
+fn ascii_string(data: &[byte]) -> String {
+    data.iter()
+        .map(|&c| if c >= 0x20 && c < 0x7F { c as char } else { '?' })
+        .collect()
+}
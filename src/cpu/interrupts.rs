@@ -0,0 +1,71 @@
+//! Interrupt controller
+//!
+//! Devices request interrupts by setting their bit in IF, whatever
+//! the state of IME and IE.  A request is pending when its bit is set
+//! in both IF and IE; pending requests wake the CPU from HALT, but they
+//! are only serviced while IME is set, lowest bit first.
+
+use cpu::Cpu;
+use cpu::constants::*;
+
+pub const IF_ADDR: usize = 0xFF0F;
+pub const IE_ADDR: usize = 0xFFFF;
+
+/// Cycles taken to push PC and jump to an interrupt handler
+pub const INTERRUPT_DISPATCH_CYCLES: u8 = 20;
+
+/// Handlers in priority order, indexed by their bit in IF and IE
+const INTERRUPT_VECTORS: [MemAddr; 5] = [VBLANK_INTERRUPT_ADDRESS,
+                                         LCDC_INTERRUPT_ADDRESS,
+                                         TIMER_OVERFLOW_INTERRUPT_ADDRESS,
+                                         SERIAL_TRANSFER_INTERRUPT_ADDRESS,
+                                         P1013_INTERRUPT_ADDRESS];
+
+impl Cpu {
+    /// Interrupts which are both requested and enabled
+    pub fn pending_interrupts(&self) -> byte {
+        self.io[IF_ADDR] & self.io[IE_ADDR] & 0x1F
+    }
+
+    /// Only the lower 5 bits of IF exist, the rest read as 1
+    pub fn write_if(&mut self, value: byte) {
+        self.io[IF_ADDR] = 0xE0 | value;
+    }
+
+    /// Jumps to the handler of the highest priority pending interrupt
+    /// if IME is set.  Returns whether an interrupt was serviced.
+    pub fn handle_interrupts(&mut self) -> bool {
+        let pending = self.pending_interrupts();
+        if !self.ime || pending == 0 || self.state != CpuState::Normal {
+            return false;
+        }
+
+        let bit = pending.trailing_zeros() as usize;
+        trace!("INT: handle interrupt {} -> 0x{:02X}", bit, INTERRUPT_VECTORS[bit]);
+
+        self.ime = false;
+        self.ime_scheduled = false;
+        self.io[IF_ADDR] &= !(1 << bit);
+
+        // EI followed by a HALT that hit the HALT bug returns to the HALT
+        if self.halt_bug {
+            self.halt_bug = false;
+            self.pc = self.pc.wrapping_sub(1);
+        }
+        // The first two M-cycles are spent waiting, the second one as
+        // part of pushing PC
+        self.m_cycle();
+        let old_pc = self.pc;
+        self.push_onto_stack(old_pc);
+        self.pc = INTERRUPT_VECTORS[bit];
+
+        true
+    }
+
+    /// HALT ends as soon as an interrupt is pending, even if IME is off
+    pub fn wake_from_halt(&mut self) {
+        if self.state == CpuState::Halt && self.pending_interrupts() != 0 {
+            self.state = CpuState::Normal;
+        }
+    }
+}
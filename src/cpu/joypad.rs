@@ -0,0 +1,68 @@
+//! Joypad register
+//!
+//! The eight buttons are wired as a 2x4 matrix.  Writing 0 to bit 4 of
+//! JOYP selects the direction keys and 0 to bit 5 the other buttons;
+//! the lower four bits read back as 0 for pressed buttons of the
+//! selected rows.  With both rows selected a line is low if either
+//! button on it is pressed, with neither it stays high.  A line going
+//! from high to low, by a button press or by selecting a row in which
+//! a button is held, requests the joypad interrupt and ends STOP.
+
+use cpu::Cpu;
+use cpu::constants::*;
+
+pub const JOYP_ADDR: usize = 0xFF00;
+
+const SELECT_DIRECTIONS: byte = 0x10;
+const SELECT_BUTTONS: byte = 0x20;
+const SELECT_MASK: byte = SELECT_DIRECTIONS | SELECT_BUTTONS;
+const LINES_MASK: byte = 0x0F;
+
+impl Cpu {
+    /// JOYP as the program reads it, computed from the select bits
+    /// and the buttons held right now
+    pub fn joypad_register(&self) -> byte {
+        let select = self.io[JOYP_ADDR] & SELECT_MASK;
+        let mut lines = LINES_MASK;
+        if select & SELECT_DIRECTIONS == 0 {
+            lines &= self.input_state & LINES_MASK;
+        }
+        if select & SELECT_BUTTONS == 0 {
+            lines &= self.input_state >> 4;
+        }
+        0xC0 | select | lines
+    }
+
+    /// Only the select bits can be written
+    pub fn write_joypad(&mut self, value: byte) {
+        let old = self.joypad_register();
+        self.io[JOYP_ADDR] = (self.io[JOYP_ADDR] & !SELECT_MASK) | (value & SELECT_MASK);
+        self.update_joypad(old);
+    }
+
+    /// The held buttons, one bit per button with 0 for pressed
+    pub fn input_state(&self) -> byte {
+        self.input_state
+    }
+
+    /// Sets the held buttons, one bit per button with 0 for pressed
+    /// like `input_state`
+    pub fn set_input_state(&mut self, input_state: byte) {
+        let old = self.joypad_register();
+        self.input_state = input_state;
+        self.update_joypad(old);
+    }
+
+    /// Brings the copy of JOYP in `io` up to date after it changed
+    /// from `old`, and reacts to lines which went low
+    fn update_joypad(&mut self, old: byte) {
+        let new = self.joypad_register();
+        self.io[JOYP_ADDR] = new;
+        if old & !new & LINES_MASK != 0 {
+            self.set_input_interrupt_bit();
+            if self.state == CpuState::Stop {
+                self.state = CpuState::Normal;
+            }
+        }
+    }
+}
@@ -5,9 +5,9 @@ macro_rules! setter_unsetter_and_getter {
             ($name:ident, $location:expr) => {
                 //TODO: maybe add an option for setting them public?
                 pub fn $name(&mut self) {
-                    let orig_val = self.mem[$memory_location] as u8;
+                    let orig_val = self.io[$memory_location] as u8;
 
-                    self.mem[$memory_location] = (orig_val | $location) as byte;
+                    self.io[$memory_location] = (orig_val | $location) as byte;
                 }
             }
         }
@@ -15,9 +15,9 @@ macro_rules! setter_unsetter_and_getter {
         macro_rules! $name_unsetter {
             ($name:ident, $location:expr) => {
                 fn $name(&mut self) {
-                    let orig_val = self.mem[$memory_location] as u8;
+                    let orig_val = self.io[$memory_location] as u8;
 
-                    self.mem[$memory_location] = (orig_val & (!$location)) as byte;
+                    self.io[$memory_location] = (orig_val & (!$location)) as byte;
                 }
             }
         }
@@ -25,7 +25,7 @@ macro_rules! setter_unsetter_and_getter {
         macro_rules! $name_getter {
             ($name:ident, $location:expr) => {
                 pub fn $name(&self) -> bool{
-                    ((self.mem[$memory_location] as u8) & $location)
+                    ((self.io[$memory_location] as u8) & $location)
                         == $location
                 }
             }
@@ -38,7 +38,7 @@ macro_rules! make_getter {
         macro_rules! $ident {
             ($name:ident, $location:expr) => {
                 fn $name(&self) -> bool {
-                    (self.mem[$memory_location] & $location)
+                    (self.io[$memory_location] & $location)
                         == $location
                 }
             }
@@ -47,10 +47,6 @@ macro_rules! make_getter {
 }
 
 
-//NOTE: look into separate sound on/off storage outside of
-// GB memory to prevent subtle "bug"/non-correct behavior
-
-setter_unsetter_and_getter!(set_sound_on, unset_sound_on, get_sound_on, 0xFF26);
 setter_unsetter_and_getter!(set_interrupt_bit, unset_interrupt_bit, get_interrupt, 0xFF0F);
 setter_unsetter_and_getter!(set_interrupt_enabled, unset_interrupt_enabled, get_interrupt_enabled, 0xFFFF);
 setter_unsetter_and_getter!(set_stat, unset_stat, get_stat, 0xFF41);
@@ -95,17 +91,13 @@ TODO: fix Rust macro system to allow this or update interpolate_idents
 macro_rules! button {
     ($press_button:ident, $unpress_button:ident, $location:expr) => {
         pub fn $press_button(&mut self) {
-            let old_val = self.input_state;
-            self.input_state = (old_val & (!$location)) as byte;
-            if self.state == CpuState::Stop {
-                self.state = CpuState::Normal;
-            }
-            self.set_input_interrupt_bit();
+            let input_state = self.input_state & !$location;
+            self.set_input_state(input_state);
         }
         
         pub fn $unpress_button(&mut self) {
-            let old_val = self.input_state;
-            self.input_state = (old_val | $location) as byte;
+            let input_state = self.input_state | $location;
+            self.set_input_state(input_state);
         }
     }
 }
@@ -0,0 +1,248 @@
+//! Devices on the memory bus
+//!
+//! Every region of the Game Boy's address space is handled by a device
+//! implementing `MemoryMapped`, with `Cpu::get_mem` and `Cpu::set_mem`
+//! routing accesses to them.  The devices are the only place memory is
+//! kept, anything else looking at memory goes through `Cpu::peek` or
+//! the devices themselves.  The whole address space can also be
+//! replaced by a `Bus`, to run the CPU against plain or instrumented
+//! memory without any of the Game Boy's hardware.
+
+use std::ops::{Index, IndexMut};
+
+use cpu::constants::*;
+use cpu::savestate::*;
+
+pub const WRAM_START: usize = 0xC000;
+pub const WRAM_END: usize = 0xDFFF;
+/// Echo of work RAM
+pub const ECHO_RAM_START: usize = 0xE000;
+pub const ECHO_RAM_END: usize = 0xFDFF;
+/// Start of the switchable work RAM bank on the Game Boy Color
+pub const WRAM_BANK_X_START: usize = 0xD000;
+pub const VRAM_BANK_SIZE: usize = 0x2000;
+pub const WRAM_BANK_SIZE: usize = 0x1000;
+/// Between OAM and the I/O registers, reads as 0 and ignores writes
+pub const UNUSABLE_START: usize = 0xFEA0;
+pub const UNUSABLE_END: usize = 0xFEFF;
+pub const IO_REGISTERS_START: usize = 0xFF00;
+pub const IO_REGISTERS_END: usize = 0xFF7F;
+pub const HRAM_START: usize = 0xFF80;
+pub const HRAM_END: usize = 0xFFFE;
+
+/// Something the CPU can read from and write to
+pub trait MemoryMapped {
+    /// Reads without any side effects, for debuggers and the like
+    fn peek(&self, addr: MemAddr) -> byte;
+    fn write(&mut self, addr: MemAddr, value: byte);
+
+    /// Reads as the CPU does, devices whose reads have side effects
+    /// override it
+    fn read(&mut self, addr: MemAddr) -> byte {
+        self.peek(addr)
+    }
+}
+
+/// An address space replacing the Game Boy's, see `Cpu::with_bus`
+pub trait Bus: MemoryMapped {
+    /// Needed to clone the CPU which owns it
+    fn box_clone(&self) -> Box<Bus>;
+}
+
+impl<T: MemoryMapped + Clone + 'static> Bus for T {
+    fn box_clone(&self) -> Box<Bus> {
+        Box::new(self.clone())
+    }
+}
+
+impl Clone for Box<Bus> {
+    fn clone(&self) -> Box<Bus> {
+        self.box_clone()
+    }
+}
+
+/// Plain memory covering `size` bytes from `start`, indexed by address
+#[derive(Debug, Clone, PartialEq)]
+pub struct Ram {
+    start: usize,
+    data: Vec<byte>,
+}
+
+impl Ram {
+    pub fn new(start: usize, size: usize) -> Ram {
+        Ram {
+            start: start,
+            data: vec![0; size],
+        }
+    }
+
+    /// RAM covering the whole 64K address space
+    pub fn flat() -> Ram {
+        Ram::new(0, 0x10000)
+    }
+
+    /// Copies `bytes` into memory starting at `addr`
+    pub fn write_bytes(&mut self, addr: MemAddr, bytes: &[byte]) {
+        let offset = addr as usize - self.start;
+        self.data[offset..offset + bytes.len()].copy_from_slice(bytes);
+    }
+
+    pub fn data(&self) -> &[byte] {
+        &self.data[..]
+    }
+}
+
+impl SaveState for Ram {
+    fn write_state(&self, w: &mut StateWriter) {
+        w.bytes(&self.data[..]);
+    }
+
+    fn read_state(&mut self, r: &mut StateReader) -> Result<(), SaveStateError> {
+        r.bytes_into(&mut self.data[..])
+    }
+}
+
+impl MemoryMapped for Ram {
+    fn peek(&self, addr: MemAddr) -> byte {
+        self[addr as usize]
+    }
+
+    fn write(&mut self, addr: MemAddr, value: byte) {
+        self[addr as usize] = value;
+    }
+}
+
+impl Index<usize> for Ram {
+    type Output = byte;
+
+    fn index(&self, addr: usize) -> &byte {
+        &self.data[addr - self.start]
+    }
+}
+
+impl IndexMut<usize> for Ram {
+    fn index_mut(&mut self, addr: usize) -> &mut byte {
+        &mut self.data[addr - self.start]
+    }
+}
+
+/// Video RAM, with a second bank on the Game Boy Color selected
+/// through VBK
+#[derive(Debug, Clone, PartialEq)]
+pub struct VideoRam {
+    data: Vec<byte>,
+    bank: usize,
+}
+
+impl VideoRam {
+    pub fn new() -> VideoRam {
+        VideoRam {
+            data: vec![0; 2 * VRAM_BANK_SIZE],
+            bank: 0,
+        }
+    }
+
+    /// Contents of bank 0 or 1, indexed from 0x8000
+    pub fn bank(&self, bank: usize) -> &[byte] {
+        &self.data[bank * VRAM_BANK_SIZE..(bank + 1) * VRAM_BANK_SIZE]
+    }
+
+    /// Bank seen by the CPU
+    pub fn selected_bank(&self) -> usize {
+        self.bank
+    }
+
+    pub fn select_bank(&mut self, bank: usize) {
+        self.bank = bank & 1;
+    }
+}
+
+impl MemoryMapped for VideoRam {
+    fn peek(&self, addr: MemAddr) -> byte {
+        self.data[self.bank * VRAM_BANK_SIZE + addr as usize - DISPLAY_RAM_START]
+    }
+
+    fn write(&mut self, addr: MemAddr, value: byte) {
+        self.data[self.bank * VRAM_BANK_SIZE + addr as usize - DISPLAY_RAM_START] = value;
+    }
+}
+
+impl SaveState for VideoRam {
+    fn write_state(&self, w: &mut StateWriter) {
+        w.bytes(&self.data[..]);
+        w.u8(self.bank as u8);
+    }
+
+    fn read_state(&mut self, r: &mut StateReader) -> Result<(), SaveStateError> {
+        r.bytes_into(&mut self.data[..])?;
+        self.select_bank(r.u8()? as usize);
+        Ok(())
+    }
+}
+
+/// Work RAM.  0xC000-0xCFFF is always bank 0, 0xD000-0xDFFF shows
+/// bank 1, or on the Game Boy Color one of banks 1-7 selected through
+/// SVBK.
+#[derive(Debug, Clone, PartialEq)]
+pub struct WorkRam {
+    data: Vec<byte>,
+    bank: usize,
+}
+
+impl WorkRam {
+    pub fn new() -> WorkRam {
+        WorkRam {
+            data: vec![0; 8 * WRAM_BANK_SIZE],
+            bank: 1,
+        }
+    }
+
+    pub fn bank(&self, bank: usize) -> &[byte] {
+        &self.data[bank * WRAM_BANK_SIZE..(bank + 1) * WRAM_BANK_SIZE]
+    }
+
+    pub fn selected_bank(&self) -> usize {
+        self.bank
+    }
+
+    /// Bank 0 can't be selected for 0xD000-0xDFFF, 1 is used instead
+    pub fn select_bank(&mut self, bank: usize) {
+        self.bank = match bank & 0x7 {
+            0 => 1,
+            n => n,
+        };
+    }
+
+    fn offset(&self, addr: MemAddr) -> usize {
+        let addr = addr as usize;
+        if addr < WRAM_BANK_X_START {
+            addr - WRAM_START
+        } else {
+            self.bank * WRAM_BANK_SIZE + addr - WRAM_BANK_X_START
+        }
+    }
+}
+
+impl MemoryMapped for WorkRam {
+    fn peek(&self, addr: MemAddr) -> byte {
+        self.data[self.offset(addr)]
+    }
+
+    fn write(&mut self, addr: MemAddr, value: byte) {
+        let offset = self.offset(addr);
+        self.data[offset] = value;
+    }
+}
+
+impl SaveState for WorkRam {
+    fn write_state(&self, w: &mut StateWriter) {
+        w.bytes(&self.data[..]);
+        w.u8(self.bank as u8);
+    }
+
+    fn read_state(&mut self, r: &mut StateReader) -> Result<(), SaveStateError> {
+        r.bytes_into(&mut self.data[..])?;
+        self.select_bank(r.u8()? as usize);
+        Ok(())
+    }
+}
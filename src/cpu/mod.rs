@@ -5,12 +5,39 @@
 #[macro_use] mod macros;
 mod tests;
 pub mod constants;
+pub mod cartridge;
+pub mod header;
+pub mod rtc;
+pub mod ppu;
+pub mod timer;
+pub mod apu;
+pub mod bootrom;
+pub mod cgb;
+pub mod dma;
+pub mod interrupts;
+pub mod joypad;
+pub mod memory;
+pub mod savestate;
+pub mod serial;
+pub mod watchpoint;
 
 use std::collections::VecDeque;
 use std::num::Wrapping;
 
 use disasm::*;
 use self::constants::*;
+use self::cartridge::*;
+use self::ppu::*;
+use self::timer::*;
+use self::apu::*;
+use self::bootrom::*;
+use self::cgb::*;
+use self::dma::*;
+use self::interrupts::*;
+use self::joypad::*;
+use self::memory::*;
+use self::serial::*;
+use self::watchpoint::*;
 
 pub trait CpuEventLogger {
     fn new(mem: Option<&[u8]>) -> Self;
@@ -167,8 +194,7 @@ pub fn add_u16_i8(word: u16, sbyte: i8) -> u16 {
 
 /// The CPU itself.
 ///
-///Currently contains memory (including the ROM (which is not
-/// read-only!), which should be abstracted later)
+/// Also owns the devices on the memory bus, see `cpu::memory`.
 pub struct Cpu {
     a:   byte,
     b:   byte,
@@ -182,8 +208,12 @@ pub struct Cpu {
     sp:  MemAddr,
     // Interrupt Master Enable flag (aka "Interrupt Flip-Flop")
     ime: bool,
+    /// EI sets IME only after the instruction following it
+    ime_scheduled: bool,
+    /// HALT with IME off and an interrupt pending fails to increment
+    /// PC after the next opcode fetch
+    halt_bug: bool,
     pub pc:  MemAddr,
-    pub mem: [byte; MEM_ARRAY_SIZE],
 
     /// Whether or not the CPU is running, waiting for input, or stopped
     pub state: CpuState,
@@ -196,34 +226,87 @@ pub struct Cpu {
 
     /// TODO: document this
     pub cycles: CycleCount,
+
+    /// ROM, external RAM and memory bank controller
+    pub cartridge: Cartridge,
+
+    /// LCD timing and the screen's frame buffer
+    pub ppu: Ppu,
+
+    /// DIV and TIMA
+    pub timer: Timer,
+
+    /// Sound channels
+    pub apu: Apu,
+
+    pub vram: VideoRam,
+    pub wram: WorkRam,
+    pub oam: Ram,
+    pub hram: Ram,
+    /// I/O registers and IE, for the registers whose value isn't kept
+    /// by the hardware behind them.  0xFF80-0xFFFE go unused, being
+    /// HRAM.
+    pub io: Ram,
+
+    /// Replaces the whole address space when set, see `Cpu::with_bus`
+    pub bus: Option<Box<Bus>>,
+
+    /// Covers the start of the cartridge ROM while set
+    boot_rom: Option<Vec<byte>>,
+
+    /// Game Boy Color registers and palettes
+    pub cgb: Cgb,
+    pub serial: Serial,
+    pub dma: OamDma,
+    pub watchpoints: Watchpoints,
+
+    pub timing_mode: TimingMode,
+    /// Set while the instruction being executed runs the hardware at
+    /// each memory access
+    access_timing: bool,
+    /// Cycles of the current instruction already run that way
+    timed_cycles: u8,
 }
 
 /// Used for save-states and reverting to old CPU on resets
 impl Clone for Cpu {
     fn clone(&self) -> Cpu {
-        let mut new_cpu = Cpu{a: self.a,
-                              b: self.b,
-                              c: self.c,
-                              d: self.d,
-                              e: self.e,
-                              f: self.f,
-                              h: self.h,
-                              l: self.l,
-                              sp: self.sp,
-                              ime: self.ime,
-                              pc: self.pc,
-                              mem: [0; MEM_ARRAY_SIZE],
-                              state: self.state,
-                              input_state: self.input_state,
-
-                              event_logger: self.event_logger.clone(),
-                              cycles: self.cycles};
-
-        for i in 0..MEM_ARRAY_SIZE {
-            new_cpu.mem[i] = self.mem[i];
-        }
-
-        new_cpu
+        Cpu{a: self.a,
+            b: self.b,
+            c: self.c,
+            d: self.d,
+            e: self.e,
+            f: self.f,
+            h: self.h,
+            l: self.l,
+            sp: self.sp,
+            ime: self.ime,
+            ime_scheduled: self.ime_scheduled,
+            halt_bug: self.halt_bug,
+            pc: self.pc,
+            state: self.state,
+            input_state: self.input_state,
+
+            event_logger: self.event_logger.clone(),
+            cycles: self.cycles,
+            cartridge: self.cartridge.clone(),
+            ppu: self.ppu.clone(),
+            timer: self.timer.clone(),
+            apu: self.apu.clone(),
+            vram: self.vram.clone(),
+            wram: self.wram.clone(),
+            oam: self.oam.clone(),
+            hram: self.hram.clone(),
+            io: self.io.clone(),
+            bus: self.bus.clone(),
+            boot_rom: self.boot_rom.clone(),
+            cgb: self.cgb.clone(),
+            serial: self.serial.clone(),
+            dma: self.dma.clone(),
+            watchpoints: self.watchpoints.clone(),
+            timing_mode: self.timing_mode,
+            access_timing: self.access_timing,
+            timed_cycles: self.timed_cycles}
     }
 }
 
@@ -240,14 +323,33 @@ impl Cpu {
             h:   0,
             l:   0,
             sp:  0xFFFE,
-            ime: true, // TODO verify initial
+            ime: false,
+            ime_scheduled: false,
+            halt_bug: false,
             pc:  0,
-            mem: [0; MEM_ARRAY_SIZE],
             state: CpuState::Normal,
             input_state: 0xFF,
 
             event_logger: Some(DeqCpuEventLogger::new(None)),
             cycles: 0,
+            cartridge: Cartridge::empty(),
+            ppu: Ppu::new(),
+            timer: Timer::new(),
+            apu: Apu::new(),
+            vram: VideoRam::new(),
+            wram: WorkRam::new(),
+            oam: Ram::new(OAM_START, OAM_END - OAM_START + 1),
+            hram: Ram::new(HRAM_START, HRAM_END - HRAM_START + 1),
+            io: Ram::new(IO_REGISTERS_START, 0x10000 - IO_REGISTERS_START),
+            bus: None,
+            boot_rom: None,
+            cgb: Cgb::new(false),
+            serial: Serial::new(),
+            dma: OamDma::new(),
+            watchpoints: Watchpoints::new(),
+            timing_mode: TimingMode::PerInstruction,
+            access_timing: false,
+            timed_cycles: 0,
         };
         /// The reset state is the default state of the CPU
         new_cpu.reset();
@@ -255,9 +357,21 @@ impl Cpu {
         new_cpu
     }
 
+    /// A CPU whose whole address space is `bus`, without any of the
+    /// Game Boy's hardware
+    pub fn with_bus(bus: Box<Bus>) -> Cpu {
+        let mut cpu = Cpu::new();
+        cpu.bus = Some(bus);
+        cpu
+    }
+
     /// Sets the CPU to as it would be after the boot rom has executed
     pub fn reset(&mut self) {
         self.state = CpuState::Normal;
+        self.ime = false;
+        self.ime_scheduled = false;
+        self.halt_bug = false;
+        self.boot_rom = None;
         self.a  = 0x01; //for GB/SGB (GBP & GBC need different values)
         self.b  = 0;
         self.c  = 0;
@@ -273,47 +387,62 @@ impl Cpu {
         //     el.events_deq.clear();
         // }
         info!("reset");
-        self.event_logger = Some(DeqCpuEventLogger::new(Some(&self.mem[..])));
+        self.reinit_logger();
 
-        //boot sequence (maybe do this by running it as a proper rom?)
+        // State left behind by the boot ROM, `boot_with` runs a real one
         self.set_bc(0x0013);
         self.set_de(0x00D8);
         self.set_hl(0x014D);
-        self.mem[0xFF05] = 0x00;
-        self.mem[0xFF06] = 0x00;
-        self.mem[0xFF07] = 0x00;
-        self.mem[0xFF10] = 0x80;
-        self.mem[0xFF11] = 0xBF;
-        self.mem[0xFF12] = 0xF3;
-        self.mem[0xFF14] = 0xBF;
-        self.mem[0xFF16] = 0x3F;
-        self.mem[0xFF17] = 0x00;
-        self.mem[0xFF19] = 0xBF;
-        self.mem[0xFF1A] = 0x7F;
-        self.mem[0xFF1B] = 0xFF;
-        self.mem[0xFF1C] = 0x9F;
-        self.mem[0xFF1E] = 0xBF;
-        self.mem[0xFF20] = 0xFF;
-        self.mem[0xFF21] = 0x00;
-        self.mem[0xFF22] = 0x00;
-        self.mem[0xFF23] = 0xBF;
-        self.mem[0xFF24] = 0x77;
-        self.mem[0xFF25] = 0xF3;
-        self.mem[0xFF26] = 0xF1; //F1 for GB // TODOA:
-        self.mem[0xFF40] = 0x91;
-        self.mem[0xFF42] = 0x00;
-        self.mem[0xFF43] = 0x00;
-        self.mem[0xFF45] = 0x00;
-        self.mem[0xFF47] = 0xFC;
-        self.mem[0xFF48] = 0xFF;
-        self.mem[0xFF49] = 0xFF;
-        self.mem[0xFF4A] = 0x00;
-        self.mem[0xFF4B] = 0x00;
-        self.mem[0xFFFF] = 0x00;
+        // Both rows selected
+        self.io[JOYP_ADDR] = 0xCF;
+        self.timer = Timer::new();
+        self.io[0xFF04] = (self.timer.divider >> 8) as byte;
+        self.io[0xFF05] = 0x00;
+        self.io[0xFF06] = 0x00;
+        self.io[0xFF07] = 0xF8;
+        self.io[0xFF40] = 0x91;
+        self.io[0xFF42] = 0x00;
+        self.io[0xFF43] = 0x00;
+        self.io[0xFF45] = 0x00;
+        self.io[0xFF47] = 0xFC;
+        self.io[0xFF48] = 0xFF;
+        self.io[0xFF49] = 0xFF;
+        self.io[0xFF4A] = 0x00;
+        self.io[0xFF4B] = 0x00;
+        self.io[IF_ADDR] = 0xE1;
+        self.io[BOOT_ROM_DISABLE_ADDR] = 0xFF;
+        self.io[IE_ADDR] = 0x00;
+
+        // CGB cartridges run in CGB mode, where the boot ROM leaves
+        // different values behind
+        let cgb = self.cartridge.cgb_supported();
+        self.reset_cgb(cgb);
+        if cgb {
+            self.a = 0x11;
+            self.f = 0x80;
+            self.set_bc(0x0000);
+            self.set_de(0xFF56);
+            self.set_hl(0x000D);
+        }
+        self.reset_serial();
+        self.dma = OamDma::new();
+
+        // Power the APU on first, it ignores writes while off
+        self.apu = Apu::new();
+        self.write_sound_register(NR52_ADDR, 0xF1);
+        for &(addr, value) in SOUND_REGISTERS_AFTER_BOOT.iter() {
+            self.write_sound_register(addr, value);
+        }
+
+        self.ppu = Ppu::new();
+        self.io[LY_ADDR] = 0;
+        self.set_oam_lock();
+        self.lyc_compare();
     }
 
     pub fn reinit_logger(&mut self) {
-        self.event_logger = Some(DeqCpuEventLogger::new(Some(&self.mem[..])));
+        let memory = self.memory_snapshot();
+        self.event_logger = Some(DeqCpuEventLogger::new(Some(&memory[..])));
     }
     
     pub fn toggle_logger(&mut self) {
@@ -330,30 +459,9 @@ impl Cpu {
     //     };
     // }
 
-    ///FF04 Div
-    ///
-    /// This needs to be called 16384 (~16779 on SGB) times a second
-    /// 
-    fn inc_div(&mut self) {
-        let old_val: MemAddr = (self.mem[0xFF04] as MemAddr) & 0xFF;
-        self.mem[0xFF04] = (old_val + 1) as byte;
-    }
-
-    /// The speed at which the timer runs, settable by the program by
-    /// writing to 0xFF07
-    pub fn timer_frequency(&self) -> u16 {
-        match self.mem[0xFF07] & 0x3 {
-            0 => 4,
-            1 => 262,
-            2 => 65,
-            3 => 16,
-            _ => unreachable!("The impossible happened!"),
-        }
-    }
-
     pub fn get_bg_tiles(&self) -> Vec<byte> {
         let mut ret = vec![];
-        for &i in self.mem[0x8800..0x9800].iter() {
+        for &i in self.vram.bank(0)[0x0800..0x1800].iter() {
             ret.push(((i as byte) >> 6) & 0x3u8);
             ret.push(((i as byte) >> 4) & 0x3u8);
             ret.push(((i as byte) >> 2) & 0x3u8);
@@ -363,52 +471,21 @@ impl Cpu {
         ret
     }
 
-    ///gets the next pixel value to be drawn to the screen and updates
-    ///the special registers correctly
-    #[allow(dead_code, unused_variables)]
-    pub fn get_next_pixel(&mut self, xval: byte) -> byte {
-        let yval = self.ly(); 
-        let tile_map_base_addr =
-            if self.lcdc_bg_tile_map() {0x9C00} else {0x9800};
-
-        let tile_data_base_addr = if
-            self.lcdc_bg_win_tile_data() {
-                //subtractive; 0 at 0x8800
-            } else {
-                //additive; 0 at 0x8000
-                //self.get_
-            }; 
-
-        if tile_map_base_addr == 0x9C00 {//determine additive or subtractive
-        }
-
-        self.inc_ly();
-        0
-    }
-
-    fn get_subtractive_pixel(&mut self) {
-        unimplemented!();
-    }
-    fn get_additive_pixel(&mut self) {
-        unimplemented!();
-    }
-
-
     /* sound */
     pub fn channel1_sweep_time(&self) -> f32 {
-        (((self.mem[0xFF10] >> 4) & 0x7) as f32) / 128.0
+        (((self.sound_register(0xFF10) >> 4) & 0x7) as f32) / 128.0
     }
         
     pub fn channel1_sweep_increase(&self) -> bool {
-        ((self.mem[0xFF10] >> 3) & 1) == 0
+        ((self.sound_register(0xFF10) >> 3) & 1) == 0
     }
     
     pub fn channel1_sweep_shift(&self) -> u8 {
-        (self.mem[0xFF10] & 0x7) as u8
+        (self.sound_register(0xFF10) & 0x7) as u8
     }
         
     pub fn channel1_wave_pattern_duty(&self) -> f32 {
-        match (self.mem[0xFF11] >> 6) & 0x3 {
+        match (self.sound_register(0xFF11) >> 6) & 0x3 {
             0 => 0.125,
             1 => 0.25,
             2 => 0.5,
@@ -418,24 +495,24 @@ impl Cpu {
     }
     
     pub fn channel1_sound_length(&self) -> u8 {
-        (self.mem[0xFF11] & 0x3F) as u8
+        (self.sound_register(0xFF11) & 0x3F) as u8
     }
         
     pub fn channel1_envelope_initial_volume(&self) -> u8 {
-        ((self.mem[0xFF12] >> 4) & 0xF) as u8
+        ((self.sound_register(0xFF12) >> 4) & 0xF) as u8
     }
         
     pub fn channel1_envelope_increasing(&self) -> bool {
-        ((self.mem[0xFF12] >> 3) & 0x1) == 1
+        ((self.sound_register(0xFF12) >> 3) & 0x1) == 1
     }
 
     pub fn channel1_envelope_sweep(&self) -> u8 {
-        (self.mem[0xFF12] & 0x7) as u8
+        (self.sound_register(0xFF12) & 0x7) as u8
     }
 
     pub fn channel1_frequency(&self) -> u16 {
-        let lower = self.mem[0xFF13];
-        let higher = self.mem[0xFF14] & 0x7;
+        let lower = self.sound_register(0xFF13);
+        let higher = self.sound_register(0xFF14) & 0x7;
         byte_to_u16(lower, higher)
     }
     
@@ -445,33 +522,33 @@ impl Cpu {
     }
     
     pub fn channel1_restart_sound(&self) -> bool {
-        ((self.mem[0xFF14] >> 7) & 1) == 1
+        ((self.sound_register(0xFF14) >> 7) & 1) == 1
     }
 
     
     pub fn channel2_wave_pattern_duty(&self) -> u8 {
-        ((self.mem[0xFF16] >> 6) & 0x3) as u8
+        ((self.sound_register(0xFF16) >> 6) & 0x3) as u8
     }
     
     pub fn channel2_sound_length(&self) -> u8 {
-        (self.mem[0xFF16] & 0x3F) as u8
+        (self.sound_register(0xFF16) & 0x3F) as u8
     }
     
     pub fn channel2_envelope_initial_volume(&self) -> u8 {
-        ((self.mem[0xFF17] >> 4) & 0xF) as u8
+        ((self.sound_register(0xFF17) >> 4) & 0xF) as u8
     }
     
     pub fn channel2_envelope_increasing(&self) -> bool {
-        ((self.mem[0xFF17] >> 3) & 0x1) == 1
+        ((self.sound_register(0xFF17) >> 3) & 0x1) == 1
     }
     
     pub fn channel2_envelope_sweep(&self) -> u8 {
-        self.mem[0xFF17] & 0x7
+        self.sound_register(0xFF17) & 0x7
     }
 
         pub fn channel2_frequency(&self) -> u16 {
-            let lower = self.mem[0xFF18];
-            let higher = self.mem[0xFF19] & 0x7;
+            let lower = self.sound_register(0xFF18);
+            let higher = self.sound_register(0xFF19) & 0x7;
             
             byte_to_u16(lower, higher)
         }
@@ -482,15 +559,15 @@ impl Cpu {
         }
 
         pub fn channel2_restart_sound(&self) -> bool {
-            ((self.mem[0xFF19] >> 7) & 1) == 1
+            ((self.sound_register(0xFF19) >> 7) & 1) == 1
         }
 
         pub fn channel3_on(&self) -> bool {
-            ((self.mem[0xFF1A] >> 7) & 1) == 1
+            ((self.sound_register(0xFF1A) >> 7) & 1) == 1
         }
 
         pub fn channel3_sound_length(&self) -> u8 {
-            self.mem[0xFF1B] as u8
+            self.sound_register(0xFF1B) as u8
         }
 
         pub fn channel3_output_level(&self) {
@@ -498,8 +575,8 @@ impl Cpu {
         }
 
         pub fn channel3_frequency(&self) -> u16 {
-            let lower = self.mem[0xFF1C];
-            let higher = self.mem[0xFF1D] & 0x7;
+            let lower = self.sound_register(0xFF1C);
+            let higher = self.sound_register(0xFF1D) & 0x7;
             
             byte_to_u16(lower, higher)
         }
@@ -510,47 +587,18 @@ impl Cpu {
         }
 
     pub fn channel3_restart_sound(&self) -> bool {
-        ((self.mem[0xFF1D] >> 7) & 1) == 1
+        ((self.sound_register(0xFF1D) >> 7) & 1) == 1
     }
     
     pub fn channel3_wave_pattern_ram(&self) -> [u8; 16] {
         let mut ret = [0u8; 16];
         for i in 0..16 {
-            ret[i] = self.mem[0xFF30 + i] as u8;
+            ret[i] = self.sound_register(0xFF30 + i) as u8;
         }
         
         ret
     }
     
-    /// Abstracts the logic of the timer
-    /// Call this from the loop when the timer should be incremented
-    /// NOTE: does not appear to take timer frequency into account...
-    pub fn timer_cycle(&mut self) {
-        if self.is_timer_on() {
-            self.inc_timer();
-        }
-    }
-
-    fn is_timer_on(&self) -> bool {
-        (self.mem[0xFF07] & 0x4) >> 2 == 1
-    }
-
-    fn inc_timer(&mut self) {
-        let old_val = self.mem[0xFF05];
-        // TMA; value which is to be set on overflow
-        let new_val = self.mem[0xFF06];
-
-        self.mem[0xFF05] =
-            if old_val.wrapping_add(1) == 0 {
-                // on overflow...
-                if self.get_interrupts_enabled() {
-                    self.set_timer_interrupt_bit();
-                }
-                new_val
-            } else {old_val.wrapping_add(1)};
-    }
-
-
     set_interrupt_bit!(set_vblank_interrupt_bit, 0x1);
     set_interrupt_bit!(set_lcdc_interrupt_bit, 0x2);
     set_interrupt_bit!(set_timer_interrupt_bit, 0x4);
@@ -570,28 +618,6 @@ impl Cpu {
     get_interrupt!(get_input_interrupt_bit, 0x10);
 
 
-    /*
-     * SOUND:
-     */
-
-    set_sound_on!(set_sound1, 0x1);
-    set_sound_on!(set_sound2, 0x2);
-    set_sound_on!(set_sound3, 0x4);
-    set_sound_on!(set_sound4, 0x8);
-    set_sound_on!(set_sound_all, 0x80);
-
-    get_sound_on!(get_sound1, 0x1);
-    get_sound_on!(get_sound2, 0x2);
-    get_sound_on!(get_sound3, 0x4);
-    get_sound_on!(get_sound4, 0x8);
-    get_sound_on!(get_sound_all, 0x80);
-
-
-    unset_sound_on!(unset_sound1, 0x1u8);
-    unset_sound_on!(unset_sound2, 0x2u8);
-    unset_sound_on!(unset_sound3, 0x4u8);
-    unset_sound_on!(unset_sound4, 0x8u8);
-    unset_sound_on!(unset_sound_all, 0x80u8);
 
     set_interrupt_enabled!(set_vblank_interrupt_enabled, 0x1);
     set_interrupt_enabled!(set_lcdc_interrupt_enabled, 0x2);
@@ -642,51 +668,51 @@ impl Cpu {
 
     pub fn set_hblank(&mut self) {
         //reset bottom two bits
-        self.mem[STAT_ADDR] &= !0x3;
+        self.io[STAT_ADDR] &= !0x3;
     }
 
     pub fn set_vblank(&mut self) {
         //setting LSB, reset next
-        let old_val = self.mem[STAT_ADDR];
-        self.mem[STAT_ADDR] = (old_val | 1) & (!2);
+        let old_val = self.io[STAT_ADDR];
+        self.io[STAT_ADDR] = (old_val | 1) & (!2);
     }
 
     pub fn set_oam_lock(&mut self) {
         //reset LSB, set next
-        let old_val = self.mem[STAT_ADDR];
-        self.mem[STAT_ADDR] = (old_val | 2) & (!1);
+        let old_val = self.io[STAT_ADDR];
+        self.io[STAT_ADDR] = (old_val | 2) & (!1);
     }
 
     /// A.K.A Transfering data to the LCD driver
     pub fn set_oam_and_display_lock(&mut self) {
         //set LSB and next
-        self.mem[STAT_ADDR] |= 0x3;
+        self.io[STAT_ADDR] |= 0x3;
     }
 
     
     pub fn lcdc_on(&self) -> bool {
-        (self.mem[0xFF40] >> 7) & 1 == 1
+        (self.io[0xFF40] >> 7) & 1 == 1
     }
     pub fn lcdc_tile_map(&self) -> bool {
-        (self.mem[0xFF40] >> 6) & 1 == 1
+        (self.io[0xFF40] >> 6) & 1 == 1
     }
     pub fn lcdc_window_on(&self) -> bool {
-        (self.mem[0xFF40] >> 5) & 1 == 1
+        (self.io[0xFF40] >> 5) & 1 == 1
     }
     pub fn lcdc_bg_win_tile_data(&self) -> bool {
-        (self.mem[0xFF40] >> 4) & 1 == 1
+        (self.io[0xFF40] >> 4) & 1 == 1
     }
     pub fn lcdc_bg_tile_map(&self) -> bool {
-        (self.mem[0xFF40] >> 3) & 1 == 1
+        (self.io[0xFF40] >> 3) & 1 == 1
     }
     pub fn lcdc_sprite_size(&self) -> bool {
-        (self.mem[0xFF40] >> 2) & 1 == 1
+        (self.io[0xFF40] >> 2) & 1 == 1
     }
     pub fn lcdc_sprite_display(&self) -> bool {
-        (self.mem[0xFF40] >> 1) & 1 == 1
+        (self.io[0xFF40] >> 1) & 1 == 1
     }
     pub fn lcdc_bg_win_display(&self) -> bool {
-        self.mem[0xFF40] & 1 == 1
+        self.io[0xFF40] & 1 == 1
     }
 
     
@@ -698,22 +724,22 @@ impl Cpu {
 
         if tile_map_base_addr == 0x9C00 {
             for j in 0..(32 * 32) {
-                let tile_pointer = self.mem[(tile_map_base_addr + j) as usize];
+                let tile_pointer = self.peek((tile_map_base_addr + j) as MemAddr);
                 for i in 0..16 {
                     for k in 0..4 {
                         //multiply offset by tile size
-                        tiles[j][i*(k+1)] = ((self.mem[((tile_data_base_addr as i16) + ((tile_pointer as i16) * 0x40)) as usize] as u8)
+                        tiles[j][i*(k+1)] = ((self.peek(((tile_data_base_addr as i16) + ((tile_pointer as i16) * 0x40)) as MemAddr) as u8)
                                              >> (k*2)) & 0x3;
                     }
                 }
             }
         } else {
             for j in 0..(32 * 32) {
-                let tile_pointer = self.mem[(tile_map_base_addr + j) as usize] as u8;
+                let tile_pointer = self.peek((tile_map_base_addr + j) as MemAddr) as u8;
                 for i in 0..16 {
                     for k in 0..4 {
                         //multiply offset by tile size
-                        tiles[j][i*(k+1)] = ((self.mem[((tile_data_base_addr as MemAddr) + ((tile_pointer as MemAddr) * 0x40)) as usize] as byte)
+                        tiles[j][i*(k+1)] = ((self.peek((tile_data_base_addr as MemAddr) + ((tile_pointer as MemAddr) * 0x40)) as byte)
                                              >> (k * 2)) & 0x3;
                     }
                 }
@@ -726,36 +752,33 @@ impl Cpu {
 
 
     pub fn scy(&self) -> u8 {
-        self.mem[0xFF42] 
+        self.io[0xFF42] 
     }
     pub fn scx(&self) -> u8 {
-        self.mem[0xFF43]
+        self.io[0xFF43]
     }
 
     pub fn ly(&self) -> u8 {
-        self.mem[0xFF44]
+        self.io[0xFF44]
     }
 
     pub fn inc_ly(&mut self) {
         let v = self.ly().wrapping_add(1) % 154;
-        self.mem[0xFF44] = v as byte;
+        self.io[0xFF44] = v as byte;
         // interrupt should only be thrown on the rising edge (when ly
         // turns to 144)
         if v == 144 {
-            //TODO: verify that this should only be done if the interrupt is enabled
-            if self.get_interrupts_enabled() && self.get_vblank_interrupt_enabled() {
-                self.set_vblank_interrupt_bit();
-            }
+            self.set_vblank_interrupt_bit();
         }
         //LY check is done any time LY is updated
         self.lyc_compare();
     }
 
     pub fn lyc(&self) -> u8 {
-        self.mem[0xFF45] as u8
+        self.io[0xFF45] as u8
     }
 
-    fn lyc_compare(&mut self) {
+    pub fn lyc_compare(&mut self) {
         let ly = self.ly();
         let lyc = self.lyc();
 
@@ -766,56 +789,39 @@ impl Cpu {
         }
     }
 
-    /// Direct memory access, lets the CPU copy memory without being
-    /// directly involve 
-    ///
-    /// Should take 160 microseconds
-    ///
-    /// During DMA, everything but high memory should be blocked
-    fn dma(&mut self) {
-        let addr = (self.mem[0xFF46] as MemAddr) << 8;
-        
-        for i in 0..0xA0 { //number of values to be copied
-            let val = self.mem[(addr + i) as usize];
-            self.mem[(0xFE00 + i) as usize] = val; //start addr + offset
-        }
-        //signal dma is over (GBC ONLY)
-       // self.mem[0xFF55] = 1;
-    }
-
     pub fn bgp(&self) -> (byte, byte, byte, byte) {
-        let v4 = ((self.mem[0xFF47] >> 6) & 0x3) as byte;
-        let v3 = ((self.mem[0xFF47] >> 4) & 0x3) as byte;
-        let v2 = ((self.mem[0xFF47] >> 2) & 0x3) as byte;
-        let v1 = ((self.mem[0xFF47] >> 0) & 0x3) as byte;
+        let v4 = ((self.io[0xFF47] >> 6) & 0x3) as byte;
+        let v3 = ((self.io[0xFF47] >> 4) & 0x3) as byte;
+        let v2 = ((self.io[0xFF47] >> 2) & 0x3) as byte;
+        let v1 = ((self.io[0xFF47] >> 0) & 0x3) as byte;
 
         (v1,v2,v3,v4)
     }
 
     pub fn obp0(&self) -> (byte, byte, byte, byte) {
-        let v4 = ((self.mem[0xFF48] >> 6) & 0x3) as byte;
-        let v3 = ((self.mem[0xFF48] >> 4) & 0x3) as byte;
-        let v2 = ((self.mem[0xFF48] >> 2) & 0x3) as byte;
-        let v1 = ((self.mem[0xFF48] >> 0) & 0x3) as byte;
+        let v4 = ((self.io[0xFF48] >> 6) & 0x3) as byte;
+        let v3 = ((self.io[0xFF48] >> 4) & 0x3) as byte;
+        let v2 = ((self.io[0xFF48] >> 2) & 0x3) as byte;
+        let v1 = ((self.io[0xFF48] >> 0) & 0x3) as byte;
 
         (v1,v2,v3,v4)
     }
 
     pub fn obp1(&self) -> (byte, byte, byte, byte) {
-        let v4 = ((self.mem[0xFF49] >> 6) & 0x3) as byte;
-        let v3 = ((self.mem[0xFF49] >> 4) & 0x3) as byte;
-        let v2 = ((self.mem[0xFF49] >> 2) & 0x3) as byte;
-        let v1 = ((self.mem[0xFF49] >> 0) & 0x3) as byte;
+        let v4 = ((self.io[0xFF49] >> 6) & 0x3) as byte;
+        let v3 = ((self.io[0xFF49] >> 4) & 0x3) as byte;
+        let v2 = ((self.io[0xFF49] >> 2) & 0x3) as byte;
+        let v1 = ((self.io[0xFF49] >> 0) & 0x3) as byte;
 
         (v1,v2,v3,v4)
     }
 
     pub fn wy(&self) -> u8 {
-        self.mem[0xFF4A] as u8
+        self.io[0xFF4A] as u8
     }
 
     pub fn wx(&self) -> u8 {
-        self.mem[0xFF4B] as u8
+        self.io[0xFF4B] as u8
     }
 
     //input register for joypad
@@ -842,20 +848,11 @@ impl Cpu {
     button!(press_right,  unpress_right,  0x1u8);
 
     pub fn get_game_name(&self) -> String {
-        let mut name_data: Vec<u8> = vec!();
-        for i in 0..16 {
-            if self.mem[0x134 + i] != 0 {
-                name_data.push(self.mem[0x134 + i] as u8);
-            }
-        }
-        match String::from_utf8(name_data) {
-            Ok(s) => s,
-            _     => "Illegally named game".to_string(),
-        }
+        self.cartridge.header().title.clone()
     }
 
     pub fn get_cartridge_type(&self) -> u8 {
-        self.mem[0x147] as u8
+        self.cartridge.header().cartridge_type_code
     }
 
 
@@ -907,7 +904,7 @@ impl Cpu {
         self.e = (dev & 0xFF)          as byte;
     }
 
-    fn set_register16(&mut self, reg: CpuRegister16, val: u16) {
+    pub fn set_register16(&mut self, reg: CpuRegister16, val: u16) {
         match reg {
             CpuRegister16::BC => self.set_bc(val),
             CpuRegister16::DE => self.set_de(val),
@@ -918,7 +915,7 @@ impl Cpu {
         }
     }
 
-    pub fn access_register16(&mut self, reg: CpuRegister16) -> u16 {
+    pub fn access_register16(&self, reg: CpuRegister16) -> u16 {
         match reg {
             CpuRegister16::BC     => self.bc(),
             CpuRegister16::DE     => self.de(),
@@ -950,73 +947,159 @@ impl Cpu {
 
     #[inline]
     pub fn get_mem(&mut self, address: MemAddr) -> byte {
+        self.m_cycle();
+        if self.dma_blocks(address) {
+            return 0xFF;
+        }
+        let value = self.read_mem(address);
+        self.watch_read(address, value);
+        value
+    }
+
+    /// Like `get_mem`, but never takes time, for reads made by devices
+    /// rather than the CPU
+    pub fn read_mem(&mut self, address: MemAddr) -> byte {
         if let Some(ref mut logger) = self.event_logger {
             logger.log_read(self.cycles, address);
         }
-        self.mem[address as usize]
+        if let Some(ref mut bus) = self.bus {
+            return bus.read(address);
+        }
+        // None of the Game Boy's own devices have side effects on reads
+        self.peek(address)
+    }
+
+    /// What the CPU would read at `address`, without taking time or
+    /// any other side effect, for debuggers and the like
+    pub fn peek(&self, address: MemAddr) -> byte {
+        if let Some(ref bus) = self.bus {
+            return bus.peek(address);
+        }
+        match address as usize {
+            n @ 0x0000...0x00FF if self.boot_rom.is_some() => {
+                self.boot_rom.as_ref().map_or(0xFF, |rom| rom[n])
+            }
+            0x0000...0x7FFF | EXTERNAL_RAM_START...EXTERNAL_RAM_END => {
+                self.cartridge.read(address)
+            }
+            DISPLAY_RAM_START...DISPLAY_RAM_END => self.vram.peek(address),
+            WRAM_START...WRAM_END => self.wram.peek(address),
+            n @ ECHO_RAM_START...ECHO_RAM_END => {
+                self.wram.peek((n - (ECHO_RAM_START - WRAM_START)) as MemAddr)
+            }
+            OAM_START...OAM_END => self.oam.peek(address),
+            UNUSABLE_START...UNUSABLE_END => 0x00,
+            HRAM_START...HRAM_END => self.hram.peek(address),
+            n => self.read_io_register(n),
+        }
+    }
+
+    /// The whole address space as `peek` sees it
+    pub fn memory_snapshot(&self) -> Vec<byte> {
+        (0..0x10000).map(|addr| self.peek(addr as MemAddr)).collect()
+    }
+
+    /// I/O registers and IE
+    fn read_io_register(&self, address: usize) -> byte {
+        match address {
+            JOYP_ADDR => self.joypad_register(),
+            n @ SOUND_REGISTERS_START...SOUND_REGISTERS_END => self.apu.read(n),
+            n @ KEY1_ADDR | n @ VBK_ADDR | n @ HDMA1_ADDR...HDMA5_ADDR |
+            n @ BCPS_ADDR...OCPD_ADDR | n @ SVBK_ADDR => self.read_cgb_register(n),
+            n => self.io[n],
+        }
     }
 
     #[inline]
     pub fn set_mem(&mut self, address: MemAddr, value: byte) {
-        /*!
-        NOTE: serial I/O is done by accessing memory addresses.
-        It is read at 8192Hz, one bit at a time if external clock is used.
-        See documentation and read carefully before implementing this.
-         */
+        self.m_cycle();
+        if self.dma_blocks(address) {
+            return;
+        }
+        self.watch_write(address, value);
+        self.write_mem(address, value);
+    }
+
+    /// Like `set_mem`, but never takes time and isn't watched, for
+    /// writes made by devices or debuggers rather than the CPU
+    pub fn write_mem(&mut self, address: MemAddr, value: byte) {
         if let Some(ref mut logger) = self.event_logger {
             logger.log_write(self.cycles, address, value);
         }
 
+        if let Some(ref mut bus) = self.bus {
+            bus.write(address, value);
+            return;
+        }
+
         let address = address as usize;
 
         match address {
+            // Bank switching registers
+            0x0000...0x7FFF | EXTERNAL_RAM_START...EXTERNAL_RAM_END => {
+                self.cartridge.write(address as MemAddr, value);
+            }
             //TODO: Verify triple dot includes final value
             v @ DISPLAY_RAM_START ... DISPLAY_RAM_END => {
                 // If in OAM and Display ram are both in use
-                if self.mem[STAT_ADDR] & 3 == 3 {
+                if self.io[STAT_ADDR] & 3 == 3 {
                     error!("CPU cannot access address {} at this time", v);
                 } else {
-                    self.mem[v] = value as byte;
+                    self.vram.write(v as MemAddr, value);
                 }
             },
             v @ OAM_START ... OAM_END => {
                 //if OAM is in use
-                match self.mem[STAT_ADDR] & 3 {
+                match self.io[STAT_ADDR] & 3 {
                     0b10 | 0b11 => {
                         error!("CPU cannot access address {} while the OAM is in use", v);
                     },
-                    _ => self.mem[v] = value as byte,
+                    _ => self.oam.write(v as MemAddr, value),
                 }
             },
-            ad @ 0xE000 ... 0xFE00 | ad @ 0xC000 ... 0xDE00
-                => {
-                    self.mem[ad]                     = value;
-                    self.mem[ad ^ (0xE000 - 0xC000)] = value;
-                },
-            0xFF00 => {
-                // (P1) Joypad Info
-                if value & 0x10 == 0x10 {
-                    // P14 is set to low
-                    self.mem[0xFF00] = value | (self.input_state >> 4);
-                } else if value & 0x20 == 0x20 {
-                    // P15 is set to low
-                    self.mem[0xFF00] = value | (self.input_state & 0x0F);
-                }
+            UNUSABLE_START...UNUSABLE_END => (),
+            v @ WRAM_START...WRAM_END | v @ ECHO_RAM_START...ECHO_RAM_END => {
+                let addr = if v >= ECHO_RAM_START {
+                    v - (ECHO_RAM_START - WRAM_START)
+                } else { v };
+                self.wram.write(addr as MemAddr, value);
+            }
+            v @ HRAM_START...HRAM_END => self.hram.write(v as MemAddr, value),
+            v => self.write_io_register(v, value),
+        }
+    }
+
+    /// I/O registers and IE
+    fn write_io_register(&mut self, address: usize, value: byte) {
+        match address {
+            JOYP_ADDR => self.write_joypad(value),
+            v @ SOUND_REGISTERS_START...SOUND_REGISTERS_END => {
+                self.write_sound_register(v, value)
             }
-            0xFF04 => self.mem[0xFF04] = 0,
+            SC_ADDR => self.write_sc(value),
+            DIV_ADDR => self.reset_div(),
+            TIMA_ADDR => self.write_tima(value),
+            TAC_ADDR => self.write_tac(value),
+            IF_ADDR => self.write_if(value),
+            BOOT_ROM_DISABLE_ADDR => self.write_boot_rom_disable(value),
+            n @ KEY1_ADDR | n @ VBK_ADDR | n @ HDMA1_ADDR...HDMA5_ADDR |
+            n @ BCPS_ADDR...OCPD_ADDR | n @ SVBK_ADDR => self.write_cgb_register(n, value),
             // TODO: Check whether vblank should be turned off on
             // writes to 0xFF44
-            0xFF44 => self.mem[0xFF44] = 0,
+            0xFF44 => self.io[0xFF44] = 0,
+            STAT_ADDR => {
+                // Mode and coincidence bits are read only
+                self.io[STAT_ADDR] = 0x80 | (value & 0x78) | (self.io[STAT_ADDR] & 0x07);
+                self.update_stat_line();
+            }
             0xFF45 => {
                 //LY check is done every time LY or LYC value is updated
-                self.mem[0xFF45] = value;
+                self.io[0xFF45] = value;
                 self.lyc_compare();
+                self.update_stat_line();
             }
-            0xFF46 => {
-                self.mem[0xFF46] = value;
-                self.dma();
-            }
-            n => self.mem[n] = value,
+            DMA_ADDR => self.start_dma(value),
+            n => self.io[n] = value,
         }
     }
 
@@ -1029,11 +1112,23 @@ impl Cpu {
             CpuRegister::E  => Some(self.e),
             CpuRegister::H  => Some(self.h),
             CpuRegister::L  => Some(self.l),
-            CpuRegister::HL => Some(self.mem[self.hl() as usize]),
+            CpuRegister::HL => Some(self.peek(self.hl())),
             _               => None,
         } 
     }
 
+    /// `access_register` for instructions, where reading (HL) is a
+    /// memory access taking an M-cycle
+    fn read_register(&mut self, reg: CpuRegister) -> Option<byte> {
+        match reg {
+            CpuRegister::HL => {
+                let hlv = self.hl();
+                Some(self.get_mem(hlv))
+            }
+            _ => self.access_register(reg),
+        }
+    }
+
     fn set_register(&mut self, reg: CpuRegister, val:byte) {
         match reg {
             CpuRegister::A  => self.a = val,
@@ -1057,7 +1152,7 @@ impl Cpu {
     }
 
     fn ldr1r2(&mut self, r1: CpuRegister, r2:CpuRegister) {
-        let val = self.access_register(r2).expect("Invalid register");
+        let val = self.read_register(r2).expect("Invalid register");
         self.set_register(r1, val);
     }
 
@@ -1189,20 +1284,13 @@ impl Cpu {
     // }
     
     //TODO: rename this awfully named function
-    fn alu_dispatch<F>(&self, reg: CpuRegister, f: F) -> i16 where
+    fn alu_dispatch<F>(&mut self, reg: CpuRegister, f: F) -> i16 where
         F: FnOnce(byte, byte) -> i16 {
-        f(self.a,
-          match reg {
-              CpuRegister::A      => self.a,
-              CpuRegister::B      => self.b,
-              CpuRegister::C      => self.c,
-              CpuRegister::D      => self.d,
-              CpuRegister::E      => self.e,
-              CpuRegister::H      => self.h,
-              CpuRegister::L      => self.l,
-              CpuRegister::HL     => self.mem[self.hl() as usize],
-              CpuRegister::Num(i) => i, 
-          })
+        let value = match reg {
+            CpuRegister::Num(i) => i,
+            _ => self.read_register(reg).expect("invalid register"),
+        };
+        f(self.a, value)
     }
 
     //TODO: rename this awfully named function
@@ -1221,7 +1309,7 @@ impl Cpu {
     }
 
     fn reg_or_const(&mut self, reg: CpuRegister) -> i8 {
-        if let Some(r) = self.access_register(reg) {
+        if let Some(r) = self.read_register(reg) {
             r as i8 
         } else if let CpuRegister::Num(v) = reg {
             v as i8
@@ -1325,9 +1413,8 @@ impl Cpu {
 
     fn inc(&mut self, reg: CpuRegister) {
         let old_c = (self.f & CL) == CL;
-        let old_3bit = self.access_register(reg).expect("invalid register") & 0x8;
-
-        let old_val: i16 = self.access_register(reg).expect("invalid register") as i16;
+        let old_val: i16 = self.read_register(reg).expect("invalid register") as i16;
+        let old_3bit = old_val as byte & 0x8;
         let new_val = (old_val + 1) as byte;
         self.set_register(reg, new_val);
         self.set_flags(new_val == 0u8, // this check fails if new_val is i16 :)
@@ -1340,7 +1427,7 @@ impl Cpu {
     fn dec(&mut self, reg: CpuRegister) {
         let old_c = (self.f & CL) == CL;
 
-        let reg_val = self.access_register(reg)
+        let reg_val = self.read_register(reg)
                             .expect("invalid register");
 
         let new_val:byte = reg_val.wrapping_sub(1) as byte;
@@ -1443,7 +1530,7 @@ impl Cpu {
 
     fn swap(&mut self, reg: CpuRegister) {
         //Potentially can bitmask hl which is 16bit value
-        let val = self.access_register(reg).expect("couldn't access register value") as u8;
+        let val = self.read_register(reg).expect("couldn't access register value") as u8;
         let top = val & 0xF0u8;
         let bot = val & 0x0Fu8;
         self.set_register(reg, (((top >> 4) & 0xF) | (bot << 4)) as byte);
@@ -1520,22 +1607,31 @@ impl Cpu {
 
     fn halt(&mut self) {
         debug!("HALT");
-        self.state = CpuState::Halt;
-        
+        if !self.ime && self.pending_interrupts() != 0 {
+            // Doesn't halt, but the next opcode byte is read twice
+            self.halt_bug = true;
+        } else {
+            self.state = CpuState::Halt;
+        }
     }
 
     fn stop(&mut self) {
         debug!("STOP");
+        if self.switch_speed() {
+            return;
+        }
         self.state = CpuState::Stop;
 
     }
 
     fn di(&mut self) {
         self.disable_interrupts();
+        self.ime_scheduled = false;
     }
 
+    /// Takes effect after the next instruction
     fn ei(&mut self) {
-        self.enable_interrupts();
+        self.ime_scheduled = true;
     }
 
     fn rlca(&mut self) {
@@ -1590,7 +1686,7 @@ impl Cpu {
     }
 
     fn rlc(&mut self, reg: CpuRegister) {
-        let reg_val = self.access_register(reg).expect("invalid register");
+        let reg_val = self.read_register(reg).expect("invalid register");
         let old_carry = ((self.f & CL) as u8) >> 4;
         let old_bit7 = (reg_val >> 7) & 1;
 
@@ -1604,7 +1700,7 @@ impl Cpu {
     }
 
     fn rl(&mut self, reg: CpuRegister) {
-        let reg_val = self.access_register(reg).expect("invalid register");
+        let reg_val = self.read_register(reg).expect("invalid register");
         let old_bit7 = (reg_val >> 7) & 1;
         let old_flags = ((self.f & CL) >> 4) & 0xF;
 
@@ -1618,7 +1714,7 @@ impl Cpu {
     }
 
     fn rrc(&mut self, reg: CpuRegister) {
-        let reg_val = self.access_register(reg).expect("invalid register");
+        let reg_val = self.read_register(reg).expect("invalid register");
         let old_bit0 = reg_val & 1;
 
         let new_val = ((reg_val >> 1) & 0x7F) | (old_bit0 << 7);
@@ -1632,7 +1728,7 @@ impl Cpu {
 
     /// Rotate n right through Carry flag.
     fn rr(&mut self, reg: CpuRegister) {
-        let reg_val = self.access_register(reg).expect("invalid register");
+        let reg_val = self.read_register(reg).expect("invalid register");
         let old_bit0 = reg_val & 1;
         let old_flags = (self.f & CL) << 3;
 
@@ -1646,7 +1742,7 @@ impl Cpu {
     }
 
     fn sla(&mut self, reg: CpuRegister) {
-        let reg_val = self.access_register(reg).expect("invalid register");
+        let reg_val = self.read_register(reg).expect("invalid register");
         let old_bit7 = (reg_val >> 7) & 1;
         self.set_register(reg, reg_val << 1);
 
@@ -1657,7 +1753,7 @@ impl Cpu {
     }
 
     fn sra(&mut self, reg: CpuRegister) {
-        let reg_val = self.access_register(reg).expect("invalid register");
+        let reg_val = self.read_register(reg).expect("invalid register");
         let old_bit0 = reg_val & 1;
         let old_bit7 = reg_val & 0x80;
         self.set_register(reg, (reg_val >> 1) | old_bit7);
@@ -1669,7 +1765,7 @@ impl Cpu {
     }
 
     fn srl(&mut self, reg: CpuRegister) {
-        let reg_val = self.access_register(reg).expect("invalid register") as u8;
+        let reg_val = self.read_register(reg).expect("invalid register") as u8;
         let old_bit0 = reg_val & 1;
 
         self.set_register(reg, (reg_val >> 1) as byte);
@@ -1681,7 +1777,7 @@ impl Cpu {
     }
 
     fn bit(&mut self, b: u8, reg: CpuRegister) {
-        let reg_val = self.access_register(reg).expect("invalid register");
+        let reg_val = self.read_register(reg).expect("invalid register");
         let old_flags = (self.f & CL) >> 4;
         
         self.set_flags(((reg_val >> b) & 1) != 1,
@@ -1691,12 +1787,12 @@ impl Cpu {
     }
 
     fn set(&mut self, b: u8, reg: CpuRegister) {
-        let reg_val = self.access_register(reg).expect("invalid register");
+        let reg_val = self.read_register(reg).expect("invalid register");
         self.set_register(reg, reg_val | (1 << b));
     }
 
     fn res(&mut self, b: u8, reg: CpuRegister) {
-        let reg_val = self.access_register(reg).expect("invalid register");
+        let reg_val = self.read_register(reg).expect("invalid register");
         self.set_register(reg, reg_val & (!(1 << b)));
     }
 
@@ -1771,6 +1867,8 @@ impl Cpu {
     }
 
     fn push_onto_stack(&mut self, nn: u16) {
+        // SP is decremented in an M-cycle of its own before the writes
+        self.m_cycle();
         let first_half = ((nn >> 8) & 0xFF) as byte;
         let second_half = (nn & 0xFF) as byte;
 
@@ -1840,92 +1938,32 @@ impl Cpu {
 
     fn reti(&mut self) {
         self.ret();
-        self.ei();
+        // Unlike EI, there is no delay
+        self.enable_interrupts();
     }
 
+    /// Peeks at the bytes an instruction at PC can span.  A bus only
+    /// sees reads of the ones the instruction uses, see
+    /// `dispatch_opcode`.
     fn read_instruction(&self) -> (u8, u8, u8, u8) {
         // if self.pc > (0xFFFF - 3) {
         //     panic!("Less than 4bytes to read!!!\nNote: this may not be a problem with the ROM; if the ROM is correct, this is the result of lazy programming on my part -- sorry");
         // }
-        (self.mem[self.pc as usize] as u8,
-         self.mem[(self.pc as usize) + 1] as u8,
-         self.mem[(self.pc as usize) + 2] as u8,
-         self.mem[(self.pc as usize) + 3] as u8)
+        let pc = self.pc;
+        // Code outside HRAM can't be fetched during OAM DMA
+        if self.bus.is_none() && self.dma_blocks(pc) {
+            return (0xFF, 0xFF, 0xFF, 0xFF);
+        }
+        (self.peek(pc) as u8,
+         self.peek(pc.wrapping_add(1)) as u8,
+         self.peek(pc.wrapping_add(2)) as u8,
+         self.peek(pc.wrapping_add(3)) as u8)
     }
 
     fn inc_pc(&mut self) {
         self.pc = (Wrapping(self.pc) + Wrapping(1)).0;
     }
 
-    fn handle_interrupts(&mut self) {
-        if !self.get_interrupts_enabled() {
-            return ();
-        }
-        if self.state == CpuState::Halt {
-            self.state = CpuState::Normal;
-        } else if self.state == CpuState::Stop {
-            //TODO: handle interrupt on stop
-        }
-        
-        //Then handle interrupts
-        if self.get_vblank_interrupt_enabled() && self.get_vblank_interrupt_bit() {
-            //handle vblank interrupt
-            trace!("INT: handle vblank interrupt");
-            let old_pc = self.pc;
-
-            self.disable_interrupts();
-            self.unset_vblank_interrupt_bit();
-            self.push_onto_stack(old_pc);
-
-            self.pc = VBLANK_INTERRUPT_ADDRESS;
-        }
-        else if self.get_lcdc_interrupt_enabled() && self.get_lcdc_interrupt_bit() {
-            //handle lcdc interrupt
-            trace!("INT: handle lcdc interrupt");
-            let old_pc = self.pc;
-            
-            self.disable_interrupts();
-            self.unset_lcdc_interrupt_bit();
-            self.push_onto_stack(old_pc);
-            
-            self.pc = LCDC_INTERRUPT_ADDRESS;
-        }
-        else if self.get_timer_interrupt_enabled() && self.get_timer_interrupt_bit() {
-            //handle timer interrupt
-            trace!("INT: handle timer interrupt");
-            let old_pc = self.pc;
-            
-            self.disable_interrupts();
-            self.unset_timer_interrupt_bit();
-            self.push_onto_stack(old_pc);
-
-            self.pc = TIMER_OVERFLOW_INTERRUPT_ADDRESS;
-        }
-        else if self.get_serial_io_interrupt_enabled() && self.get_serial_io_interrupt_bit() {
-            //handle serial interrupt
-            trace!("INT: handle serial inturrupt");
-            let old_pc = self.pc;
-
-            self.disable_interrupts();
-            self.unset_serial_io_interrupt_bit();
-            self.push_onto_stack(old_pc);
-
-            self.pc = SERIAL_TRANSFER_INTERRUPT_ADDRESS;
-        }
-        else if self.get_input_interrupt_enabled() && self.get_input_interrupt_bit() {
-            debug!("INT: handle input");
-
-            let old_pc = self.pc;
-
-            self.disable_interrupts();
-            self.unset_input_interrupt_bit();
-            self.push_onto_stack(old_pc);
-
-            self.pc = P1013_INTERRUPT_ADDRESS;
-        }
- 
-    }
-    
     /*
     Handles running opcodes
     including handling of interrupts
@@ -1942,12 +1980,28 @@ impl Cpu {
         if self.state == CpuState::Crashed {
             panic!("Attempt to run a crashed cpu PC={}", self.pc);
         }
-        // This may change PC, so should be called before fetching instruction
-        self.handle_interrupts();
+        self.wake_from_halt();
+        self.start_watching();
+        self.access_timing = self.timing_mode == TimingMode::PerAccess;
+        // This changes PC, so should be called before fetching instruction
+        if self.handle_interrupts() {
+            self.finish_instruction(INTERRUPT_DISPATCH_CYCLES);
+            return INTERRUPT_DISPATCH_CYCLES;
+        }
+        self.access_timing = false;
         
         let mut inst_time = 4;
-        let (first_byte, second_byte, third_byte, _) //TODO: verify no 32bit instructions
+        let fetch_pc = self.pc;
+        let (first_byte, mut second_byte, mut third_byte, _) //TODO: verify no 32bit instructions
             = self.read_instruction();
+        if self.halt_bug && self.state == CpuState::Normal {
+            // PC isn't incremented after fetching the opcode, so the
+            // same byte is read again as the first operand
+            self.halt_bug = false;
+            self.pc = self.pc.wrapping_sub(1);
+            third_byte = second_byte;
+            second_byte = first_byte;
+        }
         let x = (first_byte >> 6) & 0x3;
         let y = (first_byte >> 3) & 0x7;
         let z = first_byte        & 0x7;
@@ -1961,7 +2015,7 @@ impl Cpu {
 
         //First check if CPU is in a running state
         if self.state == CpuState::Halt {
-            //TODO: Needs extra handling with interupts
+            self.advance_cycles(inst_time);
             return inst_time; //unsure of this
         } else if self.state == CpuState::Stop {
             return inst_time; //unsure of this
@@ -1973,10 +2027,12 @@ impl Cpu {
                self.is_flag_set(NLV),
                self.is_flag_set(HL),
                self.is_flag_set(CL),
-               self.mem[self.sp as usize + 1], self.mem[self.sp as usize],
-               self.mem[self.hl() as usize]);
+               self.peek(self.sp.wrapping_add(1)), self.peek(self.sp),
+               self.peek(self.hl()));
 
         let old_pc = self.pc;
+        // An EI before this instruction takes effect after it
+        let enable_ime = self.ime_scheduled;
         
         //Then execute instruction
         let (inst_name, inst_len) =
@@ -1991,6 +2047,22 @@ impl Cpu {
             n => error!("Instruction with impossible length: {:?}", n),
         }
 
+        if let Some(ref mut bus) = self.bus {
+            // With the HALT bug the operands start at the opcode again
+            bus.read(fetch_pc);
+            for i in 1..inst_len {
+                bus.read(old_pc.wrapping_add(i as u16));
+            }
+        }
+
+        if self.timing_mode == TimingMode::PerAccess {
+            // The opcode and operands are fetched before anything else
+            self.access_timing = true;
+            for _ in 0..inst_len {
+                self.m_cycle();
+            }
+        }
+
         let uf = "The impossible happened!";
 
         if first_byte == 0xCB { //prefixed instruction
@@ -2321,31 +2393,85 @@ impl Cpu {
         
         self.inc_pc();
 
-        self.cycles = (Wrapping(self.cycles) + Wrapping(inst_time as u64)).0;
+        if enable_ime && self.ime_scheduled {
+            self.ime_scheduled = false;
+            self.enable_interrupts();
+        }
+
+        self.finish_instruction(inst_time);
         
         inst_time
     }
 
+    /// With `access_timing` set, runs the hardware for an M-cycle of
+    /// the instruction being executed
+    fn m_cycle(&mut self) {
+        if self.access_timing {
+            self.timed_cycles += 4;
+            self.advance_cycles(4);
+        }
+    }
+
+    /// Runs the hardware for what is left of the `cycles` the
+    /// instruction took after its M-cycles run by `m_cycle`
+    fn finish_instruction(&mut self, cycles: u8) {
+        let remaining = cycles.saturating_sub(self.timed_cycles);
+        self.access_timing = false;
+        self.timed_cycles = 0;
+        self.advance_cycles(remaining);
+    }
+
+    /// Runs the hardware next to the CPU for as long as the last
+    /// instruction, or M-cycle of it, took
+    fn advance_cycles(&mut self, cycles: u8) {
+        self.cycles = (Wrapping(self.cycles) + Wrapping(cycles as u64)).0;
+        if self.bus.is_some() {
+            // There is no hardware to run
+            return;
+        }
+        self.step_timer(cycles as u32);
+        self.step_serial(cycles as u32);
+        self.step_dma(cycles as u32);
+        // Only the CPU and timer run faster in double speed mode
+        let cycles = if self.cgb.double_speed {
+            cycles as u32 / 2
+        } else {
+            cycles as u32
+        };
+        self.step_ppu(cycles);
+        self.step_apu(cycles);
+    }
+
     pub fn crash(&mut self, info: String) {
         error!("{}", info);
         self.state = CpuState::Crashed;
     }
 
     pub fn load_rom(&mut self, file_path: &str) {
+        self.try_load_rom(file_path).expect("Could not read rom file");
+    }
+
+    /// Like `load_rom`, but leaves the CPU alone if the ROM can't be read
+    pub fn try_load_rom(&mut self, file_path: &str) -> ::std::io::Result<()> {
         use std::fs::File;
         use std::io::Read;
+        use std::path::Path;
 
-        let mut rom = File::open(file_path).expect("Could not open rom file");
-        let mut rom_buffer: [u8; 0x8000] = [0u8; 0x8000];
-
-        rom.read(&mut rom_buffer).unwrap();
+        let mut rom_buffer = vec![];
+        File::open(file_path)?.read_to_end(&mut rom_buffer)?;
 
+        self.cartridge = Cartridge::new(rom_buffer);
+        // Picks DMG or CGB mode for the new cartridge
+        self.reset();
 
-        for i in 0..0x8000 {
-            self.mem[i] = rom_buffer[i] as byte;
+        // Battery-backed RAM lives next to the ROM
+        let save_path = Path::new(file_path).with_extension("sav");
+        if let Err(e) = self.cartridge.load_save_file(&save_path) {
+            error!("Could not load save file {}: {}", save_path.display(), e);
         }
 
         self.reinit_logger();
+        Ok(())
     }
 }
 
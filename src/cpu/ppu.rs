@@ -0,0 +1,459 @@
+//! Pixel processing unit: LCD timing and rendering of the screen
+//!
+//! Each of the 154 scanlines takes 456 cycles.  The 144 visible lines
+//! go through OAM scan (mode 2), pixel transfer (mode 3) and HBlank
+//! (mode 0); the remaining 10 lines are VBlank (mode 1).  A whole
+//! line is rendered into the frame buffer at the end of mode 3.
+//!
+//! In CGB mode tiles can come from either VRAM bank, the attributes of
+//! each background tile are in bank 1 at the same address as the tile
+//! index, and colors come from the CGB palettes.
+
+use cpu::Cpu;
+use cpu::cgb::*;
+use cpu::constants::*;
+use cpu::savestate::*;
+
+pub const SCREEN_WIDTH: usize = 160;
+pub const SCREEN_HEIGHT: usize = 144;
+
+pub const OAM_SCAN_CYCLES: u32 = 80;
+pub const PIXEL_TRANSFER_CYCLES: u32 = 172;
+pub const SCANLINE_CYCLES: u32 = 456;
+pub const LINES_PER_FRAME: u8 = 154;
+
+/// Number of sprites in OAM
+pub const OBJECT_COUNT: usize = 40;
+/// Sprites after the first 10 on a line are not drawn
+pub const OBJECTS_PER_LINE: usize = 10;
+
+const OBJ_FLAG_BEHIND_BG: byte = 0x80;
+const OBJ_FLAG_Y_FLIP: byte = 0x40;
+const OBJ_FLAG_X_FLIP: byte = 0x20;
+const OBJ_FLAG_PALETTE: byte = 0x10;
+const OBJ_FLAG_CGB_BANK: byte = 0x08;
+const OBJ_FLAG_CGB_PALETTE: byte = 0x07;
+
+/// Background map attributes in VRAM bank 1
+const BG_ATTR_PRIORITY: byte = 0x80;
+const BG_ATTR_Y_FLIP: byte = 0x40;
+const BG_ATTR_X_FLIP: byte = 0x20;
+pub const BG_ATTR_BANK: byte = 0x08;
+const BG_ATTR_PALETTE: byte = 0x07;
+
+/// The modes as reported in the lower two bits of STAT
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum PpuMode {
+    HBlank = 0,
+    VBlank = 1,
+    OamScan = 2,
+    PixelTransfer = 3,
+}
+
+/// A visible sprite pixel
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct ObjectPixel {
+    /// Color number in the tile, never 0 as that is transparent
+    pub color: byte,
+    /// Shade after applying OBP0 or OBP1
+    pub shade: byte,
+    /// Palette number, 0-1 or 0-7 in CGB mode
+    pub palette: byte,
+    /// Only drawn over background color 0
+    pub behind_bg: bool,
+}
+
+#[derive(Clone)]
+pub struct Ppu {
+    pub mode: PpuMode,
+    /// Cycles spent on the current scanline
+    line_cycles: u32,
+    /// Shades (0-3, after palettes) of each pixel, row by row
+    frame_buffer: Vec<byte>,
+    /// Colors of each pixel in CGB mode
+    color_buffer: Vec<Rgb555>,
+    /// A frame was completed and not yet picked up
+    frame_ready: bool,
+    /// STAT interrupts are requested on the rising edge of this
+    stat_line: bool,
+    /// Whether the LCD was on at the last step
+    enabled: bool,
+    /// Line of the window to draw next.  Only advances on lines where
+    /// the window is visible, so hiding it for a few lines does not
+    /// skip any of its lines.
+    window_line: usize,
+}
+
+impl Ppu {
+    pub fn new() -> Ppu {
+        Ppu {
+            mode: PpuMode::OamScan,
+            line_cycles: 0,
+            frame_buffer: vec![0; SCREEN_WIDTH * SCREEN_HEIGHT],
+            color_buffer: vec![0x7FFF; SCREEN_WIDTH * SCREEN_HEIGHT],
+            frame_ready: false,
+            stat_line: false,
+            enabled: true,
+            window_line: 0,
+        }
+    }
+}
+
+impl SaveState for Ppu {
+    fn write_state(&self, w: &mut StateWriter) {
+        w.u8(self.mode as u8);
+        w.u32(self.line_cycles);
+        w.bytes(&self.frame_buffer[..]);
+        for &color in &self.color_buffer {
+            w.u16(color);
+        }
+        w.bool(self.frame_ready);
+        w.bool(self.stat_line);
+        w.bool(self.enabled);
+        w.u8(self.window_line as u8);
+    }
+
+    fn read_state(&mut self, r: &mut StateReader) -> Result<(), SaveStateError> {
+        self.mode = match r.u8()? {
+            0 => PpuMode::HBlank,
+            1 => PpuMode::VBlank,
+            2 => PpuMode::OamScan,
+            3 => PpuMode::PixelTransfer,
+            _ => return Err(SaveStateError::Corrupt),
+        };
+        self.line_cycles = r.u32()?;
+        r.bytes_into(&mut self.frame_buffer[..])?;
+        for color in self.color_buffer.iter_mut() {
+            *color = r.u16()?;
+        }
+        self.frame_ready = r.bool()?;
+        self.stat_line = r.bool()?;
+        self.enabled = r.bool()?;
+        self.window_line = r.u8()? as usize;
+        Ok(())
+    }
+}
+
+impl Cpu {
+    /// Shades (0-3) of the screen, `SCREEN_WIDTH` pixels per row
+    pub fn frame_buffer(&self) -> &[byte] {
+        &self.ppu.frame_buffer[..]
+    }
+
+    /// Colors of the screen in CGB mode, laid out like `frame_buffer`
+    pub fn cgb_frame_buffer(&self) -> &[Rgb555] {
+        &self.ppu.color_buffer[..]
+    }
+
+    /// Returns whether a frame was completed since the last call
+    pub fn take_frame_ready(&mut self) -> bool {
+        let ready = self.ppu.frame_ready;
+        self.ppu.frame_ready = false;
+        ready
+    }
+
+    /// Runs the PPU for `cycles` CPU cycles
+    pub fn step_ppu(&mut self, cycles: u32) {
+        if !self.lcdc_on() {
+            if self.ppu.enabled {
+                // LY stays at 0 in mode 0 while the LCD is off
+                self.ppu.enabled = false;
+                self.ppu.line_cycles = 0;
+                self.ppu.stat_line = false;
+                self.io[LY_ADDR] = 0;
+                self.set_ppu_mode(PpuMode::HBlank);
+                self.lyc_compare();
+            }
+            return;
+        }
+        if !self.ppu.enabled {
+            self.ppu.enabled = true;
+            self.ppu.line_cycles = 0;
+            self.ppu.window_line = 0;
+            self.set_ppu_mode(PpuMode::OamScan);
+            self.update_stat_line();
+        }
+
+        self.ppu.line_cycles += cycles;
+        loop {
+            let line_cycles = self.ppu.line_cycles;
+            match self.ppu.mode {
+                PpuMode::OamScan if line_cycles >= OAM_SCAN_CYCLES => {
+                    self.set_ppu_mode(PpuMode::PixelTransfer);
+                }
+                PpuMode::PixelTransfer if line_cycles >=
+                                          OAM_SCAN_CYCLES + PIXEL_TRANSFER_CYCLES => {
+                    self.render_scanline();
+                    self.set_ppu_mode(PpuMode::HBlank);
+                    self.hdma_hblank();
+                }
+                PpuMode::HBlank |
+                PpuMode::VBlank if line_cycles >= SCANLINE_CYCLES => {
+                    self.ppu.line_cycles -= SCANLINE_CYCLES;
+                    self.next_scanline();
+                }
+                _ => break,
+            }
+            self.update_stat_line();
+        }
+    }
+
+    fn next_scanline(&mut self) {
+        let ly = (self.ly() + 1) % LINES_PER_FRAME;
+        self.io[LY_ADDR] = ly;
+        self.lyc_compare();
+
+        if ly as usize == SCREEN_HEIGHT {
+            self.set_ppu_mode(PpuMode::VBlank);
+            self.set_vblank_interrupt_bit();
+            self.ppu.frame_ready = true;
+            self.ppu.window_line = 0;
+        } else if (ly as usize) < SCREEN_HEIGHT {
+            self.set_ppu_mode(PpuMode::OamScan);
+        }
+    }
+
+    fn set_ppu_mode(&mut self, mode: PpuMode) {
+        self.ppu.mode = mode;
+        match mode {
+            PpuMode::HBlank => self.set_hblank(),
+            PpuMode::VBlank => self.set_vblank(),
+            PpuMode::OamScan => self.set_oam_lock(),
+            PpuMode::PixelTransfer => self.set_oam_and_display_lock(),
+        }
+    }
+
+    /// Requests an LCDC interrupt when one of the conditions enabled
+    /// in STAT becomes true
+    pub fn update_stat_line(&mut self) {
+        if !self.ppu.enabled {
+            return;
+        }
+        let line = (self.get_coincidence_interrupt() && self.get_coincidence_flag()) ||
+                   match self.ppu.mode {
+            PpuMode::HBlank => self.get_hblank_interrupt(),
+            PpuMode::VBlank => self.get_vblank_interrupt_stat(),
+            PpuMode::OamScan => self.get_oam_interrupt(),
+            PpuMode::PixelTransfer => false,
+        };
+
+        if line && !self.ppu.stat_line {
+            self.set_lcdc_interrupt_bit();
+        }
+        self.ppu.stat_line = line;
+    }
+
+    /// Color number (0-3) of a pixel of the tile at `tile_addr` in
+    /// VRAM bank `bank`
+    fn tile_pixel(&self, bank: usize, tile_addr: usize, row: usize, col: usize) -> byte {
+        let tile = &self.vram.bank(bank)[tile_addr - DISPLAY_RAM_START..];
+        let low = tile[row * 2];
+        let high = tile[row * 2 + 1];
+        let bit = 7 - col;
+        (((high >> bit) & 1) << 1) | ((low >> bit) & 1)
+    }
+
+    /// Address of a background or window tile, indexed either
+    /// unsigned from 0x8000 or signed from 0x9000
+    fn bg_tile_addr(&self, tile_index: byte) -> usize {
+        if self.lcdc_bg_win_tile_data() {
+            0x8000 + (tile_index as usize) * 16
+        } else {
+            (0x9000 + (tile_index as i8 as isize) * 16) as usize
+        }
+    }
+
+    /// Attributes of the background tile at `map_addr`, always 0
+    /// outside of CGB mode
+    pub fn bg_attributes(&self, map_addr: usize) -> byte {
+        if self.cgb_mode() {
+            self.vram.bank(1)[map_addr - DISPLAY_RAM_START]
+        } else {
+            0
+        }
+    }
+
+    /// Color number and attributes of pixel (`x`, `y`) of the tile map
+    /// at `map`
+    fn map_pixel(&self, map: usize, x: usize, y: usize) -> (byte, byte) {
+        let map_addr = map + (y / 8) * 32 + x / 8;
+        let tile_index = self.vram.bank(0)[map_addr - DISPLAY_RAM_START];
+        let attributes = self.bg_attributes(map_addr);
+        let bank = if attributes & BG_ATTR_BANK != 0 { 1 } else { 0 };
+        let row = if attributes & BG_ATTR_Y_FLIP != 0 { 7 - y % 8 } else { y % 8 };
+        let col = if attributes & BG_ATTR_X_FLIP != 0 { 7 - x % 8 } else { x % 8 };
+        let color = self.tile_pixel(bank, self.bg_tile_addr(tile_index), row, col);
+        (color, attributes)
+    }
+
+    /// Whether any of the window is drawn on scanline `ly`.  In CGB
+    /// mode LCDC bit 0 doesn't hide the background and window.
+    pub fn window_visible(&self, ly: usize) -> bool {
+        (self.cgb_mode() || self.lcdc_bg_win_display()) && self.lcdc_window_on() &&
+        ly >= self.wy() as usize && (self.wx() as usize) < SCREEN_WIDTH + 7
+    }
+
+    /// Color number of a pixel of the 256x256 background
+    pub fn bg_color_at(&self, x: u8, y: u8) -> byte {
+        let map = if self.lcdc_bg_tile_map() { 0x9C00 } else { 0x9800 };
+        self.map_pixel(map, x as usize, y as usize).0
+    }
+
+    /// OAM indices of the sprites on scanline `ly` as picked by the
+    /// OAM scan, highest priority first.  Only the first 10 sprites in
+    /// OAM order are picked, even if they are outside the screen
+    /// horizontally; the one with the smaller X is drawn on top, or
+    /// the one earlier in OAM if both have the same X.  In CGB mode
+    /// only the OAM order counts.
+    pub fn objects_on_line(&self, ly: usize) -> Vec<usize> {
+        let height = if self.lcdc_sprite_size() { 16 } else { 8 };
+        let mut objects = vec![];
+        for obj in 0..OBJECT_COUNT {
+            let top = self.oam[OAM_START + obj * 4] as usize;
+            // Y is the position of the sprite's top plus 16
+            if ly + 16 >= top && ly + 16 < top + height {
+                objects.push(obj);
+                if objects.len() == OBJECTS_PER_LINE {
+                    break;
+                }
+            }
+        }
+        if self.cgb_mode() {
+            return objects;
+        }
+        // A stable sort keeps OAM order for equal X
+        objects.sort_by_key(|&obj| self.oam[OAM_START + obj * 4 + 1]);
+        objects
+    }
+
+    /// The sprite pixels visible on scanline `ly`, before deciding
+    /// whether the background covers them
+    pub fn object_line(&self, ly: usize) -> [Option<ObjectPixel>; SCREEN_WIDTH] {
+        let mut pixels = [None; SCREEN_WIDTH];
+        let height = if self.lcdc_sprite_size() { 16 } else { 8 };
+
+        for obj in self.objects_on_line(ly) {
+            let attr = OAM_START + obj * 4;
+            let left = self.oam[attr + 1] as isize - 8;
+            let flags = self.oam[attr + 3];
+            let (palette, bank) = if self.cgb_mode() {
+                (flags & OBJ_FLAG_CGB_PALETTE,
+                 if flags & OBJ_FLAG_CGB_BANK != 0 { 1 } else { 0 })
+            } else {
+                (if flags & OBJ_FLAG_PALETTE == 0 { 0 } else { 1 }, 0)
+            };
+            let obp = if palette == 0 { self.io[0xFF48] } else { self.io[0xFF49] };
+
+            let mut row = ly + 16 - self.oam[attr] as usize;
+            if flags & OBJ_FLAG_Y_FLIP != 0 {
+                row = height - 1 - row;
+            }
+            // 8x16 sprites are two consecutive tiles, the first of
+            // which has an even index
+            let mut tile_index = self.oam[attr + 2] as usize;
+            if height == 16 {
+                tile_index &= !1;
+            }
+            let tile_addr = 0x8000 + (tile_index + row / 8) * 16;
+
+            for col in 0..8 {
+                let x = left + col as isize;
+                if x < 0 || x >= SCREEN_WIDTH as isize || pixels[x as usize].is_some() {
+                    continue;
+                }
+                let tile_col = if flags & OBJ_FLAG_X_FLIP != 0 { 7 - col } else { col };
+                let color = self.tile_pixel(bank, tile_addr, row % 8, tile_col);
+                // Color 0 is transparent and lets lower priority
+                // sprites through
+                if color != 0 {
+                    pixels[x as usize] = Some(ObjectPixel {
+                        color: color,
+                        shade: palette_shade(obp, color),
+                        palette: palette,
+                        behind_bg: flags & OBJ_FLAG_BEHIND_BG != 0,
+                    });
+                }
+            }
+        }
+
+        pixels
+    }
+
+    fn render_scanline(&mut self) {
+        let ly = self.ly() as usize;
+        if ly >= SCREEN_HEIGHT {
+            return;
+        }
+        let cgb = self.cgb_mode();
+        let mut line = [0u8; SCREEN_WIDTH];
+        let mut attributes = [0u8; SCREEN_WIDTH];
+
+        if cgb || self.lcdc_bg_win_display() {
+            let map = if self.lcdc_bg_tile_map() { 0x9C00 } else { 0x9800 };
+            let y = self.scy().wrapping_add(ly as u8) as usize;
+            for x in 0..SCREEN_WIDTH {
+                let bg_x = self.scx().wrapping_add(x as u8) as usize;
+                let (color, attr) = self.map_pixel(map, bg_x, y);
+                line[x] = color;
+                attributes[x] = attr;
+            }
+
+            if self.window_visible(ly) {
+                let map = if self.lcdc_tile_map() { 0x9C00 } else { 0x9800 };
+                let y = self.ppu.window_line;
+                // WX is the window position plus 7
+                let wx = self.wx() as usize;
+                for x in 0..SCREEN_WIDTH {
+                    if x + 7 < wx {
+                        continue;
+                    }
+                    let (color, attr) = self.map_pixel(map, x + 7 - wx, y);
+                    line[x] = color;
+                    attributes[x] = attr;
+                }
+                self.ppu.window_line += 1;
+            }
+        }
+
+        let bgp = self.io[0xFF47];
+        let mut shades = [0u8; SCREEN_WIDTH];
+        let mut colors = [0u16; SCREEN_WIDTH];
+        for x in 0..SCREEN_WIDTH {
+            shades[x] = palette_shade(bgp, line[x]);
+            if cgb {
+                colors[x] = self.bg_palette_color(attributes[x] & BG_ATTR_PALETTE, line[x]);
+            }
+        }
+
+        if self.lcdc_sprite_display() {
+            // In CGB mode clearing LCDC bit 0 puts sprites above the
+            // background whatever their flags
+            let bg_priority = !cgb || self.lcdc_bg_win_display();
+            let objects = self.object_line(ly);
+            for x in 0..SCREEN_WIDTH {
+                if let Some(pixel) = objects[x] {
+                    // BG colors 1-3 cover sprites with the priority
+                    // flag, or any sprite if the tile has it set
+                    let behind = pixel.behind_bg || attributes[x] & BG_ATTR_PRIORITY != 0;
+                    if !bg_priority || !behind || line[x] == 0 {
+                        shades[x] = pixel.shade;
+                        if cgb {
+                            colors[x] = self.obj_palette_color(pixel.palette, pixel.color);
+                        }
+                    }
+                }
+            }
+        }
+
+        let start = ly * SCREEN_WIDTH;
+        self.ppu.frame_buffer[start..start + SCREEN_WIDTH].copy_from_slice(&shades);
+        if cgb {
+            self.ppu.color_buffer[start..start + SCREEN_WIDTH].copy_from_slice(&colors);
+        }
+    }
+}
+
+/// Maps a color number to a shade with a palette register
+fn palette_shade(palette: byte, color: byte) -> byte {
+    (palette >> (color * 2)) & 0x3
+}
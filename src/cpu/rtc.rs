@@ -0,0 +1,222 @@
+//! Real time clock of MBC3 cartridges
+//!
+//! The clock keeps counting while the emulator is not running: the
+//! registers are stored together with the host time they were valid
+//! at, and are advanced by the elapsed host time when needed.
+
+use std::time::{SystemTime, UNIX_EPOCH};
+
+use cpu::constants::*;
+
+/// Source of host time in seconds since the Unix epoch
+pub type Clock = fn() -> u64;
+
+/// Size of the RTC data appended to the `.sav` file
+pub const RTC_SAVE_SIZE: usize = 48;
+/// Older emulators store the timestamp as 32 bits
+const RTC_SAVE_SIZE_32BIT: usize = 44;
+
+/// Register numbers as selected through 0x4000-0x5FFF
+pub const RTC_SECONDS: u8 = 0x08;
+pub const RTC_MINUTES: u8 = 0x09;
+pub const RTC_HOURS: u8 = 0x0A;
+pub const RTC_DAY_LOW: u8 = 0x0B;
+pub const RTC_DAY_HIGH: u8 = 0x0C;
+
+const DAY_HIGH_MSB: u8 = 0x01;
+const DAY_HIGH_HALT: u8 = 0x40;
+const DAY_HIGH_CARRY: u8 = 0x80;
+
+pub fn system_clock() -> u64 {
+    match SystemTime::now().duration_since(UNIX_EPOCH) {
+        Ok(d) => d.as_secs(),
+        Err(_) => 0,
+    }
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct Rtc {
+    seconds: u8,
+    minutes: u8,
+    hours: u8,
+    /// 9 bit day counter
+    days: u16,
+    halted: bool,
+    /// Set when the day counter overflows, cleared only by software
+    day_carry: bool,
+    /// Copy of the registers made on latch, this is what is read
+    latched: [u8; 5],
+    /// A 0x00 was written to the latch register, 0x01 latches
+    latch_armed: bool,
+    /// Host time the registers were last brought up to date at
+    last_update: u64,
+}
+
+impl Rtc {
+    pub fn new(now: u64) -> Rtc {
+        Rtc {
+            seconds: 0,
+            minutes: 0,
+            hours: 0,
+            days: 0,
+            halted: false,
+            day_carry: false,
+            latched: [0; 5],
+            latch_armed: false,
+            last_update: now,
+        }
+    }
+
+    /// Advances the registers to host time `now`
+    pub fn update(&mut self, now: u64) {
+        if !self.halted && now > self.last_update {
+            let elapsed = now - self.last_update;
+            self.advance(elapsed);
+        }
+        self.last_update = now;
+    }
+
+    fn advance(&mut self, mut secs: u64) {
+        // Registers set out of range by software count up to the
+        // limit of their bits and wrap to 0 without carrying
+        while secs > 0 && (self.seconds >= 60 || self.minutes >= 60 || self.hours >= 24) {
+            self.tick();
+            secs -= 1;
+        }
+        if secs == 0 {
+            return;
+        }
+
+        let total = secs + (self.seconds as u64) + 60 * (self.minutes as u64) +
+                    3600 * (self.hours as u64) + 86400 * (self.days as u64);
+        let mut days = total / 86400;
+        if days > 0x1FF {
+            self.day_carry = true;
+            days %= 0x200;
+        }
+        self.days = days as u16;
+        self.hours = ((total / 3600) % 24) as u8;
+        self.minutes = ((total / 60) % 60) as u8;
+        self.seconds = (total % 60) as u8;
+    }
+
+    /// Advances the clock by a single second
+    fn tick(&mut self) {
+        self.seconds = (self.seconds + 1) & 0x3F;
+        if self.seconds != 60 {
+            return;
+        }
+        self.seconds = 0;
+        self.minutes = (self.minutes + 1) & 0x3F;
+        if self.minutes != 60 {
+            return;
+        }
+        self.minutes = 0;
+        self.hours = (self.hours + 1) & 0x1F;
+        if self.hours != 24 {
+            return;
+        }
+        self.hours = 0;
+        self.days += 1;
+        if self.days > 0x1FF {
+            self.days = 0;
+            self.day_carry = true;
+        }
+    }
+
+    fn registers(&self) -> [u8; 5] {
+        [self.seconds,
+         self.minutes,
+         self.hours,
+         (self.days & 0xFF) as u8,
+         self.day_high()]
+    }
+
+    fn day_high(&self) -> u8 {
+        ((self.days >> 8) as u8 & DAY_HIGH_MSB) | (if self.halted { DAY_HIGH_HALT } else { 0 }) |
+        (if self.day_carry { DAY_HIGH_CARRY } else { 0 })
+    }
+
+    /// Handles writes to 0x6000-0x7FFF: writing 0x00 then 0x01
+    /// copies the current time into the latched registers
+    pub fn write_latch(&mut self, value: byte, now: u64) {
+        if self.latch_armed && value == 0x01 {
+            self.update(now);
+            self.latched = self.registers();
+        }
+        self.latch_armed = value == 0x00;
+    }
+
+    /// Reads a latched register
+    pub fn read(&self, register: u8) -> byte {
+        match register {
+            RTC_SECONDS...RTC_DAY_HIGH => self.latched[(register - RTC_SECONDS) as usize],
+            _ => 0xFF,
+        }
+    }
+
+    /// Writes a live register
+    pub fn write(&mut self, register: u8, value: byte, now: u64) {
+        self.update(now);
+        match register {
+            RTC_SECONDS => self.seconds = value & 0x3F,
+            RTC_MINUTES => self.minutes = value & 0x3F,
+            RTC_HOURS => self.hours = value & 0x1F,
+            RTC_DAY_LOW => self.days = (self.days & 0x100) | (value as u16),
+            RTC_DAY_HIGH => {
+                self.days = (self.days & 0xFF) | (((value & DAY_HIGH_MSB) as u16) << 8);
+                self.halted = value & DAY_HIGH_HALT != 0;
+                self.day_carry = value & DAY_HIGH_CARRY != 0;
+            }
+            _ => error!("Write to invalid RTC register 0x{:02X}", register),
+        }
+    }
+
+    /// Serializes the clock in the format appended to `.sav` files
+    /// by other emulators: the live and the latched registers as
+    /// little endian 32 bit values, followed by a 64 bit timestamp
+    pub fn to_save_data(&self) -> Vec<u8> {
+        let mut data = Vec::with_capacity(RTC_SAVE_SIZE);
+        for &reg in self.registers().iter().chain(self.latched.iter()) {
+            push_le(&mut data, reg as u64, 4);
+        }
+        push_le(&mut data, self.last_update, 8);
+        data
+    }
+
+    /// Restores the clock from the data written by `to_save_data`.
+    /// Returns `false` if the data has the wrong size.
+    pub fn load_save_data(&mut self, data: &[u8]) -> bool {
+        if data.len() != RTC_SAVE_SIZE && data.len() != RTC_SAVE_SIZE_32BIT {
+            return false;
+        }
+        let mut regs = [0u8; 10];
+        for (i, reg) in regs.iter_mut().enumerate() {
+            *reg = read_le(&data[i * 4..], 4) as u8;
+        }
+
+        self.seconds = regs[0] & 0x3F;
+        self.minutes = regs[1] & 0x3F;
+        self.hours = regs[2] & 0x1F;
+        self.days = (regs[3] as u16) | (((regs[4] & DAY_HIGH_MSB) as u16) << 8);
+        self.halted = regs[4] & DAY_HIGH_HALT != 0;
+        self.day_carry = regs[4] & DAY_HIGH_CARRY != 0;
+        self.latched.copy_from_slice(&regs[5..10]);
+        self.last_update = read_le(&data[40..], data.len() - 40);
+        true
+    }
+}
+
+fn push_le(data: &mut Vec<u8>, value: u64, bytes: usize) {
+    for i in 0..bytes {
+        data.push((value >> (8 * i)) as u8);
+    }
+}
+
+fn read_le(data: &[u8], bytes: usize) -> u64 {
+    let mut value = 0;
+    for i in 0..bytes {
+        value |= (data[i] as u64) << (8 * i);
+    }
+    value
+}
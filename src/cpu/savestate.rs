@@ -0,0 +1,331 @@
+//! Save states
+//!
+//! A save state holds everything about the machine except the ROM.
+//! The file starts with a header:
+//!
+//! | offset | size | contents                            |
+//! |--------|------|-------------------------------------|
+//! | 0      | 8    | `SAVE_STATE_MAGIC`                  |
+//! | 8      | 4    | format version                      |
+//! | 12     | 4    | Adler-32 of the ROM it is for       |
+//!
+//! followed by the state of the CPU and each peripheral in turn, as
+//! written by their `SaveState` implementations.  Numbers are little
+//! endian.  Any change to what is saved has to bump
+//! `SAVE_STATE_VERSION`, states of other versions are refused instead
+//! of being misread.
+
+use std::fmt;
+use std::fs::File;
+use std::io;
+use std::io::{Read, Write};
+use std::path::{Path, PathBuf};
+
+use checksum::adler32;
+use cpu::Cpu;
+use cpu::bootrom::*;
+use cpu::constants::*;
+
+pub const SAVE_STATE_MAGIC: &'static [u8] = b"RBSTATE\x1A";
+pub const SAVE_STATE_VERSION: u32 = 7;
+
+/// Number of slots selectable from the keyboard
+pub const SAVE_STATE_SLOTS: u8 = 10;
+
+#[derive(Debug)]
+pub enum SaveStateError {
+    Io(io::Error),
+    /// The file doesn't start with `SAVE_STATE_MAGIC`
+    NotASaveState,
+    /// Made by a version of rusty-boy with a different format
+    UnsupportedVersion(u32),
+    /// Made while running a different ROM
+    WrongRom,
+    /// The data ends early or holds impossible values
+    Corrupt,
+}
+
+impl fmt::Display for SaveStateError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match *self {
+            SaveStateError::Io(ref e) => write!(f, "{}", e),
+            SaveStateError::NotASaveState => write!(f, "not a save state"),
+            SaveStateError::UnsupportedVersion(v) => {
+                write!(f,
+                       "save state format version {} is not supported (expected {})",
+                       v,
+                       SAVE_STATE_VERSION)
+            }
+            SaveStateError::WrongRom => write!(f, "save state was made with a different ROM"),
+            SaveStateError::Corrupt => write!(f, "save state is corrupt"),
+        }
+    }
+}
+
+impl From<io::Error> for SaveStateError {
+    fn from(e: io::Error) -> SaveStateError {
+        SaveStateError::Io(e)
+    }
+}
+
+/// State which can be written to and restored from a save state
+pub trait SaveState {
+    fn write_state(&self, w: &mut StateWriter);
+    fn read_state(&mut self, r: &mut StateReader) -> Result<(), SaveStateError>;
+}
+
+pub struct StateWriter {
+    data: Vec<u8>,
+}
+
+impl StateWriter {
+    pub fn new() -> StateWriter {
+        StateWriter { data: vec![] }
+    }
+
+    pub fn u8(&mut self, value: u8) {
+        self.data.push(value);
+    }
+
+    pub fn u16(&mut self, value: u16) {
+        self.u64_bytes(value as u64, 2);
+    }
+
+    pub fn u32(&mut self, value: u32) {
+        self.u64_bytes(value as u64, 4);
+    }
+
+    pub fn u64(&mut self, value: u64) {
+        self.u64_bytes(value, 8);
+    }
+
+    fn u64_bytes(&mut self, value: u64, bytes: usize) {
+        for i in 0..bytes {
+            self.data.push((value >> (8 * i)) as u8);
+        }
+    }
+
+    pub fn bool(&mut self, value: bool) {
+        self.u8(value as u8);
+    }
+
+    /// A block of bytes preceded by its length
+    pub fn bytes(&mut self, value: &[u8]) {
+        self.u32(value.len() as u32);
+        self.data.extend_from_slice(value);
+    }
+
+    pub fn into_data(self) -> Vec<u8> {
+        self.data
+    }
+}
+
+pub struct StateReader<'a> {
+    data: &'a [u8],
+    pos: usize,
+}
+
+impl<'a> StateReader<'a> {
+    pub fn new(data: &'a [u8]) -> StateReader<'a> {
+        StateReader { data: data, pos: 0 }
+    }
+
+    fn take(&mut self, len: usize) -> Result<&'a [u8], SaveStateError> {
+        if self.data.len() - self.pos < len {
+            return Err(SaveStateError::Corrupt);
+        }
+        let data = &self.data[self.pos..self.pos + len];
+        self.pos += len;
+        Ok(data)
+    }
+
+    fn u64_bytes(&mut self, bytes: usize) -> Result<u64, SaveStateError> {
+        let data = self.take(bytes)?;
+        let mut value = 0;
+        for (i, &b) in data.iter().enumerate() {
+            value |= (b as u64) << (8 * i);
+        }
+        Ok(value)
+    }
+
+    pub fn u8(&mut self) -> Result<u8, SaveStateError> {
+        Ok(self.take(1)?[0])
+    }
+
+    pub fn u16(&mut self) -> Result<u16, SaveStateError> {
+        Ok(self.u64_bytes(2)? as u16)
+    }
+
+    pub fn u32(&mut self) -> Result<u32, SaveStateError> {
+        Ok(self.u64_bytes(4)? as u32)
+    }
+
+    pub fn u64(&mut self) -> Result<u64, SaveStateError> {
+        self.u64_bytes(8)
+    }
+
+    pub fn bool(&mut self) -> Result<bool, SaveStateError> {
+        match self.u8()? {
+            0 => Ok(false),
+            1 => Ok(true),
+            _ => Err(SaveStateError::Corrupt),
+        }
+    }
+
+    pub fn bytes(&mut self) -> Result<&'a [u8], SaveStateError> {
+        let len = self.u32()? as usize;
+        self.take(len)
+    }
+
+    /// Reads a block of bytes into `dest`, which must be the same size
+    pub fn bytes_into(&mut self, dest: &mut [u8]) -> Result<(), SaveStateError> {
+        let data = self.bytes()?;
+        if data.len() != dest.len() {
+            return Err(SaveStateError::Corrupt);
+        }
+        dest.copy_from_slice(data);
+        Ok(())
+    }
+}
+
+/// File used for save state `slot` of the ROM at `rom_path`
+pub fn save_state_path(rom_path: &Path, slot: u8) -> PathBuf {
+    rom_path.with_extension(format!("ss{}", slot))
+}
+
+impl SaveState for CpuState {
+    fn write_state(&self, w: &mut StateWriter) {
+        w.u8(match *self {
+            CpuState::Normal => 0,
+            CpuState::Halt => 1,
+            CpuState::Stop => 2,
+            CpuState::Crashed => 3,
+        });
+    }
+
+    fn read_state(&mut self, r: &mut StateReader) -> Result<(), SaveStateError> {
+        *self = match r.u8()? {
+            0 => CpuState::Normal,
+            1 => CpuState::Halt,
+            2 => CpuState::Stop,
+            3 => CpuState::Crashed,
+            _ => return Err(SaveStateError::Corrupt),
+        };
+        Ok(())
+    }
+}
+
+impl Cpu {
+    /// The state of the whole machine, for `load_state`
+    pub fn save_state(&self) -> Vec<u8> {
+        let mut w = StateWriter::new();
+        for &b in SAVE_STATE_MAGIC {
+            w.u8(b);
+        }
+        w.u32(SAVE_STATE_VERSION);
+        w.u32(adler32(self.cartridge.rom()));
+
+        for &reg in &[self.a, self.b, self.c, self.d, self.e, self.f, self.h, self.l] {
+            w.u8(reg);
+        }
+        w.u16(self.sp);
+        w.u16(self.pc);
+        w.bool(self.ime);
+        w.bool(self.ime_scheduled);
+        w.bool(self.halt_bug);
+        self.state.write_state(&mut w);
+        w.u8(self.input_state);
+        w.u64(self.cycles);
+        self.io.write_state(&mut w);
+        match self.boot_rom {
+            Some(ref boot_rom) => w.bytes(&boot_rom[..]),
+            None => w.bytes(&[]),
+        }
+
+        self.cartridge.write_state(&mut w);
+        self.ppu.write_state(&mut w);
+        self.timer.write_state(&mut w);
+        self.apu.write_state(&mut w);
+        self.vram.write_state(&mut w);
+        self.wram.write_state(&mut w);
+        self.oam.write_state(&mut w);
+        self.hram.write_state(&mut w);
+        self.cgb.write_state(&mut w);
+        self.serial.write_state(&mut w);
+        self.dma.write_state(&mut w);
+
+        w.into_data()
+    }
+
+    /// Restores a state made by `save_state` for the same ROM.  The
+    /// CPU is left as it was if the state can't be loaded.
+    pub fn load_state(&mut self, data: &[u8]) -> Result<(), SaveStateError> {
+        if !data.starts_with(SAVE_STATE_MAGIC) {
+            return Err(SaveStateError::NotASaveState);
+        }
+        let mut r = StateReader::new(data);
+        r.take(SAVE_STATE_MAGIC.len())?;
+        let version = r.u32()?;
+        if version != SAVE_STATE_VERSION {
+            return Err(SaveStateError::UnsupportedVersion(version));
+        }
+        if r.u32()? != adler32(self.cartridge.rom()) {
+            return Err(SaveStateError::WrongRom);
+        }
+
+        let mut cpu = self.clone();
+        cpu.a = r.u8()?;
+        cpu.b = r.u8()?;
+        cpu.c = r.u8()?;
+        cpu.d = r.u8()?;
+        cpu.e = r.u8()?;
+        cpu.f = r.u8()?;
+        cpu.h = r.u8()?;
+        cpu.l = r.u8()?;
+        cpu.sp = r.u16()?;
+        cpu.pc = r.u16()?;
+        cpu.ime = r.bool()?;
+        cpu.ime_scheduled = r.bool()?;
+        cpu.halt_bug = r.bool()?;
+        cpu.state.read_state(&mut r)?;
+        cpu.input_state = r.u8()?;
+        cpu.cycles = r.u64()?;
+        cpu.io.read_state(&mut r)?;
+        cpu.boot_rom = match r.bytes()? {
+            boot_rom if boot_rom.is_empty() => None,
+            boot_rom if boot_rom.len() == BOOT_ROM_SIZE => Some(boot_rom.to_vec()),
+            _ => return Err(SaveStateError::Corrupt),
+        };
+
+        cpu.cartridge.read_state(&mut r)?;
+        cpu.ppu.read_state(&mut r)?;
+        cpu.timer.read_state(&mut r)?;
+        cpu.apu.read_state(&mut r)?;
+        cpu.vram.read_state(&mut r)?;
+        cpu.wram.read_state(&mut r)?;
+        cpu.oam.read_state(&mut r)?;
+        cpu.hram.read_state(&mut r)?;
+        cpu.cgb.read_state(&mut r)?;
+        cpu.serial.read_state(&mut r)?;
+        cpu.dma.read_state(&mut r)?;
+        if r.pos != data.len() {
+            return Err(SaveStateError::Corrupt);
+        }
+
+        *self = cpu;
+        self.reinit_logger();
+        Ok(())
+    }
+
+    pub fn save_state_to_file(&self, path: &Path) -> Result<(), SaveStateError> {
+        let mut file = File::create(path)?;
+        file.write_all(&self.save_state()[..])?;
+        Ok(())
+    }
+
+    pub fn load_state_from_file(&mut self, path: &Path) -> Result<(), SaveStateError> {
+        let mut data = vec![];
+        File::open(path)?.read_to_end(&mut data)?;
+        self.load_state(&data[..])
+    }
+}
@@ -0,0 +1,195 @@
+//! Serial port
+//!
+//! A transfer swaps the contents of SB with the Game Boy at the other
+//! end of the link cable, one bit at a time.  The Game Boy using the
+//! internal clock drives the transfer at 8192Hz (262144Hz with the CGB
+//! fast clock), the other one waits with the external clock until
+//! bits arrive.  Both request the serial interrupt when done.  With
+//! nothing connected the internal clock shifts in 1s and the external
+//! clock never finishes.
+
+use std::cell::RefCell;
+use std::io::Write;
+use std::rc::Rc;
+
+use cpu::Cpu;
+use cpu::constants::*;
+use cpu::savestate::*;
+
+/// Serial transfer data
+pub const SB_ADDR: usize = 0xFF01;
+/// Serial transfer control
+pub const SC_ADDR: usize = 0xFF02;
+
+const SC_TRANSFER: byte = 0x80;
+const SC_FAST_CLOCK: byte = 0x02;
+const SC_INTERNAL_CLOCK: byte = 0x01;
+
+/// Cycles per bit with the internal clock
+const BIT_CYCLES: u32 = 512;
+const FAST_BIT_CYCLES: u32 = 16;
+
+/// The other end of the link cable
+pub trait SerialLink {
+    /// Sends a byte clocked out by this side and returns the byte
+    /// shifted in from the other side, 0xFF if nothing answered
+    fn transfer(&mut self, value: byte) -> byte;
+
+    /// Returns the byte the other side clocked over since the last
+    /// call if any, after answering it with `reply`
+    fn clocked_by_peer(&mut self, reply: byte) -> Option<byte>;
+}
+
+/// A link shared by clones of the CPU, so loading a state doesn't
+/// unplug the cable
+pub type SharedLink = Rc<RefCell<Box<SerialLink>>>;
+
+/// Writes bytes sent over serial to `W`, e.g. to show the output of
+/// test ROMs
+pub struct SerialSink<W: Write> {
+    out: W,
+}
+
+impl<W: Write> SerialSink<W> {
+    pub fn new(out: W) -> SerialSink<W> {
+        SerialSink { out: out }
+    }
+}
+
+impl<W: Write> SerialLink for SerialSink<W> {
+    fn transfer(&mut self, value: byte) -> byte {
+        if let Err(e) = self.out.write_all(&[value]).and_then(|_| self.out.flush()) {
+            error!("Could not write serial output: {}", e);
+        }
+        0xFF
+    }
+
+    fn clocked_by_peer(&mut self, _: byte) -> Option<byte> {
+        None
+    }
+}
+
+#[derive(Clone)]
+pub struct Serial {
+    /// Cycles left in a transfer using the internal clock
+    transfer_cycles: Option<u32>,
+    /// Cycles until the link is next checked for bytes from the other
+    /// side
+    poll_cycles: u32,
+    link: Option<SharedLink>,
+}
+
+impl Serial {
+    pub fn new() -> Serial {
+        Serial {
+            transfer_cycles: None,
+            poll_cycles: BIT_CYCLES,
+            link: None,
+        }
+    }
+}
+
+impl SaveState for Serial {
+    fn write_state(&self, w: &mut StateWriter) {
+        // 0 is never a pending transfer
+        w.u32(self.transfer_cycles.unwrap_or(0));
+    }
+
+    fn read_state(&mut self, r: &mut StateReader) -> Result<(), SaveStateError> {
+        self.transfer_cycles = match r.u32()? {
+            0 => None,
+            n => Some(n),
+        };
+        Ok(())
+    }
+}
+
+impl Cpu {
+    /// Plugs `link` into the serial port
+    pub fn connect_serial(&mut self, link: Box<SerialLink>) {
+        self.serial.link = Some(Rc::new(RefCell::new(link)));
+    }
+
+    /// Takes the link out of the serial port, so nothing is sent to
+    /// the other side until it's put back with `replug_serial`
+    pub fn unplug_serial(&mut self) -> Option<SharedLink> {
+        self.serial.link.take()
+    }
+
+    pub fn replug_serial(&mut self, link: Option<SharedLink>) {
+        self.serial.link = link;
+    }
+
+    /// Stops any transfer and sets SB and SC as the boot ROM leaves them
+    pub fn reset_serial(&mut self) {
+        self.serial.transfer_cycles = None;
+        self.io[SB_ADDR] = 0;
+        self.io[SC_ADDR] = if self.cgb_mode() { 0x7F } else { 0x7E };
+    }
+
+    /// Only bits 7 and 0 of SC exist, and bit 1 in CGB mode
+    pub fn write_sc(&mut self, value: byte) {
+        self.io[SC_ADDR] = if self.cgb_mode() {
+            0x7C | (value & 0x83)
+        } else {
+            0x7E | (value & 0x81)
+        };
+
+        if value & (SC_TRANSFER | SC_INTERNAL_CLOCK) == SC_TRANSFER | SC_INTERNAL_CLOCK {
+            let bit_cycles = if self.cgb_mode() && value & SC_FAST_CLOCK != 0 {
+                FAST_BIT_CYCLES
+            } else {
+                BIT_CYCLES
+            };
+            self.serial.transfer_cycles = Some(8 * bit_cycles);
+        } else {
+            self.serial.transfer_cycles = None;
+        }
+    }
+
+    /// Runs the serial port for `cycles` CPU cycles
+    pub fn step_serial(&mut self, cycles: u32) {
+        if let Some(left) = self.serial.transfer_cycles {
+            if left > cycles {
+                self.serial.transfer_cycles = Some(left - cycles);
+            } else {
+                self.serial.transfer_cycles = None;
+                let value = self.io[SB_ADDR];
+                let received = match self.serial.link {
+                    Some(ref link) => link.borrow_mut().transfer(value),
+                    None => 0xFF,
+                };
+                self.finish_serial_transfer(received);
+            }
+            return;
+        }
+
+        // The other side expects an answer even if no transfer was
+        // started, checking every bit time is often enough for that
+        if self.serial.poll_cycles > cycles {
+            self.serial.poll_cycles -= cycles;
+            return;
+        }
+        self.serial.poll_cycles = BIT_CYCLES;
+        let waiting = self.io[SC_ADDR] & (SC_TRANSFER | SC_INTERNAL_CLOCK) == SC_TRANSFER;
+        let reply = if waiting { self.io[SB_ADDR] } else { 0xFF };
+        let received = match self.serial.link {
+            Some(ref link) => link.borrow_mut().clocked_by_peer(reply),
+            None => None,
+        };
+        if let Some(received) = received {
+            if waiting {
+                self.finish_serial_transfer(received);
+            }
+        }
+    }
+
+    fn finish_serial_transfer(&mut self, received: byte) {
+        trace!("Serial transfer: sent 0x{:02X}, received 0x{:02X}",
+               self.io[SB_ADDR],
+               received);
+        self.io[SB_ADDR] = received;
+        self.io[SC_ADDR] &= !SC_TRANSFER;
+        self.set_serial_io_interrupt_bit();
+    }
+}
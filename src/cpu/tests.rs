@@ -18,6 +18,10 @@ macro_rules! test_op {
             if let CpuRegister::Num(_) = in_arg {
                 ()
             } else {
+                // (HL) must point at writable memory, not the ROM
+                if in_arg == CpuRegister::HL {
+                    cpu.set_hl(0xC000);
+                }
                 cpu.set_register(in_arg, $pre_exec);
             }
             
@@ -287,7 +291,175 @@ fn test_stop() {
     assert_eq!(cpu.state, CpuState::Normal);
 }
 
-//TODO: Test execution of DI and EI
+#[test]
+fn test_joypad_register() {
+    let mut cpu = Cpu::new();
+    // Both rows are selected after the boot ROM
+    assert_eq!(cpu.get_mem(0xFF00), 0xCF);
+    cpu.press_a();
+    cpu.press_down();
+    assert_eq!(cpu.get_mem(0xFF00), 0xC6);
+
+    // Reads follow the buttons without writing JOYP again
+    cpu.set_mem(0xFF00, 0x10);
+    assert_eq!(cpu.get_mem(0xFF00), 0xDE);
+    cpu.unpress_a();
+    assert_eq!(cpu.get_mem(0xFF00), 0xDF);
+    cpu.set_mem(0xFF00, 0x20);
+    assert_eq!(cpu.get_mem(0xFF00), 0xE7);
+    cpu.press_left();
+    assert_eq!(cpu.get_mem(0xFF00), 0xE5);
+
+    // Nothing selected
+    cpu.set_mem(0xFF00, 0xFF);
+    assert_eq!(cpu.get_mem(0xFF00), 0xFF);
+}
+
+#[test]
+fn test_joypad_interrupt() {
+    let mut cpu = Cpu::new();
+    cpu.set_mem(0xFF0F, 0);
+    cpu.set_mem(0xFF00, 0x20);
+
+    // Only buttons of the selected row pull a line low
+    cpu.press_start();
+    assert!(!cpu.get_input_interrupt_bit());
+    cpu.press_up();
+    assert!(cpu.get_input_interrupt_bit());
+
+    cpu.unset_input_interrupt_bit();
+    cpu.press_select();
+    assert!(!cpu.get_input_interrupt_bit());
+
+    // Selecting a row with a button held does, for lines which
+    // weren't low already
+    cpu.set_mem(0xFF00, 0x10);
+    assert_eq!(cpu.get_mem(0xFF00), 0xD3);
+    assert!(cpu.get_input_interrupt_bit());
+
+    // STOP only ends for selected buttons
+    cpu.set_mem(0xFF00, 0x30);
+    cpu.stop();
+    cpu.press_b();
+    assert_eq!(cpu.state, CpuState::Stop);
+    cpu.set_mem(0xFF00, 0x10);
+    assert_eq!(cpu.state, CpuState::Normal);
+}
+
+/// CPU running the given code from WRAM with the VBlank interrupt
+/// pending
+fn interrupt_test_cpu(code: &[u8]) -> Cpu {
+    let mut cpu = Cpu::new();
+    for (i, &op) in code.iter().enumerate() {
+        cpu.write_mem(0xC000 + i as u16, op);
+    }
+    cpu.pc = 0xC000;
+    cpu.set_mem(0xFFFF, 0x01);
+    cpu.set_mem(0xFF0F, 0x01);
+    cpu
+}
+
+#[test]
+fn test_interrupt_flags_latched() {
+    let mut cpu = Cpu::new();
+    cpu.set_mem(0xFF0F, 0);
+    assert_eq!(cpu.get_mem(0xFF0F), 0xE0);
+
+    // Requests are recorded with IME and IE both off
+    for _ in 0..144 {
+        cpu.inc_ly();
+    }
+    cpu.press_a();
+    assert_eq!(cpu.get_mem(0xFF0F), 0xE0 | 0x11);
+
+    // Holding the button doesn't request it again
+    cpu.set_mem(0xFF0F, 0);
+    cpu.press_a();
+    assert_eq!(cpu.get_mem(0xFF0F), 0xE0);
+}
+
+#[test]
+fn test_interrupt_priority() {
+    let mut cpu = interrupt_test_cpu(&[0x00]);
+    cpu.enable_interrupts();
+    cpu.set_mem(0xFFFF, 0x1F);
+    cpu.set_mem(0xFF0F, 0x14);
+
+    assert_eq!(cpu.dispatch_opcode(), 20);
+    assert_eq!(cpu.pc, 0x50);
+    assert_eq!(cpu.get_mem(0xFF0F), 0xE0 | 0x10);
+    assert_eq!(cpu.get_mem(cpu.sp), 0x00);
+    assert_eq!(cpu.get_mem(cpu.sp + 1), 0xC0);
+
+    // IME is off inside the handler
+    cpu.set_mem(0x50, 0x00);
+    assert_eq!(cpu.dispatch_opcode(), 4);
+    assert_eq!(cpu.pc, 0x51);
+}
+
+#[test]
+fn test_halt_wakes_without_ime() {
+    let mut cpu = interrupt_test_cpu(&[0x00, 0x00]);
+    cpu.set_mem(0xFF0F, 0);
+    cpu.halt();
+    cpu.dispatch_opcode();
+    assert_eq!(cpu.state, CpuState::Halt);
+
+    cpu.set_mem(0xFF0F, 0x01);
+    cpu.dispatch_opcode();
+    assert_eq!(cpu.state, CpuState::Normal);
+    // Execution continues after HALT without servicing the interrupt
+    assert_eq!(cpu.pc, 0xC001);
+    assert_eq!(cpu.get_mem(0xFF0F), 0xE1);
+}
+
+#[test]
+fn test_ei_delay() {
+    // EI; NOP; NOP
+    let mut cpu = interrupt_test_cpu(&[0xFB, 0x00, 0x00]);
+    cpu.dispatch_opcode();
+    cpu.dispatch_opcode();
+    assert_eq!(cpu.pc, 0xC002);
+    assert_eq!(cpu.dispatch_opcode(), 20);
+    assert_eq!(cpu.pc, 0x40);
+    assert_eq!(cpu.get_mem(cpu.sp), 0x02);
+
+    // EI; DI never enables interrupts
+    let mut cpu = interrupt_test_cpu(&[0xFB, 0xF3, 0x00, 0x00]);
+    for _ in 0..4 {
+        cpu.dispatch_opcode();
+    }
+    assert_eq!(cpu.pc, 0xC004);
+}
+
+#[test]
+fn test_halt_bug() {
+    // HALT; INC A; NOP
+    let mut cpu = interrupt_test_cpu(&[0x76, 0x3C, 0x00]);
+    cpu.a = 0;
+    cpu.dispatch_opcode();
+    assert_eq!(cpu.state, CpuState::Normal);
+    cpu.dispatch_opcode();
+    cpu.dispatch_opcode();
+    assert_eq!(cpu.a, 2);
+    assert_eq!(cpu.pc, 0xC002);
+
+    // LD A,n reads its own opcode as the operand
+    let mut cpu = interrupt_test_cpu(&[0x76, 0x3E, 0x12]);
+    cpu.dispatch_opcode();
+    cpu.dispatch_opcode();
+    assert_eq!(cpu.a, 0x3E);
+    assert_eq!(cpu.pc, 0xC002);
+
+    // After EI the interrupt returns to the HALT
+    let mut cpu = interrupt_test_cpu(&[0xFB, 0x76, 0x00]);
+    cpu.dispatch_opcode();
+    cpu.dispatch_opcode();
+    assert_eq!(cpu.dispatch_opcode(), 20);
+    assert_eq!(cpu.pc, 0x40);
+    assert_eq!(cpu.get_mem(cpu.sp), 0x01);
+}
+
 
 #[allow(dead_code)]
 #[test]
@@ -391,3 +563,1805 @@ fn test_daa() {
     assert_eq!(cpu.f & CL, CL);
 }
 
+
+/// Builds a ROM image where the first byte of each bank is its number
+fn make_banked_rom(cart_type: u8, banks: usize, ram_size_code: u8) -> Vec<u8> {
+    let mut rom = vec![0u8; banks * 0x4000];
+    for bank in 0..banks {
+        rom[bank * 0x4000] = bank as u8;
+        rom[bank * 0x4000 + 1] = (bank >> 8) as u8;
+    }
+    rom[0x147] = cart_type;
+    rom[0x149] = ram_size_code;
+    rom
+}
+
+fn load_cartridge(rom: Vec<u8>) -> Cpu {
+    let mut cpu = Cpu::new();
+    cpu.cartridge = cartridge::Cartridge::new(rom);
+    cpu
+}
+
+#[test]
+fn test_cartridge_header() {
+    use cpu::header::*;
+
+    let mut rom = make_banked_rom(0x13, 4, 3);
+    assert_eq!(header_checksum(&[0; HEADER_END][..]), 0xE7);
+    rom[0x104..0x134].copy_from_slice(&NINTENDO_LOGO[..]);
+    rom[0x134..0x13F].copy_from_slice(b"RUSTY BOY 1");
+    rom[0x13F..0x143].copy_from_slice(b"ABCE");
+    rom[0x143] = 0x80;
+    rom[0x144..0x146].copy_from_slice(b"01");
+    rom[0x146] = 0x03;
+    rom[0x148] = 0x01;
+    rom[0x14A] = 0x01;
+    rom[0x14B] = 0x33;
+    rom[0x14C] = 2;
+    rom[0x14D] = header_checksum(&rom[..]);
+    let sum = global_checksum(&rom[..]);
+    rom[0x14E] = (sum >> 8) as u8;
+    rom[0x14F] = sum as u8;
+
+    let header = CartridgeHeader::parse(&rom[..]).unwrap();
+    assert_eq!(header.title, "RUSTY BOY 1");
+    assert_eq!(header.manufacturer_code, Some("ABCE".to_string()));
+    assert!(header.cgb_supported() && !header.cgb_only());
+    assert!(header.sgb_supported());
+    assert_eq!(header.cartridge_type(), Some(CartridgeType::RomMBC3RamBatt));
+    assert_eq!(header.licensee, Licensee::New("01".to_string()));
+    assert_eq!(header.rom_size(), Some(0x10000));
+    assert_eq!(header.ram_size(), Some(0x8000));
+    assert!(!header.japanese);
+    assert_eq!(header.version, 2);
+    assert_eq!(header.warnings(), Vec::<String>::new());
+
+    // A DMG title can use all 16 bytes
+    rom[0x143] = b'!';
+    let header = CartridgeHeader::parse(&rom[..]).unwrap();
+    assert_eq!(header.title, "RUSTY BOY 1ABCE!");
+    assert_eq!(header.manufacturer_code, None);
+
+    rom[0x14D] ^= 1;
+    rom[0x104] = 0;
+    rom.truncate(0x4000);
+    assert_eq!(CartridgeHeader::parse(&rom[..]).unwrap().warnings().len(), 4);
+    assert_eq!(CartridgeHeader::parse(&rom[..0x14F]), Err(HeaderError::TooShort(0x14F)));
+}
+
+#[test]
+fn test_rom_only_is_read_only() {
+    let mut cpu = load_cartridge(make_banked_rom(0x00, 2, 0));
+
+    cpu.set_mem(0x4000, 0x42);
+    assert_eq!(cpu.get_mem(0x4000), 1);
+    assert_eq!(cpu.peek(0x4000), 1);
+}
+
+#[test]
+fn test_mbc1_rom_banking() {
+    let mut cpu = load_cartridge(make_banked_rom(0x01, 128, 0));
+
+    assert_eq!(cpu.get_mem(0x4000), 1);
+    cpu.set_mem(0x2000, 0x05);
+    assert_eq!(cpu.get_mem(0x4000), 5);
+    assert_eq!(cpu.peek(0x4000), 5);
+
+    // Bank 0 is translated to bank 1
+    cpu.set_mem(0x2000, 0x00);
+    assert_eq!(cpu.get_mem(0x4000), 1);
+
+    // Upper bits select banks above 0x1F
+    cpu.set_mem(0x2000, 0x02);
+    cpu.set_mem(0x4000, 0x01);
+    assert_eq!(cpu.get_mem(0x4000), 0x22);
+    assert_eq!(cpu.get_mem(0x0000), 0);
+
+    // ...and bank 0 area in advanced mode
+    cpu.set_mem(0x6000, 0x01);
+    assert_eq!(cpu.get_mem(0x0000), 0x20);
+    assert_eq!(cpu.peek(0x0000), 0x20);
+}
+
+#[test]
+fn test_mbc1_ram_banking() {
+    let mut cpu = load_cartridge(make_banked_rom(0x03, 4, 3));
+
+    // RAM is disabled until 0x0A is written to 0x0000-0x1FFF
+    cpu.set_mem(0xA000, 0x12);
+    assert_eq!(cpu.get_mem(0xA000), 0xFF);
+
+    cpu.set_mem(0x0000, 0x0A);
+    cpu.set_mem(0xA000, 0x12);
+    assert_eq!(cpu.get_mem(0xA000), 0x12);
+
+    cpu.set_mem(0x6000, 0x01);
+    cpu.set_mem(0x4000, 0x02);
+    assert_eq!(cpu.get_mem(0xA000), 0x00);
+    cpu.set_mem(0xA000, 0x34);
+
+    cpu.set_mem(0x4000, 0x00);
+    assert_eq!(cpu.get_mem(0xA000), 0x12);
+    assert_eq!(cpu.peek(0xA000), 0x12);
+    cpu.set_mem(0x4000, 0x02);
+    assert_eq!(cpu.peek(0xA000), 0x34);
+}
+
+#[test]
+fn test_mbc2_registers_and_ram() {
+    let mut cpu = load_cartridge(make_banked_rom(0x06, 16, 0));
+
+    // Bit 8 set selects the ROM bank register
+    cpu.set_mem(0x2100, 0x03);
+    assert_eq!(cpu.get_mem(0x4000), 3);
+
+    cpu.set_mem(0x0000, 0x0A);
+    cpu.set_mem(0xA001, 0xAB);
+    assert_eq!(cpu.get_mem(0xA001), 0xFB);
+    // RAM is echoed every 512 bytes
+    assert_eq!(cpu.get_mem(0xA201), 0xFB);
+}
+
+#[test]
+fn test_mbc3_rom_banking() {
+    let mut cpu = load_cartridge(make_banked_rom(0x13, 128, 3));
+
+    cpu.set_mem(0x2000, 0x7F);
+    assert_eq!(cpu.get_mem(0x4000), 0x7F);
+    cpu.set_mem(0x2000, 0x00);
+    assert_eq!(cpu.get_mem(0x4000), 1);
+}
+
+#[test]
+fn test_mbc5_rom_banking() {
+    let mut cpu = load_cartridge(make_banked_rom(0x19, 512, 0));
+
+    // Bank 0 can be mapped into 0x4000-0x7FFF on MBC5
+    cpu.set_mem(0x2000, 0x00);
+    assert_eq!(cpu.get_mem(0x4000), 0);
+
+    cpu.set_mem(0x2000, 0x2A);
+    cpu.set_mem(0x3000, 0x01);
+    assert_eq!(cpu.get_mem(0x4000), 0x2A);
+    assert_eq!(cpu.get_mem(0x4001), 0x01);
+}
+
+#[test]
+fn test_battery_ram_save_file() {
+    use std::env;
+    use std::fs;
+    use std::io::Read;
+
+    let path = env::temp_dir().join("rusty_boy_battery_test.sav");
+    let _ = fs::remove_file(&path);
+
+    let mut cpu = load_cartridge(make_banked_rom(0x1B, 4, 3));
+    cpu.cartridge.load_save_file(&path).unwrap();
+    cpu.set_mem(0x0000, 0x0A);
+    cpu.set_mem(0x4000, 0x03);
+    cpu.set_mem(0xBFFF, 0x5A);
+    cpu.cartridge.flush_save_file().unwrap();
+
+    // The file is a raw dump of all RAM banks
+    let mut data = vec![];
+    fs::File::open(&path).unwrap().read_to_end(&mut data).unwrap();
+    assert_eq!(data.len(), 0x8000);
+    assert_eq!(data[0x7FFF], 0x5A);
+
+    let mut cpu = load_cartridge(make_banked_rom(0x1B, 4, 3));
+    cpu.cartridge.load_save_file(&path).unwrap();
+    cpu.set_mem(0x0000, 0x0A);
+    cpu.set_mem(0x4000, 0x03);
+    assert_eq!(cpu.get_mem(0xBFFF), 0x5A);
+    assert_eq!(cpu.peek(0xBFFF), 0x5A);
+
+    fs::remove_file(&path).unwrap();
+}
+
+thread_local!(static FAKE_TIME: ::std::cell::Cell<u64> = ::std::cell::Cell::new(1000));
+
+fn fake_clock() -> u64 {
+    FAKE_TIME.with(|t| t.get())
+}
+
+fn advance_fake_clock(secs: u64) {
+    FAKE_TIME.with(|t| t.set(t.get() + secs));
+}
+
+fn load_rtc_cartridge() -> Cpu {
+    let mut cpu = Cpu::new();
+    cpu.cartridge = cartridge::Cartridge::with_clock(make_banked_rom(0x10, 4, 3), fake_clock);
+    cpu.set_mem(0x0000, 0x0A);
+    cpu
+}
+
+fn latch_rtc(cpu: &mut Cpu) {
+    cpu.set_mem(0x6000, 0x00);
+    cpu.set_mem(0x6000, 0x01);
+}
+
+fn read_rtc(cpu: &mut Cpu, register: u8) -> u8 {
+    cpu.set_mem(0x4000, register);
+    assert_eq!(cpu.get_mem(0xA000), cpu.peek(0xA000));
+    cpu.get_mem(0xA000)
+}
+
+#[test]
+fn test_mbc3_rtc_latch() {
+    let mut cpu = load_rtc_cartridge();
+
+    advance_fake_clock(3 * 3600 + 2 * 60 + 1);
+    // Registers only change when latched
+    assert_eq!(read_rtc(&mut cpu, rtc::RTC_SECONDS), 0);
+    latch_rtc(&mut cpu);
+    assert_eq!(read_rtc(&mut cpu, rtc::RTC_SECONDS), 1);
+    assert_eq!(read_rtc(&mut cpu, rtc::RTC_MINUTES), 2);
+    assert_eq!(read_rtc(&mut cpu, rtc::RTC_HOURS), 3);
+
+    advance_fake_clock(10);
+    assert_eq!(read_rtc(&mut cpu, rtc::RTC_SECONDS), 1);
+    // Writing 0x01 without a preceding 0x00 does not latch
+    cpu.set_mem(0x6000, 0x01);
+    assert_eq!(read_rtc(&mut cpu, rtc::RTC_SECONDS), 1);
+    latch_rtc(&mut cpu);
+    assert_eq!(read_rtc(&mut cpu, rtc::RTC_SECONDS), 11);
+
+    // RAM banks are still accessible
+    cpu.set_mem(0x4000, 0x01);
+    cpu.set_mem(0xA000, 0x77);
+    assert_eq!(cpu.get_mem(0xA000), 0x77);
+}
+
+#[test]
+fn test_mbc3_rtc_halt_and_day_carry() {
+    let mut cpu = load_rtc_cartridge();
+
+    // Halt the clock and set it to the last day
+    cpu.set_mem(0x4000, rtc::RTC_DAY_HIGH);
+    cpu.set_mem(0xA000, 0x41);
+    cpu.set_mem(0x4000, rtc::RTC_DAY_LOW);
+    cpu.set_mem(0xA000, 0xFF);
+    cpu.set_mem(0x4000, rtc::RTC_HOURS);
+    cpu.set_mem(0xA000, 23);
+    cpu.set_mem(0x4000, rtc::RTC_MINUTES);
+    cpu.set_mem(0xA000, 59);
+    cpu.set_mem(0x4000, rtc::RTC_SECONDS);
+    cpu.set_mem(0xA000, 59);
+
+    advance_fake_clock(100);
+    latch_rtc(&mut cpu);
+    assert_eq!(read_rtc(&mut cpu, rtc::RTC_SECONDS), 59);
+    assert_eq!(read_rtc(&mut cpu, rtc::RTC_DAY_HIGH), 0x41);
+
+    // Resume, a single second overflows the day counter
+    cpu.set_mem(0x4000, rtc::RTC_DAY_HIGH);
+    cpu.set_mem(0xA000, 0x01);
+    advance_fake_clock(1);
+    latch_rtc(&mut cpu);
+    assert_eq!(read_rtc(&mut cpu, rtc::RTC_SECONDS), 0);
+    assert_eq!(read_rtc(&mut cpu, rtc::RTC_HOURS), 0);
+    assert_eq!(read_rtc(&mut cpu, rtc::RTC_DAY_LOW), 0);
+    assert_eq!(read_rtc(&mut cpu, rtc::RTC_DAY_HIGH), 0x80);
+}
+
+#[test]
+fn test_mbc3_rtc_save_file() {
+    use std::env;
+    use std::fs;
+
+    let path = env::temp_dir().join("rusty_boy_rtc_test.sav");
+    let _ = fs::remove_file(&path);
+
+    let mut cpu = load_rtc_cartridge();
+    cpu.cartridge.load_save_file(&path).unwrap();
+    advance_fake_clock(42);
+    cpu.set_mem(0x4000, rtc::RTC_MINUTES);
+    cpu.set_mem(0xA000, 5);
+    cpu.cartridge.flush_save_file().unwrap();
+    assert_eq!(fs::metadata(&path).unwrap().len(),
+               (0x8000 + rtc::RTC_SAVE_SIZE) as u64);
+
+    // Time passes while the emulator is not running
+    advance_fake_clock(3600);
+    let mut cpu = load_rtc_cartridge();
+    cpu.cartridge.load_save_file(&path).unwrap();
+    latch_rtc(&mut cpu);
+    assert_eq!(read_rtc(&mut cpu, rtc::RTC_MINUTES), 5);
+    assert_eq!(read_rtc(&mut cpu, rtc::RTC_HOURS), 1);
+
+    fs::remove_file(&path).unwrap();
+}
+
+#[test]
+fn test_ppu_mode_timing() {
+    use cpu::ppu::*;
+
+    let mut cpu = Cpu::new();
+    assert_eq!(cpu.io[STAT_ADDR] & 3, PpuMode::OamScan as u8);
+
+    cpu.step_ppu(OAM_SCAN_CYCLES);
+    assert_eq!(cpu.io[STAT_ADDR] & 3, PpuMode::PixelTransfer as u8);
+    cpu.step_ppu(PIXEL_TRANSFER_CYCLES);
+    assert_eq!(cpu.io[STAT_ADDR] & 3, PpuMode::HBlank as u8);
+    assert_eq!(cpu.ly(), 0);
+    cpu.step_ppu(SCANLINE_CYCLES - OAM_SCAN_CYCLES - PIXEL_TRANSFER_CYCLES);
+    assert_eq!(cpu.io[STAT_ADDR] & 3, PpuMode::OamScan as u8);
+    assert_eq!(cpu.ly(), 1);
+
+    // Run to the start of VBlank
+    for _ in 1..SCREEN_HEIGHT {
+        cpu.step_ppu(SCANLINE_CYCLES);
+    }
+    assert_eq!(cpu.ly() as usize, SCREEN_HEIGHT);
+    assert_eq!(cpu.io[STAT_ADDR] & 3, PpuMode::VBlank as u8);
+    assert!(cpu.get_vblank_interrupt_bit());
+    assert!(cpu.take_frame_ready());
+    assert!(!cpu.take_frame_ready());
+
+    // ...and through it to the next frame
+    for _ in SCREEN_HEIGHT..(LINES_PER_FRAME as usize) {
+        cpu.step_ppu(SCANLINE_CYCLES);
+    }
+    assert_eq!(cpu.ly(), 0);
+    assert_eq!(cpu.io[STAT_ADDR] & 3, PpuMode::OamScan as u8);
+}
+
+#[test]
+fn test_ppu_stat_interrupts() {
+    use cpu::ppu::*;
+
+    let mut cpu = Cpu::new();
+    cpu.set_mem(0xFF45, 2);
+    cpu.set_mem(STAT_ADDR as u16, 0x40);
+    assert!(!cpu.get_lcdc_interrupt_bit());
+
+    cpu.step_ppu(SCANLINE_CYCLES * 2);
+    assert_eq!(cpu.ly(), 2);
+    assert!(cpu.get_coincidence_flag());
+    assert!(cpu.get_lcdc_interrupt_bit());
+
+    // Only raised on the rising edge
+    cpu.unset_lcdc_interrupt_bit();
+    cpu.step_ppu(100);
+    assert!(!cpu.get_lcdc_interrupt_bit());
+
+    cpu.set_mem(STAT_ADDR as u16, 0x08);
+    cpu.step_ppu(SCANLINE_CYCLES);
+    assert_eq!(cpu.io[STAT_ADDR] & 0x78, 0x08);
+    assert!(cpu.get_lcdc_interrupt_bit());
+}
+
+#[test]
+fn test_ppu_lcd_off() {
+    use cpu::ppu::*;
+
+    let mut cpu = Cpu::new();
+    cpu.step_ppu(SCANLINE_CYCLES * 10 + OAM_SCAN_CYCLES);
+    cpu.set_mem(0xFF40, 0x11);
+    cpu.step_ppu(4);
+    assert_eq!(cpu.ly(), 0);
+    assert_eq!(cpu.io[STAT_ADDR] & 3, PpuMode::HBlank as u8);
+
+    // VRAM is accessible while the LCD is off
+    cpu.set_mem(0x8000, 0x12);
+    assert_eq!(cpu.peek(0x8000), 0x12);
+}
+
+#[test]
+fn test_ppu_renders_background_and_sprites() {
+    use cpu::ppu::*;
+
+    let mut cpu = Cpu::new();
+    cpu.set_mem(0xFF40, 0x00);
+    cpu.step_ppu(4);
+    // Tile 1 is solid color 3, tile 2 solid color 1
+    for i in 0..8 {
+        cpu.set_mem(0x8010 + i * 2, 0xFF);
+        cpu.set_mem(0x8011 + i * 2, 0xFF);
+        cpu.set_mem(0x8020 + i * 2, 0xFF);
+    }
+    // Background tile (1, 0) uses tile 1
+    cpu.set_mem(0x9801, 1);
+    // A sprite at the screen's top left corner
+    cpu.set_mem(0xFE00, 16);
+    cpu.set_mem(0xFE01, 8);
+    cpu.set_mem(0xFE02, 2);
+    cpu.set_mem(0xFE03, 0);
+    cpu.set_mem(0xFF47, 0xE4);
+    cpu.set_mem(0xFF48, 0xE4);
+    // LCD, BG, sprites on, unsigned tile data
+    cpu.set_mem(0xFF40, 0x93);
+
+    cpu.step_ppu(SCANLINE_CYCLES * SCREEN_HEIGHT as u32);
+    assert!(cpu.take_frame_ready());
+    let frame = cpu.frame_buffer();
+    assert_eq!(frame[0], 1);
+    assert_eq!(frame[8], 3);
+    assert_eq!(frame[16], 0);
+    assert_eq!(frame[7 * SCREEN_WIDTH + 9], 3);
+    assert_eq!(frame[8 * SCREEN_WIDTH], 0);
+}
+
+/// Writes a sprite to OAM while the LCD is off
+fn set_object(cpu: &mut Cpu, obj: u16, y: u8, x: u8, tile: u8, flags: u8) {
+    let attr = 0xFE00 + obj * 4;
+    cpu.set_mem(attr, y);
+    cpu.set_mem(attr + 1, x);
+    cpu.set_mem(attr + 2, tile);
+    cpu.set_mem(attr + 3, flags);
+}
+
+/// A CPU with the LCD off and an 8x8 tile 1 of which only the top
+/// left pixel has color 3, the rest color 1
+fn sprite_test_cpu() -> Cpu {
+    let mut cpu = Cpu::new();
+    cpu.set_mem(0xFF40, 0x00);
+    cpu.step_ppu(4);
+    for i in 0..8 {
+        cpu.set_mem(0x8010 + i * 2, 0xFF);
+    }
+    cpu.set_mem(0x8011, 0x80);
+    cpu.set_mem(0xFF48, 0xE4);
+    cpu.set_mem(0xFF49, 0x1B);
+    cpu
+}
+
+#[test]
+fn test_ppu_object_flips_and_palettes() {
+    let mut cpu = sprite_test_cpu();
+    cpu.set_mem(0xFF40, 0x02);
+    set_object(&mut cpu, 0, 16, 8, 1, 0x00);
+    set_object(&mut cpu, 1, 16, 20, 1, 0x20);
+    set_object(&mut cpu, 2, 16, 40, 1, 0x40);
+    set_object(&mut cpu, 3, 16, 60, 1, 0x10);
+
+    let line = cpu.object_line(0);
+    assert_eq!(line[0].unwrap().color, 3);
+    assert_eq!(line[1].unwrap().color, 1);
+    // X flip moves the pixel to the right edge
+    assert_eq!(line[12].unwrap().color, 1);
+    assert_eq!(line[19].unwrap().color, 3);
+    // Y flip moves it to the bottom row
+    assert_eq!(line[32].unwrap().color, 1);
+    assert_eq!(cpu.object_line(7)[32].unwrap().color, 3);
+    // OBP1 inverts the shades
+    assert_eq!(line[52].unwrap().shade, 0);
+    assert_eq!(line[53].unwrap().shade, 2);
+}
+
+#[test]
+fn test_ppu_object_tall_sprites() {
+    let mut cpu = sprite_test_cpu();
+    cpu.set_mem(0xFF40, 0x06);
+    // Tile 0 is blank, the odd bit of the index is ignored
+    set_object(&mut cpu, 0, 16, 8, 1, 0x00);
+    set_object(&mut cpu, 1, 16, 16, 1, 0x40);
+
+    assert_eq!(cpu.object_line(0)[0], None);
+    assert_eq!(cpu.object_line(8)[0].unwrap().color, 3);
+    // Flipping swaps the tiles too
+    assert_eq!(cpu.object_line(7)[8].unwrap().color, 3);
+    assert_eq!(cpu.object_line(8)[8], None);
+}
+
+#[test]
+fn test_ppu_object_priority_and_limit() {
+    let mut cpu = sprite_test_cpu();
+    cpu.set_mem(0xFF40, 0x02);
+    // Overlapping sprites, the one with the smaller X wins...
+    set_object(&mut cpu, 0, 16, 12, 1, 0x10);
+    set_object(&mut cpu, 1, 16, 8, 1, 0x00);
+    let line = cpu.object_line(0);
+    assert_eq!(line[4].unwrap().shade, 1);
+    assert_eq!(line[4].unwrap().color, 1);
+    assert_eq!(cpu.objects_on_line(0), vec![1, 0]);
+
+    // ...or the earlier one in OAM
+    set_object(&mut cpu, 1, 16, 12, 1, 0x00);
+    assert_eq!(cpu.object_line(0)[4].unwrap().shade, 0);
+
+    // Only 10 sprites per line, even off screen ones count
+    for obj in 0..12 {
+        set_object(&mut cpu, obj, 16, if obj < 5 { 0 } else { 8 * obj as u8 }, 1, 0);
+    }
+    let objects = cpu.objects_on_line(0);
+    assert_eq!(objects.len(), 10);
+    assert!(!objects.contains(&10));
+    let line = cpu.object_line(0);
+    assert!(line[8 * 9 - 8].is_some());
+    assert!(line[8 * 10 - 8].is_none());
+}
+
+#[test]
+fn test_ppu_object_behind_background() {
+    use cpu::ppu::*;
+
+    let mut cpu = sprite_test_cpu();
+    // Background is tile 2 (color 2) on the left, tile 0 elsewhere
+    for i in 0..8 {
+        cpu.set_mem(0x8021 + i * 2, 0xFF);
+    }
+    cpu.set_mem(0x9800, 2);
+    cpu.set_mem(0xFF47, 0xE4);
+    set_object(&mut cpu, 0, 16, 12, 1, 0x80);
+    cpu.set_mem(0xFF40, 0x93);
+
+    cpu.step_ppu(SCANLINE_CYCLES);
+    let frame = cpu.frame_buffer();
+    // Hidden behind BG color 2, visible over BG color 0
+    assert_eq!(frame[4], 2);
+    assert_eq!(frame[7], 2);
+    assert_eq!(frame[8], 1);
+    assert_eq!(frame[12], 0);
+}
+
+/// A CPU with the LCD off, a blank background and a window map
+/// (0x9C00) whose tile rows alternate between tile 1 (color 3) and
+/// tile 2 (color 1)
+fn window_test_cpu() -> Cpu {
+    let mut cpu = Cpu::new();
+    cpu.set_mem(0xFF40, 0x00);
+    cpu.step_ppu(4);
+    for i in 0..16 {
+        cpu.set_mem(0x8010 + i, 0xFF);
+    }
+    for i in 0..8 {
+        cpu.set_mem(0x8020 + i * 2, 0xFF);
+    }
+    for i in 0..32 * 32 {
+        cpu.set_mem(0x9C00 + i, if (i / 32) % 2 == 0 { 1 } else { 2 });
+    }
+    cpu.set_mem(0xFF47, 0xE4);
+    cpu
+}
+
+#[test]
+fn test_ppu_window_position() {
+    use cpu::ppu::*;
+
+    let mut cpu = window_test_cpu();
+    cpu.set_mem(0xFF4A, 2);
+    cpu.set_mem(0xFF4B, 7 + 10);
+    // LCD, window with map 0x9C00, BG with map 0x9800
+    cpu.set_mem(0xFF40, 0xF1);
+
+    cpu.step_ppu(SCANLINE_CYCLES * 3);
+    let frame = cpu.frame_buffer();
+    assert_eq!(frame[SCREEN_WIDTH + 10], 0);
+    assert_eq!(frame[2 * SCREEN_WIDTH + 9], 0);
+    assert_eq!(frame[2 * SCREEN_WIDTH + 10], 3);
+    assert_eq!(frame[2 * SCREEN_WIDTH + SCREEN_WIDTH - 1], 3);
+
+    // Off screen to the right
+    cpu.set_mem(0xFF4B, 167);
+    assert!(!cpu.window_visible(10));
+}
+
+#[test]
+fn test_ppu_window_line_counter() {
+    use cpu::ppu::*;
+
+    let mut cpu = window_test_cpu();
+    cpu.set_mem(0xFF4B, 7);
+    cpu.set_mem(0xFF40, 0xF1);
+
+    // Window lines 0-5 on screen lines 0-5
+    cpu.step_ppu(SCANLINE_CYCLES * 6);
+    // Hidden for screen lines 6-15
+    cpu.set_mem(0xFF40, 0xD1);
+    cpu.step_ppu(SCANLINE_CYCLES * 10);
+    cpu.set_mem(0xFF40, 0xF1);
+    cpu.step_ppu(SCANLINE_CYCLES * 4);
+
+    // The window continues at its line 6 instead of 16
+    let frame = cpu.frame_buffer();
+    assert_eq!(frame[5 * SCREEN_WIDTH], 3);
+    assert_eq!(frame[6 * SCREEN_WIDTH], 0);
+    assert_eq!(frame[16 * SCREEN_WIDTH], 3);
+    assert_eq!(frame[17 * SCREEN_WIDTH], 3);
+    assert_eq!(frame[18 * SCREEN_WIDTH], 1);
+
+    // ...and starts over on the next frame
+    cpu.step_ppu(SCANLINE_CYCLES * (LINES_PER_FRAME as u32 - 20 + 16));
+    assert_eq!(cpu.frame_buffer()[15 * SCREEN_WIDTH], 1);
+}
+
+#[test]
+fn test_timer_div() {
+    let mut cpu = Cpu::new();
+    cpu.set_mem(0xFF04, 0x12);
+    assert_eq!(cpu.get_mem(0xFF04), 0);
+
+    cpu.step_timer(252);
+    assert_eq!(cpu.get_mem(0xFF04), 0);
+    cpu.step_timer(4);
+    assert_eq!(cpu.get_mem(0xFF04), 1);
+    cpu.step_timer(256 * 255);
+    assert_eq!(cpu.get_mem(0xFF04), 0);
+}
+
+#[test]
+fn test_timer_frequencies() {
+    for &(tac, period) in [(0x04u8, 1024u32), (0x05, 16), (0x06, 64), (0x07, 256)].iter() {
+        let mut cpu = Cpu::new();
+        cpu.set_mem(0xFF04, 0);
+        cpu.set_mem(0xFF07, tac);
+        assert_eq!(cpu.timer_frequency(), 4194304 / period);
+
+        cpu.step_timer(period - 4);
+        assert_eq!(cpu.get_mem(0xFF05), 0);
+        cpu.step_timer(4);
+        assert_eq!(cpu.get_mem(0xFF05), 1);
+        cpu.step_timer(period * 9);
+        assert_eq!(cpu.get_mem(0xFF05), 10);
+    }
+
+    // Disabled
+    let mut cpu = Cpu::new();
+    cpu.set_mem(0xFF07, 0x01);
+    cpu.step_timer(1024);
+    assert_eq!(cpu.get_mem(0xFF05), 0);
+    assert_eq!(cpu.get_mem(0xFF07), 0xF9);
+}
+
+#[test]
+fn test_timer_overflow_reload_delay() {
+    let mut cpu = Cpu::new();
+    cpu.set_mem(0xFF04, 0);
+    cpu.set_mem(0xFF07, 0x05);
+    cpu.set_mem(0xFF06, 0x80);
+    cpu.set_mem(0xFF05, 0xFF);
+
+    cpu.step_timer(16);
+    // TIMA is 0 for a machine cycle before TMA is loaded
+    assert_eq!(cpu.get_mem(0xFF05), 0);
+    assert!(!cpu.get_timer_interrupt_bit());
+    cpu.step_timer(4);
+    assert_eq!(cpu.get_mem(0xFF05), 0x80);
+    assert!(cpu.get_timer_interrupt_bit());
+
+    // Writing TIMA in that cycle cancels the reload
+    cpu.unset_timer_interrupt_bit();
+    cpu.set_mem(0xFF05, 0xFF);
+    cpu.step_timer(12);
+    assert_eq!(cpu.get_mem(0xFF05), 0);
+    cpu.set_mem(0xFF05, 0x42);
+    cpu.step_timer(4);
+    assert_eq!(cpu.get_mem(0xFF05), 0x42);
+    assert!(!cpu.get_timer_interrupt_bit());
+}
+
+#[test]
+fn test_timer_div_reset_falling_edge() {
+    let mut cpu = Cpu::new();
+    cpu.set_mem(0xFF04, 0);
+    cpu.set_mem(0xFF07, 0x05);
+    // Bit 3 of the divider is set after 8 cycles
+    cpu.step_timer(8);
+    assert_eq!(cpu.get_mem(0xFF05), 0);
+    // Resetting DIV makes it fall, incrementing TIMA early
+    cpu.set_mem(0xFF04, 0);
+    assert_eq!(cpu.get_mem(0xFF05), 1);
+
+    // So does disabling the timer
+    cpu.step_timer(8);
+    cpu.set_mem(0xFF07, 0x01);
+    assert_eq!(cpu.get_mem(0xFF05), 2);
+}
+
+#[test]
+fn test_timer_runs_with_instructions() {
+    let mut cpu = Cpu::new();
+    cpu.set_mem(0xFF04, 0);
+    cpu.set_mem(0xFF07, 0x05);
+    cpu.pc = 0xC000;
+    // NOPs take 4 cycles each
+    for _ in 0..8 {
+        cpu.dispatch_opcode();
+    }
+    assert_eq!(cpu.get_mem(0xFF05), 2);
+}
+
+#[test]
+fn test_per_access_timing() {
+    for &(mode, tima) in [(TimingMode::PerInstruction, 0u8), (TimingMode::PerAccess, 1)].iter() {
+        let mut cpu = Cpu::new();
+        cpu.timing_mode = mode;
+        cpu.set_mem(0xFF04, 0);
+        cpu.set_mem(0xFF07, 0x05);
+        cpu.pc = 0xC000;
+        // LD A,(0xFF05) reads in its fourth M-cycle, when TIMA has
+        // counted once
+        for (i, &value) in [0xFA, 0x05, 0xFF].iter().enumerate() {
+            cpu.set_mem(0xC000 + i as MemAddr, value);
+        }
+        assert_eq!(cpu.dispatch_opcode(), 16);
+        assert_eq!(cpu.a, tima);
+        assert_eq!(cpu.cycles, 16);
+        assert_eq!(cpu.get_mem(0xFF05), 1);
+    }
+}
+
+#[test]
+fn test_per_access_timing_totals() {
+    // CALL 0xC010, INC (HL), PUSH BC, POP DE, RET and an interrupt
+    let program: &[(MemAddr, &[byte])] = &[(0xC000, &[0xCD, 0x10, 0xC0]),
+                                           (0xC010, &[0x34, 0xC5, 0xD1, 0xC9])];
+    let mut totals = vec![];
+    for &mode in [TimingMode::PerInstruction, TimingMode::PerAccess].iter() {
+        let mut cpu = Cpu::new();
+        cpu.timing_mode = mode;
+        for &(addr, code) in program {
+            for (i, &value) in code.iter().enumerate() {
+                cpu.set_mem(addr + i as MemAddr, value);
+            }
+        }
+        cpu.pc = 0xC000;
+        cpu.sp = 0xD000;
+        cpu.set_hl(0xC100);
+        let mut times = vec![];
+        for _ in 0..5 {
+            times.push(cpu.dispatch_opcode());
+        }
+        cpu.enable_interrupts();
+        cpu.set_mem(0xFFFF, 0x04);
+        cpu.set_timer_interrupt_bit();
+        times.push(cpu.dispatch_opcode());
+
+        assert_eq!(cpu.pc, 0x50);
+        assert_eq!(cpu.get_mem(0xC100), 1);
+        totals.push((times, cpu.cycles));
+    }
+    assert_eq!(totals[0], totals[1]);
+    assert_eq!(totals[1].0, vec![24, 12, 16, 12, 16, 20]);
+}
+
+#[test]
+fn test_oam_dma() {
+    let mut cpu = Cpu::new();
+    for i in 0..0xA0 {
+        cpu.set_mem(0xC100 + i, i as byte ^ 0x55);
+    }
+    cpu.set_mem(0xFF80, 0x42);
+    cpu.set_mem(0xFF46, 0xC1);
+    assert_eq!(cpu.get_mem(0xFF46), 0xC1);
+
+    // The bus is free while the transfer is set up
+    assert_eq!(cpu.dma.progress(), None);
+    assert_eq!(cpu.get_mem(0xC100), 0x55);
+
+    // Then only HRAM and the I/O registers can be used
+    cpu.step_dma(4);
+    assert_eq!(cpu.dma.progress(), Some(0));
+    assert_eq!(cpu.get_mem(0xC100), 0xFF);
+    cpu.step_dma(4);
+    assert_eq!(cpu.dma.progress(), Some(1));
+    assert_eq!(cpu.get_mem(0xFE00), 0xFF);
+    assert_eq!(cpu.oam.read(0xFE00), 0x55);
+    assert_eq!(cpu.get_mem(0xC100), 0xFF);
+    cpu.set_mem(0xC000, 0x12);
+    assert_eq!(cpu.get_mem(0xFF80), 0x42);
+    assert_eq!(cpu.get_mem(0xFF46), 0xC1);
+
+    // One byte per M-cycle
+    cpu.step_dma(4 * 158);
+    assert_eq!(cpu.dma.progress(), Some(159));
+    cpu.step_dma(4);
+    assert_eq!(cpu.dma.progress(), None);
+    for i in 0..0xA0 {
+        assert_eq!(cpu.get_mem(0xFE00 + i), i as byte ^ 0x55);
+    }
+    assert_eq!(cpu.get_mem(0xC000), 0);
+}
+
+#[test]
+fn test_oam_dma_blocks_fetches() {
+    let mut cpu = Cpu::new();
+    // NOPs in both work RAM and HRAM
+    cpu.set_mem(0xC000, 0x00);
+    cpu.set_mem(0xFF80, 0x00);
+    cpu.sp = 0xFFFE;
+
+    cpu.set_mem(0xFF46, 0xC0);
+    cpu.pc = 0xFF80;
+    cpu.dispatch_opcode();
+    cpu.dispatch_opcode();
+    assert_eq!(cpu.pc, 0xFF82);
+    assert!(cpu.dma.progress().is_some());
+
+    // Outside HRAM the CPU reads 0xFF, RST 0x38
+    cpu.pc = 0xC000;
+    cpu.dispatch_opcode();
+    assert_eq!(cpu.pc, 0x38);
+}
+
+/// Counts the samples of one stereo channel (0 left, 1 right) that
+/// are louder than silence
+fn loud_samples(samples: &[f32], side: usize) -> usize {
+    samples.chunks(2).filter(|s| s[side].abs() > 0.01).count()
+}
+
+#[test]
+fn test_apu_registers() {
+    use cpu::apu::*;
+
+    let mut cpu = Cpu::new();
+    // Write only bits read back as 1
+    cpu.set_mem(0xFF13, 0x12);
+    assert_eq!(cpu.get_mem(0xFF13), 0xFF);
+    cpu.set_mem(0xFF11, 0x80);
+    assert_eq!(cpu.get_mem(0xFF11), 0xBF);
+    assert_eq!(cpu.get_mem(0xFF15), 0xFF);
+
+    // Powering off clears everything but wave RAM
+    cpu.set_mem(0xFF30, 0x12);
+    cpu.set_mem(NR52_ADDR as u16, 0x00);
+    assert_eq!(cpu.get_mem(0xFF24), 0x00);
+    assert_eq!(cpu.get_mem(NR52_ADDR as u16), 0x70);
+    assert_eq!(cpu.get_mem(0xFF30), 0x12);
+    // ...and ignores writes
+    cpu.set_mem(0xFF24, 0x77);
+    assert_eq!(cpu.get_mem(0xFF24), 0x00);
+}
+
+#[test]
+fn test_apu_square_channel() {
+    use cpu::apu::*;
+
+    let mut cpu = Cpu::new();
+    cpu.set_mem(NR52_ADDR as u16, 0x00);
+    cpu.set_mem(NR52_ADDR as u16, 0x80);
+    cpu.set_mem(0xFF24, 0x77);
+    // Channel 2 on the left only
+    cpu.set_mem(0xFF25, 0x20);
+    cpu.set_mem(0xFF16, 0x80 | 0x3E);
+    cpu.set_mem(0xFF17, 0xF0);
+    cpu.set_mem(0xFF18, 0x00);
+    cpu.set_mem(0xFF19, 0xC7);
+    assert_eq!(cpu.get_mem(NR52_ADDR as u16), 0xF2);
+    cpu.apu.take_samples();
+
+    // Length 2 runs out after two 256Hz clocks
+    cpu.step_apu(8192);
+    assert!(cpu.apu.channel_on(1));
+    let samples = cpu.apu.take_samples();
+    assert!(loud_samples(&samples[..], 0) > 0);
+    assert_eq!(loud_samples(&samples[..], 1), 0);
+
+    cpu.step_apu(8192 * 3);
+    assert!(!cpu.apu.channel_on(1));
+    assert_eq!(cpu.get_mem(NR52_ADDR as u16), 0xF0);
+}
+
+#[test]
+fn test_apu_sample_rate() {
+    use cpu::apu::*;
+
+    let mut cpu = Cpu::new();
+    cpu.apu.take_samples();
+    cpu.step_apu(4194304);
+    let samples = cpu.apu.take_samples();
+    assert_eq!(samples.len(), 2 * APU_SAMPLE_RATE as usize);
+}
+
+#[test]
+fn test_apu_envelope_and_sweep() {
+    let mut cpu = Cpu::new();
+    cpu.set_mem(0xFF25, 0x11);
+    // Channel 1 sweeping up quickly overflows and turns off
+    cpu.set_mem(0xFF10, 0x11);
+    cpu.set_mem(0xFF12, 0xF0);
+    cpu.set_mem(0xFF13, 0x00);
+    cpu.set_mem(0xFF14, 0x84);
+    assert!(cpu.apu.channel_on(0));
+    cpu.step_apu(8192 * 8);
+    assert!(!cpu.apu.channel_on(0));
+
+    // Channel 1 with a silent, rising envelope
+    cpu.set_mem(0xFF10, 0x00);
+    cpu.set_mem(0xFF12, 0x09);
+    cpu.set_mem(0xFF14, 0x87);
+    cpu.step_apu(8192);
+    cpu.apu.take_samples();
+    cpu.step_apu(8192);
+    let quiet = cpu.apu.take_samples();
+    cpu.step_apu(8192 * 8 * 10);
+    cpu.apu.take_samples();
+    cpu.step_apu(8192);
+    let loud = cpu.apu.take_samples();
+    let peak = |s: &[f32]| s.iter().fold(0.0f32, |m, v| m.max(*v));
+    assert!(peak(&loud[..]) > peak(&quiet[..]));
+}
+
+#[test]
+fn test_apu_wave_and_noise_channels() {
+    let mut cpu = Cpu::new();
+    cpu.set_mem(0xFF25, 0x44);
+    for i in 0..16 {
+        cpu.set_mem(0xFF30 + i, 0xF0);
+    }
+    assert_eq!(cpu.channel3_wave_pattern_ram()[0], 0xF0);
+    cpu.set_mem(0xFF1A, 0x80);
+    cpu.set_mem(0xFF1C, 0x20);
+    cpu.set_mem(0xFF1E, 0x87);
+    assert!(cpu.apu.channel_on(2));
+    cpu.apu.take_samples();
+    cpu.step_apu(8192);
+    assert!(loud_samples(&cpu.apu.take_samples()[..], 0) > 0);
+
+    // Muting the wave channel leaves noise on the right only
+    cpu.set_mem(0xFF1A, 0x00);
+    assert!(!cpu.apu.channel_on(2));
+    cpu.set_mem(0xFF25, 0x08);
+    cpu.set_mem(0xFF21, 0xF0);
+    cpu.set_mem(0xFF22, 0x00);
+    cpu.set_mem(0xFF23, 0x80);
+    assert!(cpu.apu.channel_on(3));
+    cpu.step_apu(8192);
+    let samples = cpu.apu.take_samples();
+    assert!(loud_samples(&samples[..], 0) == 0);
+    assert!(loud_samples(&samples[..], 1) > 0);
+}
+
+#[test]
+fn test_apu_noise_clock_shift() {
+    // Whether the left output ever moves away from its first level
+    let changes = |s: &[f32]| s.chunks(2).any(|pair| pair[0] != s[0]);
+
+    let mut cpu = Cpu::new();
+    cpu.set_mem(0xFF25, 0x80);
+    cpu.set_mem(0xFF21, 0xF0);
+    // 7 bit noise with the slowest shift still clocked
+    cpu.set_mem(0xFF22, 0xD8);
+    cpu.set_mem(0xFF23, 0x80);
+    cpu.apu.take_samples();
+    cpu.step_apu(1 << 20);
+    assert!(changes(&cpu.apu.take_samples()[..]));
+
+    // Shifts of 14 and 15 leave the LFSR as triggered
+    for &nr43 in &[0xE8, 0xF8] {
+        cpu.set_mem(0xFF22, nr43);
+        cpu.set_mem(0xFF23, 0x80);
+        cpu.apu.take_samples();
+        cpu.step_apu(1 << 21);
+        assert!(cpu.apu.channel_on(3));
+        assert!(!changes(&cpu.apu.take_samples()[..]));
+    }
+}
+
+#[test]
+fn test_flat_ram_bus() {
+    // LD A,0x42; LD (0x9000),A; INC A; LD (0xFFFF),A
+    let mut ram = Ram::flat();
+    ram.write_bytes(0, &[0x3E, 0x42, 0xEA, 0x00, 0x90, 0x3C, 0xEA, 0xFF, 0xFF]);
+    let mut cpu = Cpu::with_bus(Box::new(ram));
+    cpu.pc = 0;
+    for _ in 0..4 {
+        cpu.dispatch_opcode();
+    }
+    assert_eq!(cpu.pc, 9);
+    // No VRAM locking or interrupt registers, just memory
+    assert_eq!(cpu.get_mem(0x9000), 0x42);
+    assert_eq!(cpu.get_mem(0xFFFF), 0x43);
+    assert_eq!(cpu.vram.bank(0)[0x1000], 0);
+    assert_eq!(cpu.get_mem(0xFF04), 0);
+}
+
+use std::rc::Rc;
+use std::cell::RefCell;
+use cpu::memory::{Ram, MemoryMapped};
+
+/// Memory which records every write made to it
+#[derive(Clone)]
+struct WriteLog {
+    ram: Ram,
+    writes: Rc<RefCell<Vec<(MemAddr, byte)>>>,
+}
+
+impl MemoryMapped for WriteLog {
+    fn peek(&self, addr: MemAddr) -> byte {
+        self.ram.peek(addr)
+    }
+
+    fn write(&mut self, addr: MemAddr, value: byte) {
+        self.writes.borrow_mut().push((addr, value));
+        self.ram.write(addr, value);
+    }
+}
+
+#[test]
+fn test_instrumented_bus() {
+    // LD SP,0xD000; PUSH BC
+    let mut ram = Ram::flat();
+    ram.write_bytes(0x100, &[0x31, 0x00, 0xD0, 0xC5]);
+    let writes = Rc::new(RefCell::new(vec![]));
+    let mut cpu = Cpu::with_bus(Box::new(WriteLog { ram: ram, writes: writes.clone() }));
+    cpu.set_bc(0x1234);
+    cpu.dispatch_opcode();
+    cpu.dispatch_opcode();
+    assert_eq!(*writes.borrow(), vec![(0xCFFF, 0x12), (0xCFFE, 0x34)]);
+}
+
+/// Memory which records every read made from it
+#[derive(Clone)]
+struct ReadLog {
+    ram: Ram,
+    reads: Rc<RefCell<Vec<MemAddr>>>,
+}
+
+impl MemoryMapped for ReadLog {
+    fn peek(&self, addr: MemAddr) -> byte {
+        self.ram.peek(addr)
+    }
+
+    fn write(&mut self, addr: MemAddr, value: byte) {
+        self.ram.write(addr, value);
+    }
+
+    fn read(&mut self, addr: MemAddr) -> byte {
+        self.reads.borrow_mut().push(addr);
+        self.ram.peek(addr)
+    }
+}
+
+#[test]
+fn test_bus_instruction_reads() {
+    // NOP; LD A,0x42; LD A,(HL)
+    let mut ram = Ram::flat();
+    ram.write_bytes(0x100, &[0x00, 0x3E, 0x42, 0x7E]);
+    let reads = Rc::new(RefCell::new(vec![]));
+    let mut cpu = Cpu::with_bus(Box::new(ReadLog { ram: ram, reads: reads.clone() }));
+    cpu.set_hl(0x8000);
+    for _ in 0..3 {
+        cpu.dispatch_opcode();
+    }
+    // Only the bytes making up the instructions, and their operand
+    assert_eq!(*reads.borrow(), vec![0x100, 0x101, 0x102, 0x103, 0x8000]);
+}
+
+#[test]
+fn test_work_ram_echo() {
+    let mut cpu = Cpu::new();
+    cpu.set_mem(0xC123, 0x11);
+    assert_eq!(cpu.get_mem(0xE123), 0x11);
+    cpu.set_mem(0xE456, 0x22);
+    assert_eq!(cpu.get_mem(0xC456), 0x22);
+    assert_eq!(cpu.peek(0xC456), 0x22);
+    assert_eq!(cpu.peek(0xE456), 0x22);
+
+    // The last 512 bytes of work RAM have no echo
+    cpu.set_mem(0xDF00, 0x33);
+    assert_eq!(cpu.get_mem(0xDF00), 0x33);
+    assert_eq!(cpu.get_mem(0xFF80), 0);
+    cpu.set_mem(0xFF80, 0x44);
+    assert_eq!(cpu.get_mem(0xFF80), 0x44);
+}
+
+#[test]
+fn test_devices_hold_memory() {
+    let mut cpu = Cpu::new();
+    // LD HL,0xFF80; LD A,(HL) written straight into work RAM
+    for (i, &b) in [0x21, 0x80, 0xFF, 0x7E].iter().enumerate() {
+        cpu.wram.write(0xC000 + i as u16, b);
+    }
+    cpu.hram.write(0xFF80, 0x5A);
+    cpu.pc = 0xC000;
+    cpu.dispatch_opcode();
+    assert_eq!(cpu.access_register(CpuRegister::HL), Some(0x5A));
+    cpu.dispatch_opcode();
+    assert_eq!(cpu.a, 0x5A);
+
+    // The PPU sees sprites written to OAM by anything
+    cpu.oam.write(OAM_START as u16, 16);
+    assert_eq!(cpu.objects_on_line(0), vec![0]);
+    // ...and the unusable area after OAM holds nothing
+    cpu.write_mem(0xFEA0, 0x12);
+    assert_eq!(cpu.peek(0xFEA0), 0);
+}
+
+#[test]
+fn test_save_state_round_trip() {
+    // MBC1 with RAM, running a loop of INC A; JR -3 from WRAM
+    let mut cpu = load_cartridge(make_banked_rom(0x03, 4, 2));
+    cpu.set_mem(0x0000, 0x0A);
+    cpu.set_mem(0x2000, 2);
+    cpu.set_mem(0xA000, 0x77);
+    cpu.set_mem(0xC000, 0x3C);
+    cpu.set_mem(0xC001, 0x18);
+    cpu.set_mem(0xC002, 0xFD);
+    cpu.set_mem(0xFF07, 0x05);
+    cpu.set_mem(0xFF26, 0x80);
+    cpu.set_mem(0xFF12, 0xF0);
+    cpu.set_mem(0xFF14, 0x80);
+    cpu.pc = 0xC000;
+    for _ in 0..1000 {
+        cpu.dispatch_opcode();
+    }
+
+    let state = cpu.save_state();
+    let mut expected = cpu.clone();
+    for _ in 0..1000 {
+        cpu.dispatch_opcode();
+        expected.dispatch_opcode();
+    }
+
+    cpu.set_mem(0xA000, 0);
+    cpu.set_mem(0x2000, 1);
+    cpu.load_state(&state[..]).unwrap();
+    for _ in 0..1000 {
+        cpu.dispatch_opcode();
+    }
+    assert_eq!(cpu.pc, expected.pc);
+    assert_eq!(cpu.a, expected.a);
+    assert_eq!(cpu.cycles, expected.cycles);
+    assert_eq!(cpu.get_mem(0xFF05), expected.get_mem(0xFF05));
+    assert_eq!(cpu.get_mem(0x4000), 2);
+    assert_eq!(cpu.get_mem(0xA000), 0x77);
+    assert!(cpu.apu.channel_on(0));
+    assert_eq!(cpu.memory_snapshot(), expected.memory_snapshot());
+    assert_eq!(cpu.save_state(), expected.save_state());
+}
+
+#[test]
+fn test_save_state_errors() {
+    use cpu::savestate::*;
+
+    let mut cpu = load_cartridge(make_banked_rom(0x01, 4, 0));
+    let state = cpu.save_state();
+
+    match cpu.load_state(b"not a save state") {
+        Err(SaveStateError::NotASaveState) => (),
+        other => panic!("unexpected result {:?}", other),
+    }
+
+    let mut newer = state.clone();
+    newer[8] = 99;
+    match cpu.load_state(&newer[..]) {
+        Err(SaveStateError::UnsupportedVersion(99)) => (),
+        other => panic!("unexpected result {:?}", other),
+    }
+
+    let mut other_rom = load_cartridge(make_banked_rom(0x01, 8, 0));
+    match other_rom.load_state(&state[..]) {
+        Err(SaveStateError::WrongRom) => (),
+        other => panic!("unexpected result {:?}", other),
+    }
+
+    // A failed load leaves the CPU untouched
+    cpu.pc = 0x1234;
+    match cpu.load_state(&state[..state.len() - 1]) {
+        Err(SaveStateError::Corrupt) => (),
+        other => panic!("unexpected result {:?}", other),
+    }
+    assert_eq!(cpu.pc, 0x1234);
+}
+
+#[test]
+fn test_boot_rom() {
+    // LD A,1; JP 0x00FE, which is LDH (0x50),A; then falls into the cartridge
+    let mut boot_rom = vec![0; 0x100];
+    boot_rom[0..5].copy_from_slice(&[0x3E, 0x01, 0xC3, 0xFE, 0x00]);
+    boot_rom[0xFE] = 0xE0;
+    boot_rom[0xFF] = 0x50;
+    let mut rom = make_banked_rom(0x00, 2, 0);
+    rom[0x00] = 0x12;
+    rom[0x100] = 0x3C;
+
+    let mut cpu = load_cartridge(rom);
+    cpu.boot_with(boot_rom);
+    assert_eq!(cpu.pc, 0);
+    assert!(cpu.boot_rom_mapped());
+    assert_eq!(cpu.get_mem(0x0000), 0x3E);
+    assert_eq!(cpu.get_mem(0x0100), 0x3C);
+    // The LCD is off until the boot ROM turns it on
+    assert_eq!(cpu.get_mem(0xFF40), 0);
+
+    // ROM writes still reach the cartridge without unmapping
+    cpu.set_mem(0x2000, 1);
+    assert_eq!(cpu.peek(0), 0x3E);
+
+    for _ in 0..3 {
+        cpu.dispatch_opcode();
+    }
+    assert!(!cpu.boot_rom_mapped());
+    assert_eq!(cpu.pc, 0x100);
+    assert_eq!(cpu.get_mem(0x0000), 0x12);
+    assert_eq!(cpu.peek(0), 0x12);
+    cpu.dispatch_opcode();
+    assert_eq!(cpu.a, 2);
+
+    // It can't be mapped again
+    cpu.set_mem(0xFF50, 0);
+    assert_eq!(cpu.get_mem(0x0000), 0x12);
+}
+
+fn cgb_test_cpu() -> Cpu {
+    let mut rom = make_banked_rom(0x00, 2, 0);
+    rom[0x143] = 0x80;
+    let mut cpu = load_cartridge(rom);
+    cpu.reset();
+    cpu
+}
+
+#[test]
+fn test_cgb_detection() {
+    let mut cpu = load_cartridge(make_banked_rom(0x00, 2, 0));
+    cpu.reset();
+    assert!(!cpu.cgb_mode());
+    assert_eq!(cpu.a, 0x01);
+    // The CGB registers don't exist on the DMG
+    cpu.set_mem(0xFF4F, 1);
+    assert_eq!(cpu.get_mem(0xFF4F), 0xFF);
+    assert_eq!(cpu.get_mem(0xFF70), 0xFF);
+
+    let mut cpu = cgb_test_cpu();
+    assert!(cpu.cgb_mode());
+    assert_eq!(cpu.a, 0x11);
+    assert_eq!(cpu.get_mem(0xFF4D), 0x7E);
+    assert_eq!(cpu.get_mem(0xFF4F), 0xFE);
+    assert_eq!(cpu.get_mem(0xFF70), 0xF9);
+}
+
+#[test]
+fn test_cgb_ram_banks() {
+    let mut cpu = cgb_test_cpu();
+    cpu.set_mem(0x8000, 1);
+    cpu.set_mem(0xFF4F, 1);
+    assert_eq!(cpu.get_mem(0x8000), 0);
+    cpu.set_mem(0x8000, 2);
+    assert_eq!(cpu.peek(0x8000), 2);
+    cpu.set_mem(0xFF4F, 0);
+    assert_eq!(cpu.get_mem(0x8000), 1);
+    assert_eq!(cpu.peek(0x8000), 1);
+    assert_eq!(cpu.vram.bank(1)[0], 2);
+
+    cpu.set_mem(0xC000, 0x10);
+    cpu.set_mem(0xD000, 0x11);
+    cpu.set_mem(0xFF70, 3);
+    assert_eq!(cpu.get_mem(0xC000), 0x10);
+    assert_eq!(cpu.get_mem(0xD000), 0);
+    cpu.set_mem(0xD000, 0x33);
+    assert_eq!(cpu.get_mem(0xF000), 0x33);
+    assert_eq!(cpu.peek(0xF000), 0x33);
+    // Bank 0 selects bank 1
+    cpu.set_mem(0xFF70, 0);
+    assert_eq!(cpu.get_mem(0xFF70), 0xF9);
+    assert_eq!(cpu.get_mem(0xD000), 0x11);
+    assert_eq!(cpu.peek(0xF000), 0x11);
+}
+
+#[test]
+fn test_cgb_palettes() {
+    let mut cpu = cgb_test_cpu();
+    // Auto increment from color 1 of palette 2
+    cpu.set_mem(0xFF68, 0x80 | 0x12);
+    cpu.set_mem(0xFF69, 0x1F);
+    cpu.set_mem(0xFF69, 0x7C);
+    assert_eq!(cpu.get_mem(0xFF68), 0xC0 | 0x14);
+    assert_eq!(cpu.bg_palette_color(2, 1), 0x7C1F);
+    cpu.set_mem(0xFF68, 0x12);
+    assert_eq!(cpu.get_mem(0xFF69), 0x1F);
+
+    // Without auto increment the same byte is written again
+    cpu.set_mem(0xFF6A, 0x08);
+    cpu.set_mem(0xFF6B, 0x00);
+    cpu.set_mem(0xFF6B, 0x03);
+    assert_eq!(cpu.get_mem(0xFF6A), 0x48);
+    assert_eq!(cpu.obj_palette_color(1, 0), 0x7F03);
+}
+
+#[test]
+fn test_cgb_background_attributes() {
+    use cpu::ppu::*;
+
+    let mut cpu = cgb_test_cpu();
+    cpu.set_mem(0xFF40, 0x00);
+    cpu.step_ppu(4);
+    // Tile 1 of bank 1 has color 3 on its top row only
+    cpu.set_mem(0xFF4F, 1);
+    cpu.set_mem(0x8010, 0xFF);
+    cpu.set_mem(0x8011, 0xFF);
+    // Tile (0, 0) uses it, flipped vertically, with palette 2
+    cpu.set_mem(0x9800, 0x08 | 0x40 | 0x02);
+    cpu.set_mem(0xFF4F, 0);
+    cpu.set_mem(0x9800, 1);
+    cpu.set_mem(0xFF68, 0x80 | 0x16);
+    cpu.set_mem(0xFF69, 0x00);
+    cpu.set_mem(0xFF69, 0x7C);
+    cpu.set_mem(0xFF40, 0x91);
+
+    cpu.step_ppu(SCANLINE_CYCLES * SCREEN_HEIGHT as u32);
+    let frame = cpu.cgb_frame_buffer();
+    assert_eq!(frame[7 * SCREEN_WIDTH], 0x7C00);
+    assert_eq!(frame[0], 0x7FFF);
+}
+
+#[test]
+fn test_cgb_hdma() {
+    use cpu::ppu::*;
+
+    let mut cpu = cgb_test_cpu();
+    for i in 0..0x40 {
+        cpu.set_mem(0xC000 + i, i as u8);
+    }
+
+    // General purpose DMA of two blocks from 0xC000 to 0x8100
+    cpu.set_mem(0xFF51, 0xC0);
+    cpu.set_mem(0xFF52, 0x00);
+    cpu.set_mem(0xFF53, 0x81);
+    cpu.set_mem(0xFF54, 0x00);
+    let cycles = cpu.cycles;
+    cpu.set_mem(0xFF55, 0x01);
+    assert_eq!(cpu.get_mem(0xFF55), 0xFF);
+    assert_eq!(cpu.get_mem(0x811F), 0x1F);
+    assert_eq!(cpu.get_mem(0x8120), 0);
+    assert_eq!(cpu.cycles - cycles, 64);
+
+    // HBlank DMA copies a block per HBlank
+    cpu.set_mem(0xFF51, 0xC0);
+    cpu.set_mem(0xFF52, 0x20);
+    cpu.set_mem(0xFF53, 0x82);
+    cpu.set_mem(0xFF54, 0x00);
+    cpu.set_mem(0xFF55, 0x81);
+    assert_eq!(cpu.get_mem(0xFF55), 0x01);
+    assert_eq!(cpu.get_mem(0x8200), 0);
+    cpu.step_ppu(SCANLINE_CYCLES);
+    assert_eq!(cpu.get_mem(0xFF55), 0x00);
+    assert_eq!(cpu.get_mem(0x820F), 0x2F);
+    assert_eq!(cpu.get_mem(0x8210), 0);
+    cpu.step_ppu(SCANLINE_CYCLES);
+    assert_eq!(cpu.get_mem(0xFF55), 0xFF);
+    assert_eq!(cpu.get_mem(0x821F), 0x3F);
+}
+
+#[test]
+fn test_cgb_speed_switch() {
+    let mut cpu = cgb_test_cpu();
+    // STOP with KEY1 armed switches speed and keeps running
+    cpu.set_mem(0xC000, 0x10);
+    cpu.set_mem(0xC001, 0x00);
+    cpu.pc = 0xC000;
+    cpu.set_mem(0xFF4D, 0x01);
+    assert_eq!(cpu.get_mem(0xFF4D), 0x7F);
+    cpu.dispatch_opcode();
+    assert_eq!(cpu.state, CpuState::Normal);
+    assert_eq!(cpu.get_mem(0xFF4D), 0xFE);
+
+    // The timer runs at twice the speed of the PPU
+    cpu.set_mem(0xFF04, 0);
+    let ly = cpu.ly();
+    for _ in 0..114 {
+        cpu.advance_cycles(4);
+    }
+    assert_eq!(cpu.ly(), ly);
+    assert_eq!(cpu.get_mem(0xFF04), 1);
+    for _ in 0..114 {
+        cpu.advance_cycles(4);
+    }
+    assert_eq!(cpu.ly(), ly + 1);
+}
+
+use cpu::serial::SerialLink;
+
+/// Link cable which records what is sent over it
+struct SerialLog {
+    sent: Rc<RefCell<Vec<u8>>>,
+    reply: u8,
+    /// Byte clocked over by the other side, returned once
+    incoming: Option<u8>,
+}
+
+impl SerialLink for SerialLog {
+    fn transfer(&mut self, value: u8) -> u8 {
+        self.sent.borrow_mut().push(value);
+        self.reply
+    }
+
+    fn clocked_by_peer(&mut self, reply: u8) -> Option<u8> {
+        let incoming = self.incoming.take();
+        if incoming.is_some() {
+            self.sent.borrow_mut().push(reply);
+        }
+        incoming
+    }
+}
+
+#[test]
+fn test_serial_without_link() {
+    let mut cpu = Cpu::new();
+    cpu.write_if(0);
+    cpu.set_mem(0xFF01, 0x42);
+    cpu.set_mem(0xFF02, 0x81);
+    assert_eq!(cpu.get_mem(0xFF02), 0xFF);
+    cpu.step_serial(8 * 512 - 4);
+    assert_eq!(cpu.get_mem(0xFF01), 0x42);
+    cpu.step_serial(4);
+    assert_eq!(cpu.get_mem(0xFF01), 0xFF);
+    assert_eq!(cpu.get_mem(0xFF02), 0x7F);
+    assert_eq!(cpu.get_mem(0xFF0F) & 0x08, 0x08);
+
+    // The external clock never comes
+    cpu.set_mem(0xFF02, 0x80);
+    cpu.step_serial(100000);
+    assert_eq!(cpu.get_mem(0xFF02), 0xFE);
+}
+
+#[test]
+fn test_serial_link() {
+    let sent = Rc::new(RefCell::new(vec![]));
+    let mut cpu = Cpu::new();
+    cpu.connect_serial(Box::new(SerialLog {
+        sent: sent.clone(),
+        reply: 0x24,
+        incoming: None,
+    }));
+    // Clones share the cable
+    let mut cpu = cpu.clone();
+    cpu.set_mem(0xFF01, 0x42);
+    cpu.set_mem(0xFF02, 0x81);
+    cpu.step_serial(8 * 512);
+    assert_eq!(*sent.borrow(), vec![0x42]);
+    assert_eq!(cpu.get_mem(0xFF01), 0x24);
+
+    // Waiting for the other side with the external clock
+    let mut cpu = Cpu::new();
+    cpu.write_if(0);
+    cpu.connect_serial(Box::new(SerialLog {
+        sent: sent.clone(),
+        reply: 0,
+        incoming: Some(0x99),
+    }));
+    cpu.set_mem(0xFF01, 0x11);
+    cpu.set_mem(0xFF02, 0x80);
+    cpu.step_serial(512);
+    assert_eq!(cpu.get_mem(0xFF01), 0x99);
+    assert_eq!(cpu.get_mem(0xFF02), 0x7E);
+    assert_eq!(cpu.get_mem(0xFF0F) & 0x08, 0x08);
+    assert_eq!(*sent.borrow(), vec![0x42, 0x11]);
+}
+
+#[test]
+fn test_socket_link() {
+    use std::net::{TcpListener, TcpStream};
+    use std::thread;
+    use io::link::SocketLink;
+
+    let listener = TcpListener::bind("127.0.0.1:0").unwrap();
+    let addr = listener.local_addr().unwrap();
+    let other = thread::spawn(move || {
+        let mut link = SocketLink::new(Box::new(TcpStream::connect(addr).unwrap()));
+        loop {
+            if let Some(value) = link.clocked_by_peer(0x5A) {
+                return value;
+            }
+            thread::yield_now();
+        }
+    });
+
+    let (stream, _) = listener.accept().unwrap();
+    let mut link = SocketLink::new(Box::new(stream));
+    assert_eq!(link.transfer(0xA5), 0x5A);
+    assert_eq!(other.join().unwrap(), 0xA5);
+    // Nobody answers once the other side is gone
+    assert_eq!(link.transfer(0x00), 0xFF);
+}
+
+#[test]
+fn test_socket_link_late_reply() {
+    use std::io::{Read, Write};
+    use std::net::{TcpListener, TcpStream};
+    use std::sync::mpsc;
+    use std::thread;
+    use std::time::Duration;
+    use io::link::SocketLink;
+
+    let listener = TcpListener::bind("127.0.0.1:0").unwrap();
+    let addr = listener.local_addr().unwrap();
+    let (replied_late, late_reply_sent) = mpsc::channel();
+    let other = thread::spawn(move || {
+        let mut stream = TcpStream::connect(addr).unwrap();
+        let mut frame = [0; 2];
+        // Answers the first byte only after the transfer gave up
+        stream.read_exact(&mut frame).unwrap();
+        thread::sleep(Duration::from_millis(600));
+        stream.write_all(&[1, 0x11]).unwrap();
+        replied_late.send(()).unwrap();
+        stream.read_exact(&mut frame).unwrap();
+        stream.write_all(&[1, frame[1] + 1]).unwrap();
+    });
+
+    let (stream, _) = listener.accept().unwrap();
+    let mut link = SocketLink::new(Box::new(stream));
+    assert_eq!(link.transfer(0xA5), 0xFF);
+    late_reply_sent.recv().unwrap();
+    thread::sleep(Duration::from_millis(50));
+    assert_eq!(link.transfer(0x22), 0x23);
+    other.join().unwrap();
+}
+
+#[test]
+fn test_load_missing_rom() {
+    let mut cpu = Cpu::new();
+    cpu.pc = 0x1234;
+    assert!(cpu.try_load_rom("no/such/rom.gb").is_err());
+    assert_eq!(cpu.pc, 0x1234);
+}
+
+fn headless_test_runner(program: &[u8]) -> ::io::headless::HeadlessRunner {
+    let mut rom = make_banked_rom(0x00, 2, 0);
+    rom[0x100..0x100 + program.len()].copy_from_slice(program);
+    ::io::headless::HeadlessRunner::new(load_cartridge(rom), false)
+}
+
+#[test]
+fn test_headless_breakpoint() {
+    use io::headless::RunResult;
+
+    // LD B,3; LD C,5; LD D,8; LD E,13; LD H,21; LD L,34; LD B,B
+    let fibonacci = [0x06, 3, 0x0E, 5, 0x16, 8, 0x1E, 13, 0x26, 21, 0x2E, 34, 0x40];
+    let mut runner = headless_test_runner(&fibonacci);
+    assert_eq!(runner.run(Some(1), None), RunResult::Passed);
+    assert_eq!(runner.cpu.pc, 0x10C);
+
+    // LD B,0x42; LD C,B; LD D,B; LD E,B; LD H,B; LD L,B; LD B,B
+    let mut runner = headless_test_runner(&[0x06, 0x42, 0x48, 0x50, 0x58, 0x60, 0x68, 0x40]);
+    assert_eq!(runner.run(Some(1), None), RunResult::Failed);
+    assert_eq!(runner.cpu.pc, 0x107);
+
+    // Other values are no result, as in Blargg's LD r,r test
+    let mut runner = headless_test_runner(&[0x06, 0x42, 0x40, 0x18, 0xFE]);
+    assert_eq!(runner.run(Some(1), None), RunResult::LimitReached);
+}
+
+#[test]
+fn test_headless_serial_result() {
+    use io::headless::RunResult;
+
+    let program = [0x21, 0x20, 0x01, // LD HL,0x0120
+                   0x2A,             // loop: LD A,(HL+)
+                   0xB7,             // OR A
+                   0x28, 0x0C,       // JR Z,end
+                   0xE0, 0x01,       // LDH (0x01),A
+                   0x3E, 0x81,       // LD A,0x81
+                   0xE0, 0x02,       // LDH (0x02),A
+                   0xF0, 0x02,       // wait: LDH A,(0x02)
+                   0xCB, 0x7F,       // BIT 7,A
+                   0x20, 0xFA,       // JR NZ,wait
+                   0x18, 0xEE,       // JR loop
+                   0x18, 0xFE];      // end: JR end
+    let mut rom = program.to_vec();
+    rom.resize(0x20, 0);
+    rom.extend_from_slice(b"Test: Passed\0");
+    let mut runner = headless_test_runner(&rom[..]);
+    assert_eq!(runner.run(None, Some(10000000)), RunResult::Passed);
+    assert_eq!(runner.serial_output(), "Test: Passed");
+
+    // JR -2 forever
+    let mut runner = headless_test_runner(&[0x18, 0xFE]);
+    assert_eq!(runner.run(Some(2), None), RunResult::LimitReached);
+    assert!(runner.cpu.cycles >= 70224);
+    assert!(runner.cpu.cycles < 3 * 70224);
+    assert_eq!(runner.run(None, Some(200000)), RunResult::LimitReached);
+    assert!(runner.cpu.cycles >= 200000);
+
+    // Only a pass exits successfully
+    let codes: Vec<i32> = [RunResult::Passed,
+                           RunResult::Failed,
+                           RunResult::Crashed,
+                           RunResult::LimitReached]
+        .iter()
+        .map(|result| result.exit_code())
+        .collect();
+    assert_eq!(codes, vec![0, 1, 2, 3]);
+}
+
+#[test]
+fn test_png() {
+    use io::png::write_png;
+
+    let mut png = vec![];
+    write_png(&mut png, 2, 1, &[255, 0, 0, 0, 0, 255]).unwrap();
+    assert_eq!(&png[..8], b"\x89PNG\r\n\x1a\n");
+    assert_eq!(&png[8..16], b"\0\0\0\x0DIHDR");
+    assert_eq!(&png[16..29], &[0, 0, 0, 2, 0, 0, 0, 1, 8, 2, 0, 0, 0]);
+    // IHDR CRC
+    assert_eq!(&png[29..33], &[0x7B, 0x40, 0xE8, 0xDD]);
+    assert_eq!(&png[png.len() - 12..],
+               &[0, 0, 0, 0, b'I', b'E', b'N', b'D', 0xAE, 0x42, 0x60, 0x82]);
+}
+
+/// Directory holding the test ROMs for `test_rom_conformance`, laid out
+/// as in the Blargg and Mooneye test suites' releases
+const TEST_ROMS_ENV: &'static str = "RUSTY_BOY_TEST_ROMS";
+
+/// Cycles after which a test ROM counts as hung, a minute of emulated
+/// time
+const TEST_ROM_CYCLES: u64 = 60 * 4194304;
+
+/// Test ROMs and whether they are expected to pass.  Blargg's ROMs
+/// print their result over serial, Mooneye's signal it with `LD B,B`,
+/// both of which `HeadlessRunner` understands.  A ROM expected to pass
+/// that doesn't fails the test.  Only flip an expectation once a run
+/// with the ROMs shows it passing, the table printed by
+/// `cargo test test_rom_conformance -- --nocapture` marks those.
+/// Missing ROMs are skipped.
+const TEST_ROMS: &'static [(&'static str, bool)] = &[
+    ("cpu_instrs/individual/01-special.gb", false),
+    ("cpu_instrs/individual/02-interrupts.gb", false),
+    ("cpu_instrs/individual/03-op sp,hl.gb", false),
+    ("cpu_instrs/individual/04-op r,imm.gb", false),
+    ("cpu_instrs/individual/05-op rp.gb", false),
+    ("cpu_instrs/individual/06-ld r,r.gb", false),
+    ("cpu_instrs/individual/07-jr,jp,call,ret,rst.gb", false),
+    ("cpu_instrs/individual/08-misc instrs.gb", false),
+    ("cpu_instrs/individual/09-op r,r.gb", false),
+    ("cpu_instrs/individual/10-bit ops.gb", false),
+    ("cpu_instrs/individual/11-op a,(hl).gb", false),
+    ("instr_timing/instr_timing.gb", false),
+    ("mem_timing/individual/01-read_timing.gb", false),
+    ("mem_timing/individual/02-write_timing.gb", false),
+    ("mem_timing/individual/03-modify_timing.gb", false),
+    ("acceptance/add_sp_e_timing.gb", false),
+    ("acceptance/bits/mem_oam.gb", false),
+    ("acceptance/bits/reg_f.gb", false),
+    ("acceptance/call_timing.gb", false),
+    ("acceptance/di_timing-GS.gb", false),
+    ("acceptance/div_timing.gb", false),
+    ("acceptance/ei_sequence.gb", false),
+    ("acceptance/ei_timing.gb", false),
+    ("acceptance/halt_ime0_ei.gb", false),
+    ("acceptance/halt_ime0_nointr_timing.gb", false),
+    ("acceptance/halt_ime1_timing.gb", false),
+    ("acceptance/if_ie_registers.gb", false),
+    ("acceptance/instr/daa.gb", false),
+    ("acceptance/interrupts/ie_push.gb", false),
+    ("acceptance/intr_timing.gb", false),
+    ("acceptance/jp_timing.gb", false),
+    ("acceptance/ld_hl_sp_e_timing.gb", false),
+    ("acceptance/oam_dma/basic.gb", false),
+    ("acceptance/oam_dma_restart.gb", false),
+    ("acceptance/oam_dma_start.gb", false),
+    ("acceptance/oam_dma_timing.gb", false),
+    ("acceptance/pop_timing.gb", false),
+    ("acceptance/push_timing.gb", false),
+    ("acceptance/rapid_di_ei.gb", false),
+    ("acceptance/ret_timing.gb", false),
+    ("acceptance/reti_intr_timing.gb", false),
+    ("acceptance/rst_timing.gb", false),
+    ("acceptance/timer/div_write.gb", false),
+    ("acceptance/timer/rapid_toggle.gb", false),
+    ("acceptance/timer/tim00.gb", false),
+    ("acceptance/timer/tim01.gb", false),
+    ("acceptance/timer/tim10.gb", false),
+    ("acceptance/timer/tim11.gb", false),
+    ("acceptance/timer/tima_reload.gb", false),
+    ("acceptance/timer/tima_write_reloading.gb", false),
+];
+
+#[test]
+fn test_rom_conformance() {
+    use std::env;
+    use std::path::PathBuf;
+    use io::headless::{HeadlessRunner, RunResult};
+
+    let dir = match env::var_os(TEST_ROMS_ENV) {
+        Some(dir) => PathBuf::from(dir),
+        None => {
+            println!("{} not set, skipping test ROMs", TEST_ROMS_ENV);
+            return;
+        }
+    };
+
+    let mut regressions = vec![];
+    let mut table = format!("{:<50} {:<8} {}\n", "ROM", "expected", "result");
+    for &(name, expect_pass) in TEST_ROMS {
+        let path = dir.join(name);
+        let result = if path.is_file() {
+            let mut cpu = Cpu::new();
+            cpu.load_rom(path.to_str().expect("test ROM path is not UTF-8"));
+            cpu.timing_mode = TimingMode::PerAccess;
+            let mut runner = HeadlessRunner::new(cpu, false);
+            Some(runner.run(None, Some(TEST_ROM_CYCLES)))
+        } else {
+            None
+        };
+
+        let passed = result == Some(RunResult::Passed);
+        let note = if result.is_none() {
+            ""
+        } else if expect_pass && !passed {
+            regressions.push(name);
+            "  <- regression"
+        } else if !expect_pass && passed {
+            "  <- now passes"
+        } else {
+            ""
+        };
+        let outcome = result.map_or("missing".to_string(), |result| format!("{:?}", result));
+        table.push_str(&format!("{:<50} {:<8} {}{}\n",
+                                name,
+                                if expect_pass { "pass" } else { "fail" },
+                                outcome,
+                                note));
+    }
+
+    println!("{}", table);
+    assert!(regressions.is_empty(),
+            "test ROMs expected to pass failed: {:?}\n{}",
+            regressions,
+            table);
+}
+
+#[test]
+fn test_watchpoints() {
+    use cpu::watchpoint::*;
+
+    let mut cpu = Cpu::new();
+    // LD HL, 0xC100; LD A, (HL); LD (HL), 0x05; LD (HL), 0x05; INC (HL)
+    let program = [0x21, 0x00, 0xC1, 0x7E, 0x36, 0x05, 0x36, 0x05, 0x34];
+    for (i, &b) in program.iter().enumerate() {
+        cpu.set_mem(0xC000 + i as MemAddr, b);
+    }
+    cpu.pc = 0xC000;
+    cpu.add_watchpoint(Watchpoint {
+        start: 0xC0F0,
+        end: 0xC10F,
+        mode: WatchMode::Read,
+    });
+    cpu.add_watchpoint(Watchpoint {
+        start: 0xC100,
+        end: 0xC100,
+        mode: WatchMode::Change,
+    });
+    cpu.add_watchpoint(Watchpoint {
+        start: 0xC100,
+        end: 0xC100,
+        mode: WatchMode::Value(0x06),
+    });
+
+    cpu.dispatch_opcode();
+    assert_eq!(cpu.take_watchpoint_hit(), None);
+
+    cpu.dispatch_opcode();
+    assert_eq!(cpu.take_watchpoint_hit(),
+               Some(WatchpointHit {
+                   pc: 0xC003,
+                   address: 0xC100,
+                   old: 0,
+                   new: None,
+               }));
+
+    cpu.dispatch_opcode();
+    assert_eq!(cpu.take_watchpoint_hit(),
+               Some(WatchpointHit {
+                   pc: 0xC004,
+                   address: 0xC100,
+                   old: 0,
+                   new: Some(0x05),
+               }));
+
+    // Writing the same value again changes nothing
+    cpu.dispatch_opcode();
+    assert_eq!(cpu.take_watchpoint_hit(), None);
+
+    // Only the first access of an instruction is kept
+    cpu.dispatch_opcode();
+    assert_eq!(cpu.take_watchpoint_hit().map(|hit| hit.new), Some(None));
+    assert_eq!(cpu.remove_watchpoints(0xC0F0, 0xC0F0), 1);
+    cpu.pc = 0xC008;
+    cpu.dispatch_opcode();
+    assert_eq!(cpu.take_watchpoint_hit(),
+               Some(WatchpointHit {
+                   pc: 0xC008,
+                   address: 0xC100,
+                   old: 0x06,
+                   new: Some(0x07),
+               }));
+
+    // Nothing is reported once they are gone
+    assert_eq!(cpu.remove_watchpoints(0xC000, 0xC1FF), 2);
+    cpu.pc = 0xC008;
+    cpu.dispatch_opcode();
+    assert_eq!(cpu.take_watchpoint_hit(), None);
+    assert_eq!(cpu.get_mem(0xC100), 0x08);
+}
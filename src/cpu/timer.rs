@@ -0,0 +1,139 @@
+//! DIV and TIMA timers
+//!
+//! DIV is the upper byte of a 16 bit counter incremented every CPU
+//! cycle.  TIMA is incremented whenever the divider bit selected by TAC
+//! (ANDed with the timer enable bit) goes from 1 to 0, which is why
+//! resetting DIV or changing TAC can increment TIMA.
+
+use cpu::Cpu;
+use cpu::constants::*;
+use cpu::savestate::*;
+
+pub const DIV_ADDR: usize = 0xFF04;
+pub const TIMA_ADDR: usize = 0xFF05;
+pub const TMA_ADDR: usize = 0xFF06;
+pub const TAC_ADDR: usize = 0xFF07;
+
+/// Cycles between TIMA overflowing and it being reloaded from TMA
+const TIMA_RELOAD_DELAY: u8 = 4;
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct Timer {
+    /// Internal counter, DIV is its upper byte
+    pub divider: u16,
+    /// Cycles left until TIMA is reloaded after it overflowed
+    reload_delay: Option<u8>,
+}
+
+impl Timer {
+    pub fn new() -> Timer {
+        Timer {
+            // Value after the boot ROM has run
+            divider: 0xABCC,
+            reload_delay: None,
+        }
+    }
+}
+
+impl SaveState for Timer {
+    fn write_state(&self, w: &mut StateWriter) {
+        w.u16(self.divider);
+        // 0 is never a pending delay
+        w.u8(self.reload_delay.unwrap_or(0));
+    }
+
+    fn read_state(&mut self, r: &mut StateReader) -> Result<(), SaveStateError> {
+        self.divider = r.u16()?;
+        self.reload_delay = match r.u8()? {
+            0 => None,
+            n => Some(n),
+        };
+        Ok(())
+    }
+}
+
+impl Cpu {
+    /// The speed at which the timer runs in Hz, settable by the
+    /// program by writing to 0xFF07
+    pub fn timer_frequency(&self) -> u32 {
+        match self.io[TAC_ADDR] & 0x3 {
+            0 => 4096,
+            1 => 262144,
+            2 => 65536,
+            3 => 16384,
+            _ => unreachable!("The impossible happened!"),
+        }
+    }
+
+    fn is_timer_on(&self) -> bool {
+        (self.io[TAC_ADDR] & 0x4) >> 2 == 1
+    }
+
+    /// The input of TIMA's falling edge detector
+    fn timer_signal(&self) -> bool {
+        let bit = match self.io[TAC_ADDR] & 0x3 {
+            0 => 9,
+            1 => 3,
+            2 => 5,
+            _ => 7,
+        };
+        self.is_timer_on() && (self.timer.divider >> bit) & 1 == 1
+    }
+
+    /// Runs the timer for `cycles` CPU cycles
+    pub fn step_timer(&mut self, cycles: u32) {
+        // The divider is clocked once per machine cycle
+        for _ in 0..(cycles / 4) {
+            if let Some(delay) = self.timer.reload_delay {
+                if delay <= 4 {
+                    self.timer.reload_delay = None;
+                    self.io[TIMA_ADDR] = self.io[TMA_ADDR];
+                    self.set_timer_interrupt_bit();
+                } else {
+                    self.timer.reload_delay = Some(delay - 4);
+                }
+            }
+
+            let old_signal = self.timer_signal();
+            self.timer.divider = self.timer.divider.wrapping_add(4);
+            self.timer_edge(old_signal);
+        }
+    }
+
+    /// Increments TIMA if the timer signal went from 1 to 0
+    fn timer_edge(&mut self, old_signal: bool) {
+        self.io[DIV_ADDR] = (self.timer.divider >> 8) as byte;
+        if old_signal && !self.timer_signal() {
+            self.inc_tima();
+        }
+    }
+
+    fn inc_tima(&mut self) {
+        let (val, overflow) = self.io[TIMA_ADDR].overflowing_add(1);
+        // On overflow TIMA reads as 0 until it is reloaded
+        self.io[TIMA_ADDR] = val;
+        if overflow {
+            self.timer.reload_delay = Some(TIMA_RELOAD_DELAY);
+        }
+    }
+
+    /// Writes to DIV reset the whole divider
+    pub fn reset_div(&mut self) {
+        let old_signal = self.timer_signal();
+        self.timer.divider = 0;
+        self.timer_edge(old_signal);
+    }
+
+    pub fn write_tima(&mut self, value: byte) {
+        // Writing during the reload delay cancels the reload
+        self.timer.reload_delay = None;
+        self.io[TIMA_ADDR] = value;
+    }
+
+    pub fn write_tac(&mut self, value: byte) {
+        let old_signal = self.timer_signal();
+        // Only the lower 3 bits exist
+        self.io[TAC_ADDR] = 0xF8 | value;
+        self.timer_edge(old_signal);
+    }
+}
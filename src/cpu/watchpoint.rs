@@ -0,0 +1,178 @@
+//! Memory watchpoints
+//!
+//! Watchpoints cover a range of addresses and trigger on reads, on
+//! writes, or on writes which change the stored value, optionally only
+//! to one value.  Only accesses by the CPU are watched, not those of
+//! the DMA or the debugger.  The first access of an instruction which
+//! triggers is kept until the debugger takes it.
+
+use std::fmt;
+use cpu::Cpu;
+use cpu::constants::*;
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum WatchMode {
+    Read,
+    Write,
+    /// Writes of a value different from the one stored
+    Change,
+    /// Writes which change the stored value to this one
+    Value(byte),
+}
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Watchpoint {
+    pub start: MemAddr,
+    /// Last address watched, inclusive
+    pub end: MemAddr,
+    pub mode: WatchMode,
+}
+
+impl Watchpoint {
+    pub fn covers(&self, address: MemAddr) -> bool {
+        self.start <= address && address <= self.end
+    }
+
+    fn triggers(&self, address: MemAddr, old: byte, new: Option<byte>) -> bool {
+        if !self.covers(address) {
+            return false;
+        }
+        match (self.mode, new) {
+            (WatchMode::Read, None) => true,
+            (WatchMode::Write, Some(_)) => true,
+            (WatchMode::Change, Some(new)) => new != old,
+            (WatchMode::Value(v), Some(new)) => new != old && new == v,
+            _ => false,
+        }
+    }
+}
+
+impl fmt::Display for Watchpoint {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let mode = match self.mode {
+            WatchMode::Read => "read".to_string(),
+            WatchMode::Write => "write".to_string(),
+            WatchMode::Change => "change".to_string(),
+            WatchMode::Value(v) => format!("change to 0x{:02X}", v),
+        };
+        if self.start == self.end {
+            write!(f, "{} 0x{:04X}", mode, self.start)
+        } else {
+            write!(f, "{} 0x{:04X}..0x{:04X}", mode, self.start, self.end)
+        }
+    }
+}
+
+/// An access which triggered a watchpoint
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct WatchpointHit {
+    /// Address of the instruction which made the access
+    pub pc: MemAddr,
+    pub address: MemAddr,
+    /// Value before the access
+    pub old: byte,
+    /// Value written, None for reads
+    pub new: Option<byte>,
+}
+
+impl fmt::Display for WatchpointHit {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self.new {
+            Some(new) => {
+                write!(f,
+                       "PC 0x{:04X} wrote 0x{:04X}: 0x{:02X} -> 0x{:02X}",
+                       self.pc,
+                       self.address,
+                       self.old,
+                       new)
+            }
+            None => {
+                write!(f,
+                       "PC 0x{:04X} read 0x{:04X}: 0x{:02X}",
+                       self.pc,
+                       self.address,
+                       self.old)
+            }
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct Watchpoints {
+    pub list: Vec<Watchpoint>,
+    hit: Option<WatchpointHit>,
+    /// Address of the instruction being executed
+    instruction_pc: MemAddr,
+}
+
+impl Watchpoints {
+    pub fn new() -> Watchpoints {
+        Watchpoints {
+            list: vec![],
+            hit: None,
+            instruction_pc: 0,
+        }
+    }
+
+    fn covers(&self, address: MemAddr) -> bool {
+        self.list.iter().any(|wp| wp.covers(address))
+    }
+}
+
+impl Cpu {
+    pub fn add_watchpoint(&mut self, watchpoint: Watchpoint) {
+        self.watchpoints.list.push(watchpoint);
+    }
+
+    /// Removes the watchpoints overlapping `start..end`, returning how
+    /// many there were
+    pub fn remove_watchpoints(&mut self, start: MemAddr, end: MemAddr) -> usize {
+        let before = self.watchpoints.list.len();
+        self.watchpoints.list.retain(|wp| wp.end < start || end < wp.start);
+        before - self.watchpoints.list.len()
+    }
+
+    /// The access which triggered a watchpoint since the last call
+    pub fn take_watchpoint_hit(&mut self) -> Option<WatchpointHit> {
+        self.watchpoints.hit.take()
+    }
+
+    /// Called at the start of each instruction
+    pub fn start_watching(&mut self) {
+        self.watchpoints.instruction_pc = self.pc;
+    }
+
+    /// Called for reads by the CPU with the value read
+    pub fn watch_read(&mut self, address: MemAddr, value: byte) {
+        if self.watchpoints.list.is_empty() {
+            return;
+        }
+        self.check_watchpoints(address, value, None);
+    }
+
+    /// Called before writes by the CPU
+    pub fn watch_write(&mut self, address: MemAddr, value: byte) {
+        if !self.watchpoints.covers(address) {
+            return;
+        }
+        // The debugger mustn't show up in the event log
+        let logger = self.event_logger.take();
+        let old = self.read_mem(address);
+        self.event_logger = logger;
+        self.check_watchpoints(address, old, Some(value));
+    }
+
+    fn check_watchpoints(&mut self, address: MemAddr, old: byte, new: Option<byte>) {
+        if self.watchpoints.hit.is_some() {
+            return;
+        }
+        if self.watchpoints.list.iter().any(|wp| wp.triggers(address, old, new)) {
+            self.watchpoints.hit = Some(WatchpointHit {
+                pc: self.watchpoints.instruction_pc,
+                address: address,
+                old: old,
+                new: new,
+            });
+        }
+    }
+}
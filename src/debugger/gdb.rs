@@ -0,0 +1,601 @@
+//! GDB remote serial protocol stub
+//!
+//! Lets GDB, or a front-end speaking its protocol, debug the game over
+//! a TCP connection.  The registers are AF, BC, DE, HL, SP and PC in
+//! that order, 16 bits each and little endian, as described by the
+//! target description served to GDB.  Breakpoints are kept by the stub
+//! instead of being patched into memory, so software and hardware
+//! breakpoints are the same, and watchpoints are the CPU's.  The game
+//! only runs while GDB lets it continue.
+
+use std::cmp;
+use std::collections::BTreeSet;
+use std::io;
+use std::io::{Read, Write};
+use std::net::TcpListener;
+use std::str;
+use std::time::Duration;
+
+use cpu::{Cpu, CycleCount};
+use cpu::constants::*;
+use cpu::watchpoint::*;
+use io::constants::CPU_CYCLES_PER_VBLANK;
+use io::link::Socket;
+
+/// How long to wait for packets while stopped, before letting the
+/// window handle its events
+const STOPPED_POLL_MS: u64 = 10;
+/// Cycles between looking for an interrupt from GDB while running
+const RUNNING_POLL_CYCLES: CycleCount = CPU_CYCLES_PER_VBLANK;
+/// Largest packet accepted, also limits memory reads
+const PACKET_SIZE: usize = 0x1000;
+
+const SIGINT: u8 = 2;
+const SIGILL: u8 = 4;
+const SIGTRAP: u8 = 5;
+
+/// Interrupt request sent outside of packets
+const INTERRUPT: u8 = 0x03;
+
+const TARGET_XML: &'static str = r#"<?xml version="1.0"?>
+<!DOCTYPE target SYSTEM "gdb-target.dtd">
+<target version="1.0">
+  <architecture>sm83</architecture>
+  <feature name="org.rustyboy.sm83.cpu">
+    <reg name="af" bitsize="16" type="int"/>
+    <reg name="bc" bitsize="16" type="int"/>
+    <reg name="de" bitsize="16" type="int"/>
+    <reg name="hl" bitsize="16" type="data_ptr"/>
+    <reg name="sp" bitsize="16" type="data_ptr"/>
+    <reg name="pc" bitsize="16" type="code_ptr"/>
+  </feature>
+</target>
+"#;
+
+/// Registers in the order of the target description
+const REGISTERS: [Option<CpuRegister16>; 6] = [Some(CpuRegister16::AF),
+                                               Some(CpuRegister16::BC),
+                                               Some(CpuRegister16::DE),
+                                               Some(CpuRegister16::HL),
+                                               Some(CpuRegister16::SP),
+                                               None];
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+enum WatchKind {
+    Write,
+    Read,
+    Access,
+}
+
+impl WatchKind {
+    fn modes(&self) -> &'static [WatchMode] {
+        match *self {
+            WatchKind::Write => &[WatchMode::Write],
+            WatchKind::Read => &[WatchMode::Read],
+            WatchKind::Access => &[WatchMode::Read, WatchMode::Write],
+        }
+    }
+
+    /// Name in stop replies
+    fn name(&self) -> &'static str {
+        match *self {
+            WatchKind::Write => "watch",
+            WatchKind::Read => "rwatch",
+            WatchKind::Access => "awatch",
+        }
+    }
+}
+
+pub struct GdbStub {
+    socket: Box<Socket + Send>,
+    /// Received bytes which don't form a whole packet yet
+    incoming: Vec<u8>,
+    /// Sent again if GDB asks for it
+    last_packet: String,
+    /// Reply to `?`
+    last_stop: String,
+    breakpoints: BTreeSet<MemAddr>,
+    /// Watchpoints set by GDB as (kind, start, end)
+    watchpoints: Vec<(WatchKind, MemAddr, MemAddr)>,
+    running: bool,
+    connected: bool,
+    killed: bool,
+    last_poll: CycleCount,
+}
+
+impl GdbStub {
+    /// Waits for GDB to connect to `port` on this machine
+    pub fn listen(port: u16) -> io::Result<GdbStub> {
+        info!("Waiting for GDB to connect on port {}", port);
+        let (stream, peer) = TcpListener::bind(("127.0.0.1", port))?.accept()?;
+        info!("GDB connected from {}", peer);
+        stream.set_nodelay(true)?;
+        Ok(GdbStub::new(Box::new(stream)))
+    }
+
+    /// Starts out stopped, as GDB expects when it attaches
+    pub fn new(socket: Box<Socket + Send>) -> GdbStub {
+        GdbStub {
+            socket: socket,
+            incoming: vec![],
+            last_packet: String::new(),
+            last_stop: format!("S{:02x}", SIGTRAP),
+            breakpoints: BTreeSet::new(),
+            watchpoints: vec![],
+            running: false,
+            connected: true,
+            killed: false,
+            last_poll: 0,
+        }
+    }
+
+    /// Whether the game may run, false while GDB has it stopped
+    pub fn should_run(&self) -> bool {
+        self.running || !self.connected
+    }
+
+    /// Whether GDB asked to end the emulator
+    pub fn killed(&self) -> bool {
+        self.killed
+    }
+
+    /// Handles what GDB sent.  While stopped this waits a little for
+    /// it, while running it only looks now and then.
+    pub fn poll(&mut self, cpu: &mut Cpu) {
+        if !self.connected {
+            return;
+        }
+        if self.running {
+            // Also polls after the cycle count went back on a reset
+            if cpu.cycles.wrapping_sub(self.last_poll) < RUNNING_POLL_CYCLES {
+                return;
+            }
+            self.last_poll = cpu.cycles;
+        }
+        let wait = !self.running;
+        self.receive(wait);
+        self.handle_incoming(cpu);
+        if !self.connected {
+            self.detach(cpu);
+        }
+    }
+
+    /// Must be called after each instruction run, stops the game at
+    /// breakpoints and triggered watchpoints
+    pub fn after_instruction(&mut self, cpu: &mut Cpu) {
+        if !self.running || !self.connected {
+            return;
+        }
+        if let Some(reply) = self.stop_reason(cpu) {
+            self.stop(reply);
+        }
+    }
+
+    fn stop(&mut self, reply: String) {
+        self.running = false;
+        self.send_packet(&reply);
+        self.last_stop = reply;
+    }
+
+    fn stop_reason(&mut self, cpu: &mut Cpu) -> Option<String> {
+        if cpu.state == CpuState::Crashed {
+            return Some(format!("S{:02x}", SIGILL));
+        }
+        if let Some(hit) = cpu.take_watchpoint_hit() {
+            let watchpoint = self.watchpoints.iter().find(|&&(kind, start, end)| {
+                let access = match (kind, hit.new) {
+                    (WatchKind::Access, _) |
+                    (WatchKind::Write, Some(_)) |
+                    (WatchKind::Read, None) => true,
+                    _ => false,
+                };
+                access && start <= hit.address && hit.address <= end
+            });
+            if let Some(&(kind, _, _)) = watchpoint {
+                return Some(format!("T{:02x}{}:{:x};", SIGTRAP, kind.name(), hit.address));
+            }
+        }
+        if self.breakpoints.contains(&cpu.pc) {
+            return Some(format!("S{:02x}", SIGTRAP));
+        }
+        None
+    }
+
+    /// Lets the game run on its own after GDB is gone
+    fn detach(&mut self, cpu: &mut Cpu) {
+        for &(kind, start, end) in &self.watchpoints {
+            remove_watchpoint(cpu, kind, start, end);
+        }
+        self.watchpoints.clear();
+        self.breakpoints.clear();
+        self.running = true;
+        self.connected = false;
+    }
+
+    fn disconnect(&mut self, reason: &str) {
+        if self.connected {
+            error!("GDB disconnected: {}", reason);
+            self.connected = false;
+        }
+    }
+
+    fn receive(&mut self, wait: bool) {
+        let result = if wait {
+            let timeout = Duration::from_millis(STOPPED_POLL_MS);
+            self.socket
+                .set_nonblocking(false)
+                .and_then(|_| self.socket.set_read_timeout(Some(timeout)))
+        } else {
+            self.socket.set_nonblocking(true)
+        };
+        if let Err(e) = result {
+            self.disconnect(&e.to_string());
+            return;
+        }
+
+        let mut buffer = [0; PACKET_SIZE];
+        match self.socket.read(&mut buffer) {
+            Ok(0) => self.disconnect("GDB hung up"),
+            Ok(n) => self.incoming.extend_from_slice(&buffer[..n]),
+            Err(ref e) if e.kind() == io::ErrorKind::WouldBlock ||
+                          e.kind() == io::ErrorKind::TimedOut ||
+                          e.kind() == io::ErrorKind::Interrupted => (),
+            Err(e) => self.disconnect(&e.to_string()),
+        }
+    }
+
+    fn send(&mut self, data: &[u8]) {
+        if !self.connected {
+            return;
+        }
+        if let Err(e) = self.socket.write_all(data) {
+            self.disconnect(&e.to_string());
+        }
+    }
+
+    fn send_packet(&mut self, data: &str) {
+        let packet = format!("${}#{:02x}", data, checksum(data.as_bytes()));
+        self.send(packet.as_bytes());
+        self.last_packet = packet;
+    }
+
+    /// Acknowledges and handles the whole packets received
+    fn handle_incoming(&mut self, cpu: &mut Cpu) {
+        loop {
+            let start = match self.incoming.iter().position(|&b| b == b'$') {
+                Some(start) => start,
+                None => {
+                    let end = self.incoming.len();
+                    self.handle_outside_packets(end);
+                    self.incoming.clear();
+                    return;
+                }
+            };
+            self.handle_outside_packets(start);
+            let end = match self.incoming[start..].iter().position(|&b| b == b'#') {
+                Some(end) if start + end + 3 <= self.incoming.len() => start + end,
+                _ => {
+                    self.incoming.drain(..start);
+                    if self.incoming.len() > 2 * PACKET_SIZE {
+                        self.disconnect("packet too long");
+                    }
+                    return;
+                }
+            };
+
+            let packet: Vec<u8> = self.incoming[start + 1..end].to_vec();
+            let sent = str::from_utf8(&self.incoming[end + 1..end + 3])
+                .ok()
+                .and_then(|cs| u8::from_str_radix(cs, 16).ok());
+            self.incoming.drain(..end + 3);
+            if sent == Some(checksum(&packet)) {
+                self.send(b"+");
+                self.handle_packet(cpu, &packet);
+            } else {
+                self.send(b"-");
+            }
+            if !self.connected {
+                return;
+            }
+        }
+    }
+
+    /// Handles acknowledgements and interrupts before `end`
+    fn handle_outside_packets(&mut self, end: usize) {
+        for i in 0..end {
+            match self.incoming[i] {
+                b'-' => {
+                    let packet = self.last_packet.clone();
+                    self.send(packet.as_bytes());
+                }
+                INTERRUPT if self.running => self.stop(format!("S{:02x}", SIGINT)),
+                _ => (),
+            }
+        }
+    }
+
+    fn handle_packet(&mut self, cpu: &mut Cpu, packet: &[u8]) {
+        let (command, args) = match packet.split_first() {
+            Some((&command, args)) => (command, args),
+            None => return self.send_packet(""),
+        };
+        let reply = match command {
+            b'?' => Some(self.last_stop.clone()),
+            b'g' => Some(REGISTERS.iter().map(|&reg| encode_register(cpu, reg)).collect()),
+            b'G' => write_registers(cpu, args),
+            b'p' => {
+                parse_hex(args)
+                    .and_then(|n| REGISTERS.get(n as usize))
+                    .map(|&reg| encode_register(cpu, reg))
+            }
+            b'P' => write_register(cpu, args),
+            b'm' => read_memory(cpu, args),
+            b'M' => {
+                split_data(args).and_then(|(addr, len, data)| {
+                    decode_hex(data).and_then(|data| write_memory(cpu, addr, len, &data))
+                })
+            }
+            b'X' => {
+                split_data(args)
+                    .and_then(|(addr, len, data)| write_memory(cpu, addr, len, &unescape(data)))
+            }
+            b'Z' | b'z' => self.set_point(cpu, command == b'Z', args),
+            b's' => {
+                if !args.is_empty() {
+                    match parse_hex(args) {
+                        Some(addr) => cpu.pc = addr as MemAddr,
+                        None => return self.send_packet("E01"),
+                    }
+                }
+                let reply = self.step(cpu);
+                self.last_stop = reply.clone();
+                Some(reply)
+            }
+            b'c' => {
+                if !args.is_empty() {
+                    match parse_hex(args) {
+                        Some(addr) => cpu.pc = addr as MemAddr,
+                        None => return self.send_packet("E01"),
+                    }
+                }
+                // Only what happens from now on stops the game
+                cpu.take_watchpoint_hit();
+                self.running = true;
+                self.last_poll = cpu.cycles;
+                return;
+            }
+            b'D' => {
+                self.send_packet("OK");
+                info!("GDB detached");
+                self.connected = false;
+                return;
+            }
+            b'k' => {
+                self.killed = true;
+                self.connected = false;
+                return;
+            }
+            b'q' => Some(self.query(args)),
+            b'H' => Some("OK".to_string()),
+            _ => Some(String::new()),
+        };
+        let reply = reply.unwrap_or_else(|| "E01".to_string());
+        self.send_packet(&reply);
+    }
+
+    /// Runs a single instruction and returns the stop reply
+    fn step(&mut self, cpu: &mut Cpu) -> String {
+        cpu.take_watchpoint_hit();
+        if cpu.state != CpuState::Crashed {
+            cpu.dispatch_opcode();
+        }
+        self.stop_reason(cpu).unwrap_or_else(|| format!("S{:02x}", SIGTRAP))
+    }
+
+    fn query(&mut self, args: &[u8]) -> String {
+        let query = String::from_utf8_lossy(args);
+        if query.starts_with("Supported") {
+            format!("PacketSize={:x};qXfer:features:read+", PACKET_SIZE)
+        } else if query.starts_with("Xfer:features:read:target.xml:") {
+            let range = query["Xfer:features:read:target.xml:".len()..].as_bytes();
+            match split_range(range) {
+                Some((offset, length)) => {
+                    let offset = cmp::min(offset as usize, TARGET_XML.len());
+                    let end = cmp::min(offset + length as usize, TARGET_XML.len());
+                    let more = if end < TARGET_XML.len() { "m" } else { "l" };
+                    format!("{}{}", more, &TARGET_XML[offset..end])
+                }
+                None => "E01".to_string(),
+            }
+        } else if query.starts_with("Xfer:features:read:") {
+            "E00".to_string()
+        } else if query.starts_with("Attached") {
+            "1".to_string()
+        } else {
+            String::new()
+        }
+    }
+
+    /// Handles `Z` and `z` packets
+    fn set_point(&mut self, cpu: &mut Cpu, insert: bool, args: &[u8]) -> Option<String> {
+        // Conditions evaluated by the target aren't supported
+        let args = args.split(|&b| b == b';').next().unwrap_or(args);
+        let mut fields = args.splitn(2, |&b| b == b',');
+        let kind = match fields.next().and_then(parse_hex) {
+            Some(0) | Some(1) => None,
+            Some(2) => Some(WatchKind::Write),
+            Some(3) => Some(WatchKind::Read),
+            Some(4) => Some(WatchKind::Access),
+            _ => return Some(String::new()),
+        };
+        let (addr, len) = match fields.next().and_then(split_range) {
+            Some((addr, len)) if addr <= 0xFFFF => (addr as MemAddr, len),
+            _ => return None,
+        };
+
+        match kind {
+            None if insert => {
+                self.breakpoints.insert(addr);
+            }
+            None => {
+                self.breakpoints.remove(&addr);
+            }
+            Some(kind) => {
+                if len == 0 {
+                    return None;
+                }
+                let end = cmp::min(addr as u32 + len - 1, 0xFFFF) as MemAddr;
+                if insert {
+                    for &mode in kind.modes() {
+                        cpu.add_watchpoint(Watchpoint {
+                            start: addr,
+                            end: end,
+                            mode: mode,
+                        });
+                    }
+                    self.watchpoints.push((kind, addr, end));
+                } else if let Some(i) = self.watchpoints
+                    .iter()
+                    .position(|&wp| wp == (kind, addr, end)) {
+                    self.watchpoints.remove(i);
+                    remove_watchpoint(cpu, kind, addr, end);
+                }
+            }
+        }
+        Some("OK".to_string())
+    }
+}
+
+/// Removes one of the CPU's watchpoints for each mode of `kind`
+fn remove_watchpoint(cpu: &mut Cpu, kind: WatchKind, start: MemAddr, end: MemAddr) {
+    for &mode in kind.modes() {
+        let watchpoint = Watchpoint {
+            start: start,
+            end: end,
+            mode: mode,
+        };
+        if let Some(i) = cpu.watchpoints.list.iter().position(|wp| *wp == watchpoint) {
+            cpu.watchpoints.list.remove(i);
+        }
+    }
+}
+
+fn checksum(data: &[u8]) -> u8 {
+    data.iter().fold(0u8, |sum, &b| sum.wrapping_add(b))
+}
+
+fn parse_hex(digits: &[u8]) -> Option<u32> {
+    str::from_utf8(digits).ok().and_then(|digits| u32::from_str_radix(digits, 16).ok())
+}
+
+fn decode_hex(digits: &[u8]) -> Option<Vec<u8>> {
+    if digits.len() % 2 != 0 {
+        return None;
+    }
+    digits.chunks(2).map(|pair| parse_hex(pair).map(|b| b as u8)).collect()
+}
+
+/// Binary data escapes `#`, `$`, `}` and `*` as `}` and the byte xor 0x20
+fn unescape(data: &[u8]) -> Vec<u8> {
+    let mut bytes = Vec::with_capacity(data.len());
+    let mut escaped = false;
+    for &b in data {
+        if escaped {
+            bytes.push(b ^ 0x20);
+            escaped = false;
+        } else if b == b'}' {
+            escaped = true;
+        } else {
+            bytes.push(b);
+        }
+    }
+    bytes
+}
+
+/// Parses `addr,length`
+fn split_range(args: &[u8]) -> Option<(u32, u32)> {
+    let mut fields = args.splitn(2, |&b| b == b',');
+    match (fields.next().and_then(parse_hex), fields.next().and_then(parse_hex)) {
+        (Some(addr), Some(len)) => Some((addr, len)),
+        _ => None,
+    }
+}
+
+/// Parses `addr,length:data`
+fn split_data(args: &[u8]) -> Option<(u32, u32, &[u8])> {
+    let colon = match args.iter().position(|&b| b == b':') {
+        Some(colon) => colon,
+        None => return None,
+    };
+    split_range(&args[..colon]).map(|(addr, len)| (addr, len, &args[colon + 1..]))
+}
+
+fn encode_register(cpu: &Cpu, reg: Option<CpuRegister16>) -> String {
+    let value = match reg {
+        Some(reg) => cpu.access_register16(reg),
+        None => cpu.pc,
+    };
+    format!("{:02x}{:02x}", value & 0xFF, value >> 8)
+}
+
+fn set_register(cpu: &mut Cpu, reg: Option<CpuRegister16>, value: &[u8]) {
+    let value = value[0] as u16 | (value[1] as u16) << 8;
+    match reg {
+        Some(reg) => cpu.set_register16(reg, value),
+        None => cpu.pc = value,
+    }
+}
+
+fn write_registers(cpu: &mut Cpu, args: &[u8]) -> Option<String> {
+    let values = match decode_hex(args) {
+        Some(values) => values,
+        None => return None,
+    };
+    if values.len() != 2 * REGISTERS.len() {
+        return None;
+    }
+    for (&reg, value) in REGISTERS.iter().zip(values.chunks(2)) {
+        set_register(cpu, reg, value);
+    }
+    Some("OK".to_string())
+}
+
+/// Handles `P n=value`
+fn write_register(cpu: &mut Cpu, args: &[u8]) -> Option<String> {
+    let mut fields = args.splitn(2, |&b| b == b'=');
+    let reg = fields.next().and_then(parse_hex).and_then(|n| REGISTERS.get(n as usize));
+    match (reg, fields.next().and_then(decode_hex)) {
+        (Some(&reg), Some(ref value)) if value.len() == 2 => {
+            set_register(cpu, reg, value);
+            Some("OK".to_string())
+        }
+        _ => None,
+    }
+}
+
+/// Reads memory without side effects on the hardware or the event log
+fn read_memory(cpu: &mut Cpu, args: &[u8]) -> Option<String> {
+    let (addr, len) = match split_range(args) {
+        Some((addr, len)) if (len as usize) <= PACKET_SIZE / 2 => (addr, len),
+        _ => return None,
+    };
+    let logger = cpu.event_logger.take();
+    let bytes: Vec<String> = (0..len)
+        .map(|i| format!("{:02x}", cpu.peek(addr.wrapping_add(i) as MemAddr)))
+        .collect();
+    cpu.event_logger = logger;
+    Some(bytes.concat())
+}
+
+/// Writes memory like the CPU, but without taking time
+fn write_memory(cpu: &mut Cpu, addr: u32, len: u32, data: &[u8]) -> Option<String> {
+    if data.len() != len as usize {
+        return None;
+    }
+    let logger = cpu.event_logger.take();
+    for (i, &value) in data.iter().enumerate() {
+        cpu.write_mem(addr.wrapping_add(i as u32) as MemAddr, value);
+    }
+    cpu.event_logger = logger;
+    Some("OK".to_string())
+}
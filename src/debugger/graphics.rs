@@ -1,11 +1,17 @@
 use ncurses::*;
-// use std::collections::HashMap;
 use super::language::*;
 use super::dbglanguage;
+use super::history::*;
+use super::symbols::SymbolTable;
 use cpu::*;
+use cpu::cartridge::{EXTERNAL_RAM_START, EXTERNAL_RAM_END};
 use cpu::constants::*;
+use cpu::memory::*;
+use cpu::watchpoint::*;
 use super::super::disasm::*;
-use std::collections::BTreeSet;
+use std::collections::BTreeMap;
+use std::io;
+use std::path::Path;
 
 const WIN_Y_DIV: i32 = 5;
 const WIN_Y_ADJ: i32 = 2;
@@ -37,7 +43,7 @@ enum DebuggerState {
 
 /// Handles data related to the TUI debugger
 pub struct Debugger {
-    //   symbol_table: HashMap<&'str, Expression>,
+    symbol_table: SymbolTable,
     asm_win: WINDOW,
     reg_win: WINDOW,
     in_win: WINDOW,
@@ -45,10 +51,11 @@ pub struct Debugger {
     input_buffer: String,
     output_buffer: Vec<String>,
     debugger_state: DebuggerState,
-    watchpoints: BTreeSet<u16>,
-    breakpoints: BTreeSet<u16>,
+    /// Breakpoints with the condition under which they stop execution
+    breakpoints: BTreeMap<u16, Option<Condition>>,
     history_location: usize, // used for scrolling back in history
     run_to_point: Option<u16>,
+    history: History,
 }
 
 impl Debugger {
@@ -65,13 +72,10 @@ impl Debugger {
 
         getmaxyx(stdscr(), &mut max_y, &mut max_x);
 
-        let mut romcp = [0u8; 0x8000];
-        for i in 0..0x7FFF {
-            romcp[i] = cpu.mem[i] as u8;
-        }
-
+        let symbol_table = SymbolTable::new();
         let dbg = Debugger {
-            //         symbol_table: HashMap::new(),
+            dissassembled_rom: disassemble(cpu, &symbol_table),
+            symbol_table: symbol_table,
             asm_win: create_win((max_y / WIN_Y_DIV) * WIN_Y_ADJ,
                                 (max_x / WIN_X_DIV) * WIN_X_ADJ,
                                 0,
@@ -81,14 +85,13 @@ impl Debugger {
                                max_x,
                                (max_y / WIN_Y_DIV) * WIN_Y_ADJ,
                                0),
-            dissassembled_rom: disasm_rom_to_vec(romcp, 0x7FF0),
             input_buffer: String::new(),
             output_buffer: vec![String::new(), String::new()], // to make history nicer
             debugger_state: DebuggerState::Paused,
-            breakpoints: BTreeSet::new(),
-            watchpoints: BTreeSet::new(),
+            breakpoints: BTreeMap::new(),
             history_location: 0,
             run_to_point: None,
+            history: History::new(),
         };
 
         refresh();
@@ -156,10 +159,18 @@ impl Debugger {
 
                 // do parsing
 
+                // Symbols are only tried when the input doesn't parse
+                // as it is, so they can't hide commands
                 #[cfg(feature = "debugger")]
                 let parseret = match dbglanguage::parse_Input(self.input_buffer.as_ref()) {
                     Ok(v) => Ok(v),
-                    Err(e) => Err(format!("{:?}", e)),
+                    Err(e) => {
+                        let resolved = self.symbol_table.substitute(&self.input_buffer);
+                        match dbglanguage::parse_Input(resolved.as_ref()) {
+                            Ok(v) => Ok(v),
+                            Err(_) => Err(format!("{:?}", e)),
+                        }
+                    }
                 };
 
                 #[cfg(not(feature = "debugger"))]
@@ -296,16 +307,13 @@ impl Debugger {
         let mut x = 0;
         let mut y = 0;
         getmaxyx(self.reg_win, &mut y, &mut x);
-        let watchpoints: Vec<u16> = self.watchpoints.iter().cloned().collect();
+        let watchpoints = cpu.watchpoints.list.clone();
 
 
-        for i in WATCHPOINT_Y_OFFSET..(WATCHPOINT_Y_OFFSET + (self.watchpoints.len() as i32)) {
-            wmove(self.reg_win, i, 1);
+        for (i, wp) in watchpoints.iter().enumerate() {
+            wmove(self.reg_win, WATCHPOINT_Y_OFFSET + (i as i32), 1);
             wprintw(self.reg_win,
-                    format!("({:X}): {:X}",
-                            watchpoints[(i - WATCHPOINT_Y_OFFSET) as usize],
-                            cpu.mem[(watchpoints[(i - WATCHPOINT_Y_OFFSET) as usize]) as usize])
-                        .as_ref());
+                    format!("({:X}): {:X}", wp.start, cpu.peek(wp.start)).as_ref());
         }
 
     }
@@ -348,17 +356,19 @@ impl Debugger {
         // Stack starts at 0xFFFE
         let cur_stack_ptr = 0xFFFE - effective_stack_frames;
 
+        let rom_bank = cpu.cartridge.rom_bank_x();
         for (i, addr) in (cur_stack_ptr..0xFFFF)
             .filter(|&n| n % 2 == 0)
             .enumerate() {
             // i+1 because enumerate starts at 0
             wmove(self.reg_win, (i as i32) + 1, STACK_X_POS_OFFSET);
-            wprintw(self.reg_win,
-                    format!("({:X}): {:02X}{:02X}",
-                            addr,
-                            cpu.mem[(addr + 1) as usize],
-                            cpu.mem[addr as usize])
-                        .as_ref());
+            let value = stack_word(cpu, addr as u16);
+            let name = if is_return_address(cpu, value) {
+                self.symbol_table.describe(value, rom_bank).unwrap_or_default()
+            } else {
+                String::new()
+            };
+            wprintw(self.reg_win, format!("({:X}): {:04X} {}", addr, value, name).as_ref());
         }
     }
 
@@ -388,6 +398,15 @@ impl Debugger {
         wmove(self.reg_win, 5, 13);
         wprintw(self.reg_win,
                 format!("{:2}: 0x{:04X}", "PC", cpu.pc).as_ref());
+
+        // OAM DMA blocks most of memory while it runs, shown as the
+        // source page and the number of bytes copied so far
+        wmove(self.reg_win, 6, 13);
+        let dma = match cpu.dma.progress() {
+            Some(copied) => format!("DMA: {:02X}+{:02X}", cpu.dma.source >> 8, copied),
+            None => "DMA: off".to_string(),
+        };
+        wprintw(self.reg_win, dma.as_ref());
     }
 
     fn dispatch_debugger_action(&mut self, cpu: &mut Cpu, da: DebuggerAction) -> String {
@@ -395,6 +414,7 @@ impl Debugger {
             DebuggerAction::Echo { str: s } => s,
             DebuggerAction::Reset => {
                 cpu.reset();
+                self.clear_history();
                 "CPU resetting".to_string()
             }
             DebuggerAction::Run => {
@@ -402,24 +422,41 @@ impl Debugger {
                 "Running...".to_string()
             }
             DebuggerAction::Step => {
+                self.history.record(cpu);
                 cpu.dispatch_opcode();
-                "Stepping...".to_string()
+                match cpu.take_watchpoint_hit() {
+                    Some(hit) => format!("Stepping... watchpoint: {}", hit),
+                    None => "Stepping...".to_string(),
+                }
             }
-            DebuggerAction::WatchPoint { addr } => {
-                self.watchpoints.insert(addr);
-                format!("Watchpoint set at 0x{:X}", addr)
+            DebuggerAction::WatchPoint { start, end, mode } => {
+                if end < start {
+                    return format!("Invalid range 0x{:X}..0x{:X}", start, end);
+                }
+                let wp = Watchpoint {
+                    start: start,
+                    end: end,
+                    mode: mode,
+                };
+                cpu.add_watchpoint(wp);
+                format!("Watchpoint set on {}", wp)
             }
-            DebuggerAction::UnwatchPoint { addr } => {
-                self.watchpoints.remove(&addr);
-                format!("Removing watchpoint at 0x{:X}", addr)
+            DebuggerAction::UnwatchPoint { start, end } => {
+                let removed = cpu.remove_watchpoints(start, end);
+                format!("Removed {} watchpoint(s) at 0x{:X}..0x{:X}", removed, start, end)
             }
-            DebuggerAction::SetBreakPoint { addr } => {
+            DebuggerAction::SetBreakPoint { addr, condition } => {
                 let ar_max = self.dissassembled_rom.len() - 1;
                 let bp = binsearch_inst(&self.dissassembled_rom, addr, 0, ar_max as usize);
 
                 if let Some(inst) = bp {
-                    self.breakpoints.insert(addr);
-                    format!("Setting breakpoint at 0x{:X} ({})", addr, inst)
+                    let location = format!("0x{:X}{} ({})", addr, self.name_of(cpu, addr), inst);
+                    let msg = match condition {
+                        Some(ref c) => format!("Setting breakpoint at {} if {}", location, c),
+                        None => format!("Setting breakpoint at {}", location),
+                    };
+                    self.breakpoints.insert(addr, condition);
+                    msg
                 } else {
                     format!("Cannot break at invalid address 0x{:X}", addr)
                 }
@@ -435,14 +472,113 @@ impl Debugger {
             DebuggerAction::Show { show } => {
                 match show {
                     ShowableThing::Address { addr } => {
-                        format!("(0x{:X}) = 0x{:X}", addr, cpu.mem[addr as usize])
+                        format!("(0x{:X}{}) = 0x{:X}",
+                                addr,
+                                self.name_of(cpu, addr),
+                                cpu.peek(addr))
+                    }
+                    ShowableThing::Breakpoints => {
+                        let bps: Vec<String> = self.breakpoints
+                            .iter()
+                            .map(|(&addr, condition)| {
+                                let name = self.name_of(cpu, addr);
+                                match *condition {
+                                    Some(ref c) => format!("0x{:X}{} if {}", addr, name, c),
+                                    None => format!("0x{:X}{}", addr, name),
+                                }
+                            })
+                            .collect();
+                        format!("Breakpoints: {}", bps.join(", "))
+                    }
+                    ShowableThing::Watchpoints => {
+                        let wps: Vec<String> =
+                            cpu.watchpoints.list.iter().map(|wp| format!("{}", wp)).collect();
+                        format!("Watchpoints: {}", wps.join(", "))
+                    }
+                    ShowableThing::Stack => {
+                        let frames: Vec<String> = Some(cpu.pc)
+                            .into_iter()
+                            .chain(call_stack(cpu))
+                            .map(|addr| format!("0x{:04X}{}", addr, self.name_of(cpu, addr)))
+                            .collect();
+                        format!("Call stack: {}", frames.join(" <- "))
+                    }
+                    ShowableThing::Cycles => {
+                        match self.history.oldest_cycle() {
+                            Some(oldest) => {
+                                format!("Cycle {}, history from cycle {}", cpu.cycles, oldest)
+                            }
+                            None => format!("Cycle {}, no history", cpu.cycles),
+                        }
                     }
-                    ShowableThing::Breakpoints => format!("Breakpoints: {:?}", self.breakpoints),
                 }
             }
+            DebuggerAction::ReverseStep => {
+                let result = self.history.reverse_step(cpu);
+                self.travelled(cpu, result)
+            }
+            DebuggerAction::ReverseContinue => {
+                let result = self.history.reverse_continue(cpu, |cpu| {
+                    // Always take the hit so it isn't seen at a later boundary
+                    let watchpoint = cpu.take_watchpoint_hit().is_some();
+                    watchpoint || self.at_breakpoint(cpu)
+                });
+                match result {
+                    Ok(None) => {
+                        let result = Ok(cpu.cycles);
+                        format!("Reached the start of the history. {}",
+                                self.travelled(cpu, result))
+                    }
+                    Ok(Some(cycle)) => self.travelled(cpu, Ok(cycle)),
+                    Err(e) => self.travelled(cpu, Err(e)),
+                }
+            }
+            DebuggerAction::GotoCycle { cycle } => {
+                let result = self.history.goto_cycle(cpu, cycle);
+                self.travelled(cpu, result)
+            }
+        }
+    }
+
+    /// Reads a symbol file, replacing the symbols loaded before.
+    /// Returns how many symbols it has.
+    pub fn load_symbols(&mut self, cpu: &Cpu, path: &Path) -> io::Result<usize> {
+        self.symbol_table = SymbolTable::load(path)?;
+        self.dissassembled_rom = disassemble(cpu, &self.symbol_table);
+        Ok(self.symbol_table.len())
+    }
+
+    /// Forgets the recorded past, which doesn't lead to the CPU's state
+    /// any more once it was replaced by a reset or a loaded state
+    pub fn clear_history(&mut self) {
+        self.history.clear();
+    }
+
+    /// ` (label+0xN)` for an address near a symbol, empty otherwise
+    fn name_of(&self, cpu: &Cpu, addr: u16) -> String {
+        match self.symbol_table.describe(addr, cpu.cartridge.rom_bank_x()) {
+            Some(name) => format!(" ({})", name),
+            None => String::new(),
+        }
+    }
+
+    /// Describes where a command moving through the history ended up
+    fn travelled(&mut self, cpu: &mut Cpu, result: Result<u64, HistoryError>) -> String {
+        // Replaying doesn't trigger watchpoints
+        cpu.take_watchpoint_hit();
+        match result {
+            Ok(cycle) => format!("At cycle {}, PC 0x{:04X}", cycle, cpu.pc),
+            Err(e) => format!("Cannot go back: {}", e),
         }
     }
 
+    /// Shows a message which isn't the result of a command.  An empty
+    /// input line keeps the history in input/output pairs.
+    fn report(&mut self, msg: String) {
+        self.output_buffer.push(String::new());
+        self.output_buffer.push(msg);
+    }
+
     fn reset_history_location(&mut self) {
         self.history_location = 0;
     }
@@ -477,6 +613,39 @@ impl Debugger {
         self.debugger_state == DebuggerState::Running
     }
 
+    /// Whether `cpu` is at a breakpoint whose condition holds
+    pub fn at_breakpoint(&self, cpu: &Cpu) -> bool {
+        match self.breakpoints.get(&cpu.pc) {
+            Some(&Some(ref condition)) => condition.holds(cpu),
+            Some(&None) => true,
+            None => false,
+        }
+    }
+
+    /// Must be called before each instruction run while the debugger
+    /// isn't paused, for going back in time
+    pub fn record(&mut self, cpu: &Cpu) {
+        self.history.record(cpu);
+    }
+
+    /// Pauses when a watchpoint triggers, a breakpoint or the address
+    /// to run to is reached.  Should be called after every instruction
+    /// while running.
+    pub fn check_breakpoints(&mut self, cpu: &mut Cpu) {
+        if !self.should_run() {
+            return;
+        }
+        if let Some(hit) = cpu.take_watchpoint_hit() {
+            self.pause();
+            self.report(format!("Watchpoint: {}", hit));
+        } else if self.at_breakpoint(cpu) {
+            self.pause();
+        } else if self.run_to_point == Some(cpu.pc) {
+            self.pause();
+            self.run_to_point = None;
+        }
+    }
+
     // pub fn run(&mut self) {
     // let start_time = std::time::Instant::now();
     //
@@ -530,3 +699,68 @@ fn create_win(height: i32, width: i32, start_y: i32, start_x: i32) -> WINDOW {
     wrefresh(win);
     win
 }
+
+/// Disassembles the ROM mapped right now, naming addresses after
+/// their symbols
+fn disassemble(cpu: &Cpu, symbols: &SymbolTable) -> Vec<(String, u16)> {
+    let mut romcp = [0u8; 0x8000];
+    for i in 0..0x7FFF {
+        romcp[i] = cpu.peek(i as MemAddr) as u8;
+    }
+    let rom_bank = cpu.cartridge.rom_bank_x();
+
+    disasm_rom_to_vec(romcp, 0x7FF0)
+        .into_iter()
+        .map(|(line, addr)| {
+            let line = symbols.annotate(&line, rom_bank);
+            match symbols.name_at(addr, rom_bank) {
+                // Lines start with the address, e.g. `0x0150\tNOP`
+                Some(name) => (format!("{} {}:{}", &line[..6], name, &line[6..]), addr),
+                None => (line, addr),
+            }
+        })
+        .collect()
+}
+
+fn stack_word(cpu: &Cpu, addr: u16) -> u16 {
+    (cpu.peek(addr.wrapping_add(1)) as u16) << 8 | cpu.peek(addr) as u16
+}
+
+/// Whether `addr` follows a CALL or RST, as return addresses do.
+/// `RST 0x38` is left out, its 0xFF is everywhere from ROM padding to
+/// IE just before 0x0000.
+fn is_return_address(cpu: &Cpu, addr: u16) -> bool {
+    let call = cpu.peek(addr.wrapping_sub(3));
+    let rst = cpu.peek(addr.wrapping_sub(1));
+    match call {
+        0xCD | 0xC4 | 0xCC | 0xD4 | 0xDC => true,
+        _ => rst & 0xC7 == 0xC7 && rst != 0xFF,
+    }
+}
+
+/// Return addresses on the stack from the innermost call out.  The
+/// stack holds other data too, so these are the words which could be
+/// return addresses rather than the exact calls made.
+pub fn call_stack(cpu: &Cpu) -> Vec<u16> {
+    let sp = cpu.access_register16(CpuRegister16::SP);
+    (sp as u32..stack_end(sp) - 1)
+        .filter(|&addr| (addr - sp as u32) % 2 == 0)
+        .map(|addr| stack_word(cpu, addr as u16))
+        .filter(|&value| is_return_address(cpu, value))
+        .collect()
+}
+
+/// One past the end of the RAM `sp` points into, as the stack can't
+/// grow across it
+fn stack_end(sp: u16) -> u32 {
+    let end = match sp as usize {
+        DISPLAY_RAM_START...DISPLAY_RAM_END => DISPLAY_RAM_END,
+        EXTERNAL_RAM_START...EXTERNAL_RAM_END => EXTERNAL_RAM_END,
+        WRAM_START...WRAM_END => WRAM_END,
+        ECHO_RAM_START...ECHO_RAM_END => ECHO_RAM_END,
+        HRAM_START...HRAM_END => HRAM_END,
+        // Not a stack
+        _ => return sp as u32 + 1,
+    };
+    end as u32 + 1
+}
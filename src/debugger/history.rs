@@ -0,0 +1,228 @@
+//! Reverse execution
+//!
+//! While the CPU runs forward the history keeps a snapshot of it every
+//! `SNAPSHOT_INTERVAL` cycles and logs every change of the input state
+//! together with the cycle it happened at.  An earlier cycle is reached
+//! by restoring the last snapshot before it and running forward again,
+//! feeding in the logged input at the same cycles, which repeats
+//! exactly what happened before.  The real time clock of MBC3
+//! cartridges isn't replayed, and the link cable is unplugged and the
+//! sound muted while running through the past, so the other side of
+//! the cable and the speakers don't get it a second time.
+//!
+//! Running forward from a point in the past starts a new timeline and
+//! throws away everything recorded after it.
+
+use std::collections::VecDeque;
+use std::fmt;
+
+use cpu::{Cpu, CycleCount};
+use cpu::constants::*;
+use io::constants::CPU_CYCLES_PER_VBLANK;
+
+/// Cycles between snapshots, a quarter of a second
+pub const SNAPSHOT_INTERVAL: CycleCount = CPU_CYCLES_PER_VBLANK * 15;
+/// Snapshots kept, enough to go back a minute
+pub const MAX_SNAPSHOTS: usize = 240;
+
+#[derive(Debug, PartialEq)]
+pub enum HistoryError {
+    /// Nothing was recorded before the current cycle
+    Empty,
+    /// The cycle is older than the oldest snapshot, which is given
+    TooOld(CycleCount),
+}
+
+impl fmt::Display for HistoryError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match *self {
+            HistoryError::Empty => write!(f, "no history recorded"),
+            HistoryError::TooOld(oldest) => {
+                write!(f, "history only goes back to cycle {}", oldest)
+            }
+        }
+    }
+}
+
+pub struct History {
+    /// Oldest first, taken at instruction boundaries
+    snapshots: VecDeque<Cpu>,
+    /// Changes of the input state as (cycle, state), oldest first
+    inputs: VecDeque<(CycleCount, byte)>,
+    interval: CycleCount,
+    max_snapshots: usize,
+}
+
+impl History {
+    pub fn new() -> History {
+        History::with_interval(SNAPSHOT_INTERVAL, MAX_SNAPSHOTS)
+    }
+
+    pub fn with_interval(interval: CycleCount, max_snapshots: usize) -> History {
+        History {
+            snapshots: VecDeque::new(),
+            inputs: VecDeque::new(),
+            interval: interval,
+            max_snapshots: max_snapshots,
+        }
+    }
+
+    pub fn clear(&mut self) {
+        self.snapshots.clear();
+        self.inputs.clear();
+    }
+
+    /// The first cycle which can be gone back to
+    pub fn oldest_cycle(&self) -> Option<CycleCount> {
+        self.snapshots.front().map(|s| s.cycles)
+    }
+
+    /// Must be called before each instruction run forward, after the
+    /// input for it was set
+    pub fn record(&mut self, cpu: &Cpu) {
+        while self.snapshots.back().map_or(false, |s| s.cycles > cpu.cycles) {
+            self.snapshots.pop_back();
+        }
+        while self.inputs.back().map_or(false, |&(cycle, _)| cycle > cpu.cycles) {
+            self.inputs.pop_back();
+        }
+
+        let logged_input = match self.inputs.back() {
+            Some(&(_, input)) => Some(input),
+            None => self.snapshots.back().map(|s| s.input_state()),
+        };
+        if logged_input.map_or(false, |input| input != cpu.input_state()) {
+            self.inputs.push_back((cpu.cycles, cpu.input_state()));
+        }
+
+        let due = self.snapshots.back().map_or(true, |s| cpu.cycles >= s.cycles + self.interval);
+        if due {
+            let mut snapshot = cpu.clone();
+            snapshot.event_logger = None;
+            snapshot.take_watchpoint_hit();
+            snapshot.apu.take_samples();
+            self.snapshots.push_back(snapshot);
+            if self.snapshots.len() > self.max_snapshots {
+                self.snapshots.pop_front();
+            }
+            // Older input is part of the oldest snapshot
+            let oldest = self.snapshots[0].cycles;
+            while self.inputs.front().map_or(false, |&(cycle, _)| cycle <= oldest) {
+                self.inputs.pop_front();
+            }
+        }
+    }
+
+    /// Goes to the first instruction boundary at or after `target`,
+    /// returning its cycle
+    pub fn goto_cycle(&self, cpu: &mut Cpu, target: CycleCount) -> Result<CycleCount, HistoryError> {
+        if target < cpu.cycles {
+            let index = match self.snapshot_before(target + 1) {
+                Some(index) => index,
+                None => return Err(self.too_old()),
+            };
+            self.restore(cpu, index);
+        }
+        self.run(cpu, target, target, &mut |_| false);
+        Ok(cpu.cycles)
+    }
+
+    /// Goes back to the start of the instruction before the current one
+    pub fn reverse_step(&self, cpu: &mut Cpu) -> Result<CycleCount, HistoryError> {
+        let now = cpu.cycles;
+        let index = match self.snapshot_before(now) {
+            Some(index) => index,
+            None => return Err(self.too_old()),
+        };
+        self.restore(cpu, index);
+        let previous = self.run(cpu, now, now, &mut |_| true)
+            .expect("the snapshot is an instruction boundary");
+        self.goto_cycle(cpu, previous)
+    }
+
+    /// Goes back to the last instruction boundary where `stop` returns
+    /// true, or to the oldest snapshot if there is none.  `stop` is
+    /// called at each boundary, after the instruction leading to it.
+    /// Returns the cycle stopped at if `stop` was true there.
+    pub fn reverse_continue<F>(&self,
+                               cpu: &mut Cpu,
+                               mut stop: F)
+                               -> Result<Option<CycleCount>, HistoryError>
+        where F: FnMut(&mut Cpu) -> bool
+    {
+        let now = cpu.cycles;
+        let latest = match self.snapshot_before(now) {
+            Some(index) => index,
+            None => return Err(self.too_old()),
+        };
+        for index in (0..latest + 1).rev() {
+            let end = match self.snapshots.get(index + 1) {
+                Some(next) if next.cycles < now => next.cycles,
+                _ => now,
+            };
+            self.restore(cpu, index);
+            if let Some(cycle) = self.run(cpu, end, now, &mut stop) {
+                return self.goto_cycle(cpu, cycle).map(Some);
+            }
+        }
+        self.restore(cpu, 0);
+        Ok(None)
+    }
+
+    /// The last snapshot taken before `cycle`
+    fn snapshot_before(&self, cycle: CycleCount) -> Option<usize> {
+        self.snapshots.iter().rposition(|s| s.cycles < cycle)
+    }
+
+    fn too_old(&self) -> HistoryError {
+        match self.oldest_cycle() {
+            Some(oldest) => HistoryError::TooOld(oldest),
+            None => HistoryError::Empty,
+        }
+    }
+
+    /// Replaces `cpu` with a snapshot, keeping what belongs to the
+    /// debugger and the memory visualization
+    fn restore(&self, cpu: &mut Cpu, index: usize) {
+        let mut snapshot = self.snapshots[index].clone();
+        snapshot.event_logger = cpu.event_logger.take();
+        snapshot.watchpoints.list = cpu.watchpoints.list.clone();
+        *cpu = snapshot;
+    }
+
+    /// Runs `cpu` with the logged input up to the first instruction
+    /// boundary at or after `end`.  Returns the last boundary before
+    /// `before` where `stop` returned true.
+    fn run(&self,
+           cpu: &mut Cpu,
+           end: CycleCount,
+           before: CycleCount,
+           stop: &mut FnMut(&mut Cpu) -> bool)
+           -> Option<CycleCount> {
+        let link = cpu.unplug_serial();
+        cpu.apu.set_muted(true);
+
+        let mut next_input = self.inputs
+            .iter()
+            .position(|&(cycle, _)| cycle > cpu.cycles)
+            .unwrap_or(self.inputs.len());
+        let mut found = None;
+        loop {
+            while next_input < self.inputs.len() && self.inputs[next_input].0 <= cpu.cycles {
+                cpu.set_input_state(self.inputs[next_input].1);
+                next_input += 1;
+            }
+            if cpu.cycles < before && stop(cpu) {
+                found = Some(cpu.cycles);
+            }
+            if cpu.cycles >= end || cpu.state == CpuState::Crashed {
+                break;
+            }
+            cpu.dispatch_opcode();
+        }
+
+        cpu.apu.set_muted(false);
+        cpu.replug_serial(link);
+        found
+    }
+}
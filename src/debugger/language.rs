@@ -1,15 +1,23 @@
+use std::fmt;
+use cpu::Cpu;
+use cpu::constants::*;
+use cpu::watchpoint::WatchMode;
 
 #[derive(Debug, PartialEq)]
 pub enum ShowableThing {
     Address { addr: u16 },
     Breakpoints,
+    Watchpoints,
+    Cycles,
+    /// Return addresses of the calls being made
+    Stack,
 }
 
 #[derive(Debug, PartialEq)]
 pub enum DebuggerAction {
-    WatchPoint { addr: u16 },
-    UnwatchPoint { addr: u16 },
-    SetBreakPoint { addr: u16 },
+    WatchPoint { start: u16, end: u16, mode: WatchMode },
+    UnwatchPoint { start: u16, end: u16 },
+    SetBreakPoint { addr: u16, condition: Option<Condition> },
     UnsetBreakPoint { addr: u16 },
     Show { show: ShowableThing },
     Step,
@@ -17,4 +25,228 @@ pub enum DebuggerAction {
     Reset,
     Echo { str: String },
     RunToAddress { addr: u16 },
+    ReverseStep,
+    ReverseContinue,
+    GotoCycle { cycle: u64 },
+}
+
+/// Registers which can be used in conditions
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum Register {
+    A,
+    B,
+    C,
+    D,
+    E,
+    F,
+    H,
+    L,
+    AF,
+    BC,
+    DE,
+    HL,
+    SP,
+    PC,
+}
+
+/// Flags of the F register, read as 0 or 1
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum Flag {
+    Zero,
+    Subtract,
+    HalfCarry,
+    Carry,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum BinaryOp {
+    Add,
+    Sub,
+    Or,
+    Mul,
+    Div,
+    Rem,
+    And,
+}
+
+/// An expression over the state of the CPU
+#[derive(Debug, Clone, PartialEq)]
+pub enum Value {
+    Number(i64),
+    Register(Register),
+    Flag(Flag),
+    /// The byte at the address the inner value evaluates to
+    Memory(Box<Value>),
+    Binary(BinaryOp, Box<Value>, Box<Value>),
+}
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum CompareOp {
+    Equal,
+    NotEqual,
+    Less,
+    LessEqual,
+    Greater,
+    GreaterEqual,
+}
+
+/// Condition of a breakpoint
+#[derive(Debug, Clone, PartialEq)]
+pub enum Condition {
+    Compare(CompareOp, Value, Value),
+    Not(Box<Condition>),
+    And(Box<Condition>, Box<Condition>),
+    Or(Box<Condition>, Box<Condition>),
+}
+
+impl Register {
+    pub fn read(&self, cpu: &Cpu) -> u16 {
+        let reg8 = |reg| cpu.access_register(reg).expect("invalid register") as u16;
+        match *self {
+            Register::A => reg8(CpuRegister::A),
+            Register::B => reg8(CpuRegister::B),
+            Register::C => reg8(CpuRegister::C),
+            Register::D => reg8(CpuRegister::D),
+            Register::E => reg8(CpuRegister::E),
+            Register::F => cpu.f as u16,
+            Register::H => reg8(CpuRegister::H),
+            Register::L => reg8(CpuRegister::L),
+            Register::AF => cpu.access_register16(CpuRegister16::AF),
+            Register::BC => cpu.access_register16(CpuRegister16::BC),
+            Register::DE => cpu.access_register16(CpuRegister16::DE),
+            Register::HL => cpu.access_register16(CpuRegister16::HL),
+            Register::SP => cpu.access_register16(CpuRegister16::SP),
+            Register::PC => cpu.pc,
+        }
+    }
+}
+
+impl Flag {
+    pub fn read(&self, cpu: &Cpu) -> bool {
+        let mask = match *self {
+            Flag::Zero => ZL,
+            Flag::Subtract => NLV,
+            Flag::HalfCarry => HL,
+            Flag::Carry => CL,
+        };
+        cpu.f & mask != 0
+    }
+}
+
+impl Value {
+    /// None if the expression divides by zero
+    pub fn evaluate(&self, cpu: &Cpu) -> Option<i64> {
+        match *self {
+            Value::Number(n) => Some(n),
+            Value::Register(reg) => Some(reg.read(cpu) as i64),
+            Value::Flag(flag) => Some(flag.read(cpu) as i64),
+            Value::Memory(ref addr) => {
+                addr.evaluate(cpu).map(|addr| cpu.peek(addr as u16) as i64)
+            }
+            Value::Binary(op, ref l, ref r) => {
+                let (l, r) = match (l.evaluate(cpu), r.evaluate(cpu)) {
+                    (Some(l), Some(r)) => (l, r),
+                    _ => return None,
+                };
+                match op {
+                    BinaryOp::Add => Some(l.wrapping_add(r)),
+                    BinaryOp::Sub => Some(l.wrapping_sub(r)),
+                    BinaryOp::Or => Some(l | r),
+                    BinaryOp::Mul => Some(l.wrapping_mul(r)),
+                    BinaryOp::Div => l.checked_div(r),
+                    BinaryOp::Rem => l.checked_rem(r),
+                    BinaryOp::And => Some(l & r),
+                }
+            }
+        }
+    }
+}
+
+impl Condition {
+    /// Whether the condition holds for the current state of `cpu`.
+    /// A comparison which can't be evaluated doesn't hold.
+    pub fn holds(&self, cpu: &Cpu) -> bool {
+        match *self {
+            Condition::Compare(op, ref l, ref r) => {
+                let (l, r) = match (l.evaluate(cpu), r.evaluate(cpu)) {
+                    (Some(l), Some(r)) => (l, r),
+                    _ => return false,
+                };
+                match op {
+                    CompareOp::Equal => l == r,
+                    CompareOp::NotEqual => l != r,
+                    CompareOp::Less => l < r,
+                    CompareOp::LessEqual => l <= r,
+                    CompareOp::Greater => l > r,
+                    CompareOp::GreaterEqual => l >= r,
+                }
+            }
+            Condition::Not(ref c) => !c.holds(cpu),
+            Condition::And(ref l, ref r) => l.holds(cpu) && r.holds(cpu),
+            Condition::Or(ref l, ref r) => l.holds(cpu) || r.holds(cpu),
+        }
+    }
+}
+
+impl fmt::Display for Register {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{:?}", self)
+    }
+}
+
+impl fmt::Display for Flag {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let name = match *self {
+            Flag::Zero => "ZF",
+            Flag::Subtract => "NF",
+            Flag::HalfCarry => "HF",
+            Flag::Carry => "CF",
+        };
+        write!(f, "{}", name)
+    }
+}
+
+impl fmt::Display for Value {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match *self {
+            Value::Number(n) if n < 0 => write!(f, "{}", n),
+            Value::Number(n) => write!(f, "0x{:X}", n),
+            Value::Register(reg) => write!(f, "{}", reg),
+            Value::Flag(flag) => write!(f, "{}", flag),
+            Value::Memory(ref addr) => write!(f, "[{}]", addr),
+            Value::Binary(op, ref l, ref r) => {
+                let op = match op {
+                    BinaryOp::Add => "+",
+                    BinaryOp::Sub => "-",
+                    BinaryOp::Or => "|",
+                    BinaryOp::Mul => "*",
+                    BinaryOp::Div => "/",
+                    BinaryOp::Rem => "%",
+                    BinaryOp::And => "&",
+                };
+                write!(f, "({} {} {})", l, op, r)
+            }
+        }
+    }
+}
+
+impl fmt::Display for Condition {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match *self {
+            Condition::Compare(op, ref l, ref r) => {
+                let op = match op {
+                    CompareOp::Equal => "==",
+                    CompareOp::NotEqual => "!=",
+                    CompareOp::Less => "<",
+                    CompareOp::LessEqual => "<=",
+                    CompareOp::Greater => ">",
+                    CompareOp::GreaterEqual => ">=",
+                };
+                write!(f, "{} {} {}", l, op, r)
+            }
+            Condition::Not(ref c) => write!(f, "!({})", c),
+            Condition::And(ref l, ref r) => write!(f, "({} && {})", l, r),
+            Condition::Or(ref l, ref r) => write!(f, "({} || {})", l, r),
+        }
+    }
 }
@@ -3,8 +3,11 @@ mod language;
 #[allow(unknown_lints, useless_attribute, needless_lifetimes, match_same_arms, cyclomatic_complexity, clone_on_copy, type_complexity, dead_code, unused_comparisons, unused_label, absurd_extreme_comparisons)]
 #[cfg(feature = "debugger")]
 mod dbglanguage;
+pub mod gdb;
 pub mod graphics;
-#[cfg(feature = "debugger")] mod tests;
+pub mod history;
+pub mod symbols;
+#[cfg(test)] mod tests;
 
 #[cfg(not(feature = "debugger"))]
 mod dbglanguage {
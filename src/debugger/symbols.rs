@@ -0,0 +1,212 @@
+//! Symbol files
+//!
+//! RGBDS and no$gmb write `.sym` files with a line per label, giving
+//! its bank and address in hex and its name, e.g. `01:4A2F main_loop`.
+//! Anything after a `;` is a comment.  Labels in 0x4000-0x7FFF only
+//! name an address while their bank is mapped, other labels name it
+//! whichever bank they're in.
+
+use std::collections::{BTreeMap, HashMap};
+use std::collections::Bound::{Included, Unbounded};
+use std::fs::File;
+use std::io;
+use std::io::Read;
+use std::path::Path;
+
+use cpu::constants::*;
+
+/// How far after a label an address is still shown relative to it
+const MAX_LABEL_OFFSET: MemAddr = 0x400;
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct SymbolTable {
+    by_name: HashMap<String, (usize, MemAddr)>,
+    /// First label of each address and bank, ordered by address
+    by_address: BTreeMap<(MemAddr, usize), String>,
+}
+
+impl SymbolTable {
+    pub fn new() -> SymbolTable {
+        SymbolTable {
+            by_name: HashMap::new(),
+            by_address: BTreeMap::new(),
+        }
+    }
+
+    pub fn load(path: &Path) -> io::Result<SymbolTable> {
+        let mut text = String::new();
+        File::open(path)?.read_to_string(&mut text)?;
+        Ok(SymbolTable::parse(&text))
+    }
+
+    /// Reads the lines of a symbol file, skipping the malformed ones
+    pub fn parse(text: &str) -> SymbolTable {
+        let mut symbols = SymbolTable::new();
+        for (n, line) in text.lines().enumerate() {
+            let line = line.split(';').next().unwrap_or("").trim();
+            if line.is_empty() {
+                continue;
+            }
+            match parse_line(line) {
+                Some((bank, address, name)) => symbols.insert(bank, address, name),
+                None => warn!("Ignoring line {} of the symbol file: {}", n + 1, line),
+            }
+        }
+        symbols
+    }
+
+    pub fn insert(&mut self, bank: usize, address: MemAddr, name: &str) {
+        if self.by_name.contains_key(name) {
+            warn!("Symbol {} is defined twice, using the first one", name);
+            return;
+        }
+        self.by_name.insert(name.to_string(), (bank, address));
+        self.by_address.entry((address, bank)).or_insert_with(|| name.to_string());
+    }
+
+    pub fn len(&self) -> usize {
+        self.by_name.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.by_name.is_empty()
+    }
+
+    pub fn address_of(&self, name: &str) -> Option<MemAddr> {
+        self.by_name.get(name).map(|&(_, address)| address)
+    }
+
+    /// The label of `address` while ROM bank `rom_bank` is mapped
+    pub fn name_at(&self, address: MemAddr, rom_bank: usize) -> Option<&str> {
+        self.by_address
+            .range((Included((address, 0)), Included((address, usize::max_value()))))
+            .find(|&(&(_, bank), _)| visible(address, bank, rom_bank))
+            .map(|(_, name)| name.as_ref())
+    }
+
+    /// The address as `label` or `label+0xN` relative to the closest
+    /// label before it, if there is one near enough
+    pub fn describe(&self, address: MemAddr, rom_bank: usize) -> Option<String> {
+        let label = self.by_address
+            .range((Unbounded, Included((address, usize::max_value()))))
+            .rev()
+            .take_while(|&(&(start, _), _)| address - start <= MAX_LABEL_OFFSET)
+            .find(|&(&(start, bank), _)| {
+                // Labels don't reach into a different area of memory
+                start >> 14 == address >> 14 && visible(start, bank, rom_bank)
+            });
+        match label {
+            Some((&(start, _), name)) if start == address => Some(name.clone()),
+            Some((&(start, _), name)) => Some(format!("{}+0x{:X}", name, address - start)),
+            None => None,
+        }
+    }
+
+    /// Replaces the known symbols in a debugger command with their
+    /// addresses
+    pub fn substitute(&self, input: &str) -> String {
+        let chars: Vec<char> = input.chars().collect();
+        let mut output = String::new();
+        let mut i = 0;
+        while i < chars.len() {
+            let start = i;
+            if is_symbol_start(chars[i]) {
+                while i < chars.len() && is_symbol_char(&chars, i) {
+                    i += 1;
+                }
+            } else if chars[i].is_digit(10) {
+                // Keeps `0xAB` in one piece
+                while i < chars.len() && chars[i].is_alphanumeric() {
+                    i += 1;
+                }
+            } else {
+                i += 1;
+            }
+
+            let word: String = chars[start..i].iter().cloned().collect();
+            match self.address_of(&word) {
+                Some(address) if is_symbol_start(chars[start]) => {
+                    output.push_str(&format!("0x{:04X}", address))
+                }
+                _ => output.push_str(&word),
+            }
+        }
+        output
+    }
+
+    /// Names the addresses in a disassembled instruction, which are
+    /// written as `Addr_XXXX` for relative jumps and `$XXXX` otherwise
+    pub fn annotate(&self, instruction: &str, rom_bank: usize) -> String {
+        let mut output = String::new();
+        let mut rest = instruction;
+        while let Some(start) = rest.find(|c: char| c == '$' || c == 'A') {
+            output.push_str(&rest[..start]);
+            rest = &rest[start..];
+            let prefix = if rest.starts_with("Addr_") {
+                "Addr_".len()
+            } else if rest.starts_with('$') {
+                1
+            } else {
+                output.push_str(&rest[..1]);
+                rest = &rest[1..];
+                continue;
+            };
+
+            let digits: String = rest[prefix..].chars().take_while(|c| c.is_digit(16)).collect();
+            let name = if digits.len() == 4 {
+                MemAddr::from_str_radix(&digits, 16)
+                    .ok()
+                    .and_then(|address| self.name_at(address, rom_bank))
+            } else {
+                None
+            };
+            match name {
+                Some(name) => output.push_str(name),
+                None => output.push_str(&rest[..prefix + digits.len()]),
+            }
+            rest = &rest[prefix + digits.len()..];
+        }
+        output.push_str(rest);
+        output
+    }
+}
+
+/// Parses `bank:address name`
+fn parse_line(line: &str) -> Option<(usize, MemAddr, &str)> {
+    let mut fields = line.split_whitespace();
+    let (location, name) = match (fields.next(), fields.next(), fields.next()) {
+        (Some(location), Some(name), None) => (location, name),
+        _ => return None,
+    };
+    let mut parts = location.splitn(2, ':');
+    match (parts.next(), parts.next()) {
+        (Some(bank), Some(address)) => {
+            match (usize::from_str_radix(bank, 16), MemAddr::from_str_radix(address, 16)) {
+                (Ok(bank), Ok(address)) => Some((bank, address, name)),
+                _ => None,
+            }
+        }
+        _ => None,
+    }
+}
+
+/// Whether a label in `bank` names `address` while ROM bank
+/// `rom_bank` is mapped
+fn visible(address: MemAddr, bank: usize, rom_bank: usize) -> bool {
+    match address {
+        0x4000...0x7FFF => bank == rom_bank,
+        _ => true,
+    }
+}
+
+fn is_symbol_start(c: char) -> bool {
+    c.is_alphabetic() || c == '_'
+}
+
+/// Local labels are written `parent.local`, but `..` separates ranges
+fn is_symbol_char(chars: &[char], i: usize) -> bool {
+    match chars[i] {
+        '.' => chars.get(i + 1).map_or(false, |&c| c != '.'),
+        c => c.is_alphanumeric() || c == '_' || c == '@' || c == '#',
+    }
+}
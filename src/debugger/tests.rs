@@ -5,16 +5,501 @@ use super::language::*;
 #[allow(unused_imports)]
 use super::dbglanguage::*;
 
-#[cfg(test)]
+// The debugging language is only built with the debugger feature
+#[cfg(feature = "debugger")]
 #[test]
 fn number_test() {
     assert!(parse_Input("12").is_ok());
     assert!(parse_Input("-02").is_ok());
 }
 
+#[cfg(feature = "debugger")]
 #[test]
 fn hexnumber_test() {
     assert!(parse_Input("0x12").is_ok());
     assert_eq!(parse_Input("0x100").unwrap(),
                DebuggerAction::Echo { str: "0x100".to_string() });
 }
+
+#[cfg(feature = "debugger")]
+#[test]
+fn breakpoint_test() {
+    assert_eq!(parse_Input("break 0x150").unwrap(),
+               DebuggerAction::SetBreakPoint {
+                   addr: 0x150,
+                   condition: None,
+               });
+    assert!(parse_Input("break 0x150 if").is_err());
+    assert!(parse_Input("break 0x150 if A").is_err());
+
+    let condition = match parse_Input("b 0x150 if !(ZF == 1) || BC + 1 * 2 != [SP]").unwrap() {
+        DebuggerAction::SetBreakPoint { addr: 0x150, condition: Some(c) } => c,
+        other => panic!("unexpected action {:?}", other),
+    };
+    assert_eq!(format!("{}", condition),
+               "(!(ZF == 0x1) || (BC + (0x1 * 0x2)) != [SP])");
+}
+
+#[cfg(feature = "debugger")]
+#[test]
+fn conditional_breakpoint_test() {
+    use cpu::Cpu;
+
+    let condition = match parse_Input("break 0x150 if A == 0x3 && [HL] > 0x10").unwrap() {
+        DebuggerAction::SetBreakPoint { addr: 0x150, condition: Some(c) } => c,
+        other => panic!("unexpected action {:?}", other),
+    };
+
+    let mut cpu = Cpu::new();
+    // LD A, 0x03; LD HL, 0xC100
+    let program = [0x3E, 0x03, 0x21, 0x00, 0xC1];
+    for (i, &b) in program.iter().enumerate() {
+        cpu.write_mem(0xC000 + i as u16, b);
+    }
+    cpu.write_mem(0xC100, 0x20);
+    cpu.pc = 0xC000;
+    assert!(!condition.holds(&cpu));
+
+    cpu.dispatch_opcode();
+    assert!(!condition.holds(&cpu));
+    cpu.dispatch_opcode();
+    assert!(condition.holds(&cpu));
+
+    cpu.write_mem(0xC100, 0x10);
+    assert!(!condition.holds(&cpu));
+
+    // Dividing by zero never stops execution
+    let condition = match parse_Input("break 0x150 if A / (A - 3) == 0").unwrap() {
+        DebuggerAction::SetBreakPoint { condition: Some(c), .. } => c,
+        other => panic!("unexpected action {:?}", other),
+    };
+    assert!(!condition.holds(&cpu));
+}
+
+#[cfg(feature = "debugger")]
+#[test]
+fn watchpoint_test() {
+    use cpu::watchpoint::WatchMode;
+
+    assert_eq!(parse_Input("watch 0xC000").unwrap(),
+               DebuggerAction::WatchPoint {
+                   start: 0xC000,
+                   end: 0xC000,
+                   mode: WatchMode::Write,
+               });
+    assert_eq!(parse_Input("watch read 0xC000..0xC0FF").unwrap(),
+               DebuggerAction::WatchPoint {
+                   start: 0xC000,
+                   end: 0xC0FF,
+                   mode: WatchMode::Read,
+               });
+    assert_eq!(parse_Input("watch change 0xC000").unwrap(),
+               DebuggerAction::WatchPoint {
+                   start: 0xC000,
+                   end: 0xC000,
+                   mode: WatchMode::Change,
+               });
+    assert_eq!(parse_Input("watch change 0xC000..0xC000 + 3 to 5").unwrap(),
+               DebuggerAction::WatchPoint {
+                   start: 0xC000,
+                   end: 0xC003,
+                   mode: WatchMode::Value(5),
+               });
+    assert_eq!(parse_Input("unwatch 0xC000..0xC0FF").unwrap(),
+               DebuggerAction::UnwatchPoint {
+                   start: 0xC000,
+                   end: 0xC0FF,
+               });
+    assert!(parse_Input("watch read 0xC000 to 5").is_err());
+    assert_eq!(parse_Input("watch change 0xC000 to 0xFF").unwrap(),
+               DebuggerAction::WatchPoint {
+                   start: 0xC000,
+                   end: 0xC000,
+                   mode: WatchMode::Value(0xFF),
+               });
+    assert!(parse_Input("watch change 0xC000 to 0x100").is_err());
+    assert!(parse_Input("watch change 0xC000 to -1").is_err());
+    assert_eq!(parse_Input("show watchpoints").unwrap(),
+               DebuggerAction::Show { show: ShowableThing::Watchpoints });
+}
+
+#[cfg(feature = "debugger")]
+#[test]
+fn time_travel_command_test() {
+    assert_eq!(parse_Input("reverse-step").unwrap(), DebuggerAction::ReverseStep);
+    assert_eq!(parse_Input("rs").unwrap(), DebuggerAction::ReverseStep);
+    assert_eq!(parse_Input("rc").unwrap(), DebuggerAction::ReverseContinue);
+    assert_eq!(parse_Input("goto-cycle 0x100 + 4").unwrap(),
+               DebuggerAction::GotoCycle { cycle: 0x104 });
+    assert_eq!(parse_Input("show cycles").unwrap(),
+               DebuggerAction::Show { show: ShowableThing::Cycles });
+}
+
+#[test]
+fn time_travel_test() {
+    use cpu::Cpu;
+    use super::history::*;
+
+    let state = |cpu: &Cpu| {
+        (cpu.cycles, cpu.pc, Register::A.read(cpu), Register::B.read(cpu), cpu.peek(0xFF00))
+    };
+
+    let mut cpu = Cpu::new();
+    // loop: LDH A, (0x00); ADD A, B; LD B, A; JR loop
+    let program = [0xF0, 0x00, 0x80, 0x47, 0x18, 0xFA];
+    for (i, &b) in program.iter().enumerate() {
+        cpu.write_mem(0xC000 + i as u16, b);
+    }
+    cpu.pc = 0xC000;
+    cpu.write_joypad(0x10);
+
+    let mut history = History::with_interval(40, 8);
+    let mut boundaries = vec![];
+    for i in 0..60 {
+        match i {
+            10 | 50 => cpu.set_input_state(0xEF),
+            40 => cpu.set_input_state(0xFF),
+            _ => (),
+        }
+        history.record(&cpu);
+        boundaries.push(state(&cpu));
+        cpu.dispatch_opcode();
+    }
+    let end = state(&cpu);
+    let oldest = history.oldest_cycle().unwrap();
+    assert!(oldest > boundaries[10].0 && oldest < boundaries[40].0);
+
+    // Every recorded boundary can be gone back to, replaying the input
+    for &expected in boundaries.iter().rev().filter(|b| b.0 >= oldest) {
+        assert_eq!(history.goto_cycle(&mut cpu, expected.0), Ok(expected.0));
+        assert_eq!(state(&cpu), expected);
+    }
+    assert_eq!(history.goto_cycle(&mut cpu, end.0), Ok(end.0));
+    assert_eq!(state(&cpu), end);
+    assert_eq!(history.goto_cycle(&mut cpu, 0), Err(HistoryError::TooOld(oldest)));
+    assert_eq!(state(&cpu), end);
+
+    // A cycle inside an instruction goes to the end of it
+    let middle = boundaries[45].0 + 1;
+    assert_eq!(history.goto_cycle(&mut cpu, middle), Ok(boundaries[46].0));
+
+    history.goto_cycle(&mut cpu, end.0).unwrap();
+    assert_eq!(history.reverse_step(&mut cpu), Ok(boundaries[59].0));
+    assert_eq!(state(&cpu), boundaries[59]);
+    assert_eq!(history.reverse_step(&mut cpu), Ok(boundaries[58].0));
+    assert_eq!(state(&cpu), boundaries[58]);
+
+    // Stops at the last boundary before now where the condition holds
+    history.goto_cycle(&mut cpu, end.0).unwrap();
+    let b = boundaries[30..].iter().find(|b| b.1 == 0xC002).unwrap().3;
+    let expected = *boundaries.iter().rev().find(|s| s.1 == 0xC002 && s.3 == b).unwrap();
+    let last = boundaries.iter().rev().find(|s| s.1 == 0xC002).unwrap().0;
+    assert_eq!(history.reverse_continue(&mut cpu, |cpu| cpu.pc == 0xC002), Ok(Some(last)));
+    assert_eq!(history.reverse_continue(&mut cpu, |cpu| {
+                   cpu.pc == 0xC002 && Register::B.read(cpu) == b
+               }),
+               Ok(Some(expected.0)));
+    assert_eq!(state(&cpu), expected);
+    assert_eq!(history.reverse_continue(&mut cpu, |_| false), Ok(None));
+    assert_eq!(cpu.cycles, oldest);
+
+    // Running forward from the past starts a new timeline
+    history.goto_cycle(&mut cpu, boundaries[55].0).unwrap();
+    history.record(&cpu);
+    cpu.dispatch_opcode();
+    assert_eq!(history.goto_cycle(&mut cpu, end.0), Ok(end.0));
+    assert_eq!(state(&cpu), end);
+}
+
+#[test]
+fn time_travel_link_test() {
+    use std::io;
+    use std::sync::{Arc, Mutex};
+    use std::time::Duration;
+    use cpu::Cpu;
+    use io::link::{Socket, SocketLink};
+    use super::history::*;
+
+    /// The other side of the cable, answering every byte with 0x99
+    struct Recorder {
+        sent: Arc<Mutex<Vec<u8>>>,
+        reply: Vec<u8>,
+    }
+
+    impl io::Read for Recorder {
+        fn read(&mut self, buf: &mut [u8]) -> io::Result<usize> {
+            if self.reply.is_empty() {
+                return Err(io::Error::new(io::ErrorKind::WouldBlock, "nothing sent"));
+            }
+            let n = ::std::cmp::min(buf.len(), self.reply.len());
+            buf[..n].copy_from_slice(&self.reply[..n]);
+            self.reply.drain(..n);
+            Ok(n)
+        }
+    }
+
+    impl io::Write for Recorder {
+        fn write(&mut self, buf: &[u8]) -> io::Result<usize> {
+            self.sent.lock().unwrap().extend_from_slice(buf);
+            self.reply.extend_from_slice(&[1, 0x99]);
+            Ok(buf.len())
+        }
+
+        fn flush(&mut self) -> io::Result<()> {
+            Ok(())
+        }
+    }
+
+    impl Socket for Recorder {
+        fn set_read_timeout(&self, _: Option<Duration>) -> io::Result<()> {
+            Ok(())
+        }
+
+        fn set_nonblocking(&self, _: bool) -> io::Result<()> {
+            Ok(())
+        }
+    }
+
+    let sent = Arc::new(Mutex::new(vec![]));
+    let mut cpu = Cpu::new();
+    cpu.connect_serial(Box::new(SocketLink::new(Box::new(Recorder {
+        sent: sent.clone(),
+        reply: vec![],
+    }))));
+    // LD A, 0x42; LDH (0x01), A; LD A, 0x81; LDH (0x02), A; loop: JR loop
+    let program = [0x3E, 0x42, 0xE0, 0x01, 0x3E, 0x81, 0xE0, 0x02, 0x18, 0xFE];
+    for (i, &b) in program.iter().enumerate() {
+        cpu.write_mem(0xC000 + i as u16, b);
+    }
+    cpu.pc = 0xC000;
+
+    let mut history = History::with_interval(1000, 16);
+    while cpu.cycles < 6000 {
+        history.record(&cpu);
+        cpu.dispatch_opcode();
+    }
+    assert_eq!(*sent.lock().unwrap(), vec![0, 0x42]);
+    assert_eq!(cpu.peek(0xFF01), 0x99);
+    cpu.apu.take_samples();
+
+    // Replaying the transfer doesn't send the byte again
+    let end = cpu.cycles;
+    history.reverse_step(&mut cpu).unwrap();
+    history.goto_cycle(&mut cpu, 100).unwrap();
+    assert_eq!(history.goto_cycle(&mut cpu, end), Ok(end));
+    assert_eq!(*sent.lock().unwrap(), vec![0, 0x42]);
+    assert!(cpu.apu.take_samples().is_empty());
+
+    // The cable is plugged back in for running forward
+    cpu.write_mem(0xFF01, 0x24);
+    cpu.write_mem(0xFF02, 0x81);
+    while cpu.cycles < end + 5000 {
+        cpu.dispatch_opcode();
+    }
+    assert_eq!(*sent.lock().unwrap(), vec![0, 0x42, 0, 0x24]);
+    assert!(!cpu.apu.take_samples().is_empty());
+}
+
+#[test]
+fn gdb_stub_test() {
+    use std::io::{Read, Write};
+    use std::net::{TcpListener, TcpStream};
+    use std::time::Duration;
+    use cpu::Cpu;
+    use cpu::constants::CpuRegister16;
+    use super::gdb::GdbStub;
+
+    fn encode(value: u16) -> String {
+        format!("{:02x}{:02x}", value & 0xFF, value >> 8)
+    }
+
+    fn send(client: &mut TcpStream, data: &str) {
+        let checksum = data.bytes().fold(0u8, |sum, b| sum.wrapping_add(b));
+        let packet = format!("${}#{:02x}", data, checksum);
+        client.write_all(packet.as_bytes()).unwrap();
+    }
+
+    fn receive(client: &mut TcpStream) -> String {
+        let mut received = String::new();
+        let mut buffer = [0; 256];
+        loop {
+            let n = client.read(&mut buffer).unwrap();
+            assert!(n > 0, "the stub hung up");
+            received.push_str(::std::str::from_utf8(&buffer[..n]).unwrap());
+            // Skips acknowledgements
+            let packet = match received.find('$') {
+                Some(start) => &received[start..],
+                None => continue,
+            };
+            if let Some(end) = packet.find('#') {
+                if packet.len() >= end + 3 {
+                    return packet[1..end].to_string();
+                }
+            }
+        }
+    }
+
+    fn command(client: &mut TcpStream, gdb: &mut GdbStub, cpu: &mut Cpu, packet: &str) -> String {
+        send(client, packet);
+        gdb.poll(cpu);
+        receive(client)
+    }
+
+    /// Runs like the application would until GDB stops the game
+    fn run(gdb: &mut GdbStub, cpu: &mut Cpu) {
+        for _ in 0..100000 {
+            gdb.poll(cpu);
+            if !gdb.should_run() {
+                return;
+            }
+            cpu.dispatch_opcode();
+            gdb.after_instruction(cpu);
+        }
+        panic!("the game didn't stop");
+    }
+
+    let listener = TcpListener::bind("127.0.0.1:0").unwrap();
+    let mut client = TcpStream::connect(listener.local_addr().unwrap()).unwrap();
+    client.set_read_timeout(Some(Duration::from_secs(5))).unwrap();
+    let mut gdb = GdbStub::new(Box::new(listener.accept().unwrap().0));
+    let mut cpu = Cpu::new();
+
+
+    assert!(command(&mut client, &mut gdb, &mut cpu, "qSupported:swbreak+").contains("qXfer:features:read+"));
+    let xml = command(&mut client, &mut gdb, &mut cpu, "qXfer:features:read:target.xml:0,1000");
+    assert!(xml.starts_with("l<?xml") && xml.contains("<architecture>sm83</architecture>"));
+    assert_eq!(command(&mut client, &mut gdb, &mut cpu, "?"), "S05");
+    assert!(!gdb.should_run());
+
+    // loop: INC A; LD (0xC100), A; JR loop
+    assert_eq!(command(&mut client, &mut gdb, &mut cpu, "Mc000,6:3cea00c118fa"), "OK");
+    assert_eq!(command(&mut client, &mut gdb, &mut cpu, "mc000,6"), "3cea00c118fa");
+    assert_eq!(command(&mut client, &mut gdb, &mut cpu, "P5=00c0"), "OK");
+    assert_eq!(command(&mut client, &mut gdb, &mut cpu, "P0=f005"), "OK");
+    assert_eq!(command(&mut client, &mut gdb, &mut cpu, "p5"), "00c0");
+    assert_eq!(command(&mut client, &mut gdb, &mut cpu, "g"),
+               format!("f005{}{}{}{}00c0",
+                       encode(cpu.access_register16(CpuRegister16::BC)),
+                       encode(cpu.access_register16(CpuRegister16::DE)),
+                       encode(cpu.access_register16(CpuRegister16::HL)),
+                       encode(cpu.access_register16(CpuRegister16::SP))));
+    assert_eq!(command(&mut client, &mut gdb, &mut cpu, "p9"), "E01");
+
+    assert_eq!(command(&mut client, &mut gdb, &mut cpu, "s"), "S05");
+    assert_eq!(cpu.pc, 0xC001);
+    assert_eq!(Register::A.read(&cpu), 0x06);
+
+    assert_eq!(command(&mut client, &mut gdb, &mut cpu, "Z0,c004,1"), "OK");
+    send(&mut client, "c");
+    run(&mut gdb, &mut cpu);
+    assert_eq!(receive(&mut client), "S05");
+    assert_eq!(cpu.pc, 0xC004);
+    assert_eq!(command(&mut client, &mut gdb, &mut cpu, "z0,c004,1"), "OK");
+
+    assert_eq!(command(&mut client, &mut gdb, &mut cpu, "Z2,c100,1"), "OK");
+    send(&mut client, "c");
+    run(&mut gdb, &mut cpu);
+    assert_eq!(receive(&mut client), "T05watch:c100;");
+    assert_eq!(cpu.pc, 0xC004);
+    assert_eq!(command(&mut client, &mut gdb, &mut cpu, "z2,c100,1"), "OK");
+    assert!(cpu.watchpoints.list.is_empty());
+
+    // Ctrl-C while running
+    send(&mut client, "c");
+    gdb.poll(&mut cpu);
+    client.write_all(&[0x03]).unwrap();
+    run(&mut gdb, &mut cpu);
+    assert_eq!(receive(&mut client), "S02");
+
+    assert_eq!(command(&mut client, &mut gdb, &mut cpu, "D"), "OK");
+    assert!(gdb.should_run());
+}
+
+#[test]
+fn symbols_test() {
+    use cpu::Cpu;
+    use super::graphics::call_stack;
+    use super::symbols::SymbolTable;
+
+    let symbols = SymbolTable::parse("; File generated by rgblink\n\
+                                      00:0150 Main\n\
+                                      00:0153 Main.loop\n\
+                                      01:4000 BankedFn\n\
+                                      02:4000 OtherBank\n\
+                                      00:c000 wCounter ; comment\n\
+                                      not a symbol\n");
+    assert_eq!(symbols.len(), 5);
+    assert_eq!(symbols.address_of("Main.loop"), Some(0x153));
+    assert_eq!(symbols.address_of("loop"), None);
+    assert_eq!(symbols.name_at(0x4000, 1), Some("BankedFn"));
+    assert_eq!(symbols.name_at(0x4000, 2), Some("OtherBank"));
+    assert_eq!(symbols.name_at(0x4000, 3), None);
+    assert_eq!(symbols.describe(0x155, 1), Some("Main.loop+0x2".to_string()));
+    assert_eq!(symbols.describe(0xC000, 1), Some("wCounter".to_string()));
+    assert_eq!(symbols.describe(0x4001, 3), None);
+
+    assert_eq!(symbols.annotate("JR NZ,Addr_0153", 1), "JR NZ,Main.loop");
+    assert_eq!(symbols.annotate("CALL $4000", 2), "CALL OtherBank");
+    assert_eq!(symbols.annotate("LD A,($C000)", 1), "LD A,(wCounter)");
+    assert_eq!(symbols.annotate("ADD A,$12", 1), "ADD A,$12");
+    assert_eq!(symbols.annotate("JP $0200", 1), "JP $0200");
+
+    assert_eq!(symbols.substitute("break Main.loop if [wCounter] == 0x3"),
+               "break 0x0153 if [0xC000] == 0x3");
+    assert_eq!(symbols.substitute("watch Main..wCounter"), "watch 0x0150..0xC000");
+    assert_eq!(symbols.substitute("show 0x150"), "show 0x150");
+
+    let mut cpu = Cpu::new();
+    // CALL 0xC010; ...; 0xC010: CALL 0xC020
+    let program = [(0xC000, 0xCD), (0xC001, 0x10), (0xC002, 0xC0),
+                   (0xC010, 0xCD), (0xC011, 0x20), (0xC012, 0xC0)];
+    for &(addr, b) in program.iter() {
+        cpu.write_mem(addr, b);
+    }
+    cpu.pc = 0xC000;
+    cpu.dispatch_opcode();
+    cpu.dispatch_opcode();
+    assert_eq!(cpu.pc, 0xC020);
+    assert_eq!(call_stack(&cpu), vec![0xC013, 0xC003]);
+
+    // Only the RAM SP is in holds the stack, not what lies after it
+    let mut cpu = Cpu::new();
+    // LD SP, 0xDFFE; CALL 0xC010; ...; 0xC010: CALL 0xC020
+    let program = [(0xC000, 0x31), (0xC001, 0xFE), (0xC002, 0xDF),
+                   (0xC003, 0xCD), (0xC004, 0x10), (0xC005, 0xC0),
+                   (0xC010, 0xCD), (0xC011, 0x20), (0xC012, 0xC0)];
+    for &(addr, b) in program.iter() {
+        cpu.write_mem(addr, b);
+    }
+    // A return address left in HRAM
+    cpu.write_mem(0xFF80, 0x13);
+    cpu.write_mem(0xFF81, 0xC0);
+    // 0x0000 on the stack, after IE holding 0xFF like RST 0x38
+    cpu.write_mem(0xDFFE, 0x00);
+    cpu.write_mem(0xDFFF, 0x00);
+    cpu.write_mem(0xFFFF, 0xFF);
+    cpu.pc = 0xC000;
+    for _ in 0..3 {
+        cpu.dispatch_opcode();
+    }
+    assert_eq!(cpu.pc, 0xC020);
+    assert_eq!(call_stack(&cpu), vec![0xC013, 0xC006]);
+}
+
+#[cfg(feature = "debugger")]
+#[test]
+fn symbol_command_test() {
+    use super::symbols::SymbolTable;
+
+    let symbols = SymbolTable::parse("00:0153 Main.loop\n00:c000 wCounter\n");
+    let input = symbols.substitute("break Main.loop if [wCounter] == 0x3");
+    match parse_Input(&input).unwrap() {
+        DebuggerAction::SetBreakPoint { addr: 0x153, condition: Some(_) } => (),
+        other => panic!("unexpected action {:?}", other),
+    }
+
+    assert_eq!(parse_Input("bt").unwrap(),
+               DebuggerAction::Show { show: ShowableThing::Stack });
+    assert_eq!(parse_Input("show stack").unwrap(),
+               DebuggerAction::Show { show: ShowableThing::Stack });
+}
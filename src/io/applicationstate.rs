@@ -6,17 +6,22 @@
 use std;
 
 use sdl2::*;
-use sdl2::audio::AudioDevice;
+use sdl2::audio::AudioQueue;
 use sdl2::keyboard::Keycode;
 use log4rs;
 
+use debugger::gdb::GdbStub;
 use debugger::graphics::*;
 use cpu;
+use cpu::constants::TimingMode;
+use cpu::savestate::*;
 use io::constants::*;
 use io::input::*;
+use io::link::*;
 use io::graphics::*;
 use io::memvis::MemVisState;
 use io::vidram::{VidRamBGDisplay, VidRamTileDisplay};
+use io::screen::ScreenDisplay;
 use io::sound::*;
 
 use log::LogLevelFilter;
@@ -28,18 +33,33 @@ use sdl2;
 use sdl2::rect::{Point, Rect};
 
 use std::num::Wrapping;
+use std::path::{Path, PathBuf};
+
+/// Logs to stdout, up to `level`
+pub fn init_logging(level: LogLevelFilter) -> log4rs::Handle {
+    let stdout = ConsoleAppender::builder()
+        .encoder(Box::new(PatternEncoder::new("{h({l})} {m} {n}")))
+        .build();
+
+    let config = Config::builder()
+        .appender(Appender::builder().build("stdout", Box::new(stdout)))
+        .build(Root::builder().appender("stdout").build(level))
+        .unwrap();
+
+    log4rs::init_config(config).unwrap()
+}
 
 /// Holds all the data needed to use the emulator in meaningful ways
 pub struct ApplicationState {
     pub gameboy: cpu::Cpu,
     sdl_context: Sdl, //  sdl_sound: sdl2::audio,
-    sound_system: AudioDevice<Wave>,
+    sound_system: AudioQueue<f32>,
     renderer: render::Renderer<'static>,
     cycle_count: u64,
     prev_time: u64,
     debugger: Option<Debugger>,
-    prev_hsync_cycles: u64,
-    clock_cycles: u64,
+    gdb: Option<GdbStub>,
+    prev_save_flush_cycles: u64,
     initial_gameboy_state: cpu::Cpu,
     logger_handle: Option<log4rs::Handle>, // storing to keep alive
     controller: Option<sdl2::controller::GameController>, // storing to keep alive
@@ -47,35 +67,35 @@ pub struct ApplicationState {
     ui_scale: f32,
     ui_offset: Point, // TODO whole interface pan
     widgets: Vec<PositionedFrame>,
+    rom_path: PathBuf,
+    /// Save state slot used by the save and load keys
+    state_slot: u8,
 }
 
 
 
 impl ApplicationState {
     //! Sets up the environment for running in memory visualization mode
-    pub fn new(trace_mode: bool, debug_mode: bool, rom_file_name: &str) -> ApplicationState {
-        // Set up logging
-        let stdout = ConsoleAppender::builder()
-            .encoder(Box::new(PatternEncoder::new("{h({l})} {m} {n}")))
-            .build();
-
-        let config = Config::builder()
-            .appender(Appender::builder().build("stdout", Box::new(stdout)))
-            .build(Root::builder().appender("stdout").build(if trace_mode {
+    pub fn new(trace_mode: bool,
+               debug_mode: bool,
+               rom_file_name: &str,
+               boot_rom: Option<&str>,
+               timing_mode: TimingMode,
+               link: Option<LinkTarget>,
+               gdb_port: Option<u16>,
+               symbol_file: Option<&str>)
+               -> ApplicationState {
+        // Set up debugging or command-line logging
+        // The debugger draws over the terminal, so it replaces the logger
+        let debugging = debug_mode && cfg!(feature = "debugger");
+        let handle = if debugging {
+            None
+        } else {
+            Some(init_logging(if trace_mode {
                 LogLevelFilter::Trace
             } else {
                 LogLevelFilter::Debug
             }))
-            .unwrap();
-
-        // Set up debugging or command-line logging
-        let (debugger, handle) = if debug_mode && cfg!(feature = "debugger") {
-            info!("Running in debug mode");
-            // Some(Debugger::new(&mut gameboy))
-            (None, None)
-        } else {
-            let handle = log4rs::init_config(config).unwrap();
-            (None, Some(handle))
         };
 
 
@@ -84,6 +104,50 @@ impl ApplicationState {
 
         trace!("loading ROM");
         gameboy.load_rom(rom_file_name);
+        gameboy.timing_mode = timing_mode;
+
+        if let Some(boot_rom) = boot_rom {
+            if let Err(e) = gameboy.load_boot_rom(Path::new(boot_rom)) {
+                error!("Could not load boot ROM {}: {}", boot_rom, e);
+                std::process::exit(1);
+            }
+        }
+
+        if let Some(link) = link {
+            match open_link(link) {
+                Ok(link) => gameboy.connect_serial(link),
+                Err(e) => {
+                    error!("Could not set up the serial link: {}", e);
+                    std::process::exit(1);
+                }
+            }
+        }
+
+        let mut debugger = if debugging {
+            Some(Debugger::new(&mut gameboy))
+        } else {
+            None
+        };
+
+        if let Some(ref mut dbg) = debugger {
+            // RGBDS puts the symbols next to the ROM
+            let path = symbol_file.map_or_else(|| Path::new(rom_file_name).with_extension("sym"),
+                                               PathBuf::from);
+            if symbol_file.is_some() || path.exists() {
+                match dbg.load_symbols(&gameboy, &path) {
+                    Ok(n) => info!("Loaded {} symbols from {}", n, path.display()),
+                    Err(e) => error!("Could not load symbols from {}: {}", path.display(), e),
+                }
+            }
+        }
+
+        let gdb = gdb_port.map(|port| match GdbStub::listen(port) {
+            Ok(gdb) => gdb,
+            Err(e) => {
+                error!("Could not wait for GDB on port {}: {}", port, e);
+                std::process::exit(1);
+            }
+        });
 
         let sdl_context = sdl2::init().unwrap();
         let device = setup_audio(&sdl_context);
@@ -133,7 +197,10 @@ impl ApplicationState {
         };
 
         let widget_vidram_tiles = {
-            let vis = VidRamTileDisplay { tile_data_select: TileDataSelect::Auto };
+            let vis = VidRamTileDisplay {
+                tile_data_select: TileDataSelect::Auto,
+                bank: 0,
+            };
             let (w, h) = vis.get_initial_size();
             PositionedFrame {
                 rect: Rect::new((MEM_DISP_WIDTH + SCREEN_BUFFER_SIZE_X as i32) as i32 + 5,
@@ -145,10 +212,25 @@ impl ApplicationState {
             }
         };
 
+        let widget_screen = {
+            let txt_format = sdl2::pixels::PixelFormatEnum::RGB24;
+            let w = cpu::ppu::SCREEN_WIDTH as u32;
+            let h = cpu::ppu::SCREEN_HEIGHT as u32;
+            let texture = renderer.create_texture_streaming(txt_format, w, h).unwrap();
+            let vis = ScreenDisplay::new(texture);
+            let (w, h) = vis.get_initial_size();
+            PositionedFrame {
+                rect: Rect::new(MEM_DISP_WIDTH + 3, SCREEN_BUFFER_SIZE_Y as i32 + 3, w, h),
+                scale: 1.0,
+                vis: Box::new(vis),
+            }
+        };
+
         let mut widgets = Vec::new();
         widgets.push(widget_memvis);
         widgets.push(widget_vidram_bg);
         widgets.push(widget_vidram_tiles);
+        widgets.push(widget_screen);
 
         ApplicationState {
             gameboy: gameboy,
@@ -158,8 +240,8 @@ impl ApplicationState {
             cycle_count: 0,
             prev_time: 0,
             debugger: debugger,
-            prev_hsync_cycles: 0,
-            clock_cycles: 0,
+            gdb: gdb,
+            prev_save_flush_cycles: 0,
             initial_gameboy_state: gbcopy,
             logger_handle: handle,
             controller: controller,
@@ -167,6 +249,39 @@ impl ApplicationState {
             ui_scale: SCALE,
             ui_offset: Point::new(0, 0),
             widgets: widgets,
+            rom_path: PathBuf::from(rom_file_name),
+            state_slot: 0,
+        }
+    }
+
+    /// Writes the whole machine state to the current slot
+    pub fn save_state(&mut self) {
+        let path = save_state_path(&self.rom_path, self.state_slot);
+        match self.gameboy.save_state_to_file(&path) {
+            Ok(()) => info!("Saved state to slot {}", self.state_slot),
+            Err(e) => error!("Could not save state to {}: {}", path.display(), e),
+        }
+    }
+
+    /// Restores the machine state from the current slot, keeping the
+    /// current state if that fails
+    pub fn load_state(&mut self) {
+        let path = save_state_path(&self.rom_path, self.state_slot);
+        match self.gameboy.load_state_from_file(&path) {
+            Ok(()) => {
+                info!("Loaded state from slot {}", self.state_slot);
+                if let Some(ref mut dbg) = self.debugger {
+                    dbg.clear_history();
+                }
+            }
+            Err(e) => error!("Could not load state from {}: {}", path.display(), e),
+        }
+    }
+
+    /// Writes battery-backed cartridge RAM to disk if it has changed
+    pub fn flush_save_file(&mut self) {
+        if let Err(e) = self.gameboy.cartridge.flush_save_file() {
+            error!("Could not write save file: {}", e);
         }
     }
 
@@ -250,6 +365,7 @@ impl ApplicationState {
                 }
                 Event::Quit { .. } => {
                     info!("Program exiting!");
+                    self.flush_save_file();
                     std::process::exit(0);
                 }
                 Event::KeyDown { keycode: Some(keycode), repeat, .. } => {
@@ -257,16 +373,34 @@ impl ApplicationState {
                         match keycode {
                             Keycode::Escape => {
                                 info!("Program exiting!");
+                                self.flush_save_file();
                                 std::process::exit(0);
                             }
                             Keycode::F3 => self.gameboy.toggle_logger(),
+                            Keycode::F5 => self.save_state(),
+                            Keycode::F9 => self.load_state(),
+                            Keycode::Num0 | Keycode::Num1 | Keycode::Num2 |
+                            Keycode::Num3 | Keycode::Num4 | Keycode::Num5 |
+                            Keycode::Num6 | Keycode::Num7 | Keycode::Num8 |
+                            Keycode::Num9 => {
+                                let slot = keycode as i32 - Keycode::Num0 as i32;
+                                self.state_slot = slot as u8 % SAVE_STATE_SLOTS;
+                                info!("Selected save state slot {}", self.state_slot);
+                            }
                             Keycode::R => {
                                 // Reset/reload emu
                                 // TODO Keep previous visualization settings
                                 self.gameboy.reset();
+                                // Cartridge RAM survives a reset
+                                self.flush_save_file();
+                                let ram = self.gameboy.cartridge.ram().to_vec();
                                 let gbcopy = self.initial_gameboy_state.clone();
                                 self.gameboy = gbcopy;
+                                self.gameboy.cartridge.load_ram(&ram[..]);
                                 self.gameboy.reinit_logger();
+                                if let Some(ref mut dbg) = self.debugger {
+                                    dbg.clear_history();
+                                }
                                 
                                 // // This way makes it possible to edit rom
                                 // // with external editor and see changes
@@ -335,45 +469,47 @@ impl ApplicationState {
 
         // handle_events(&mut sdl_context, &mut gameboy);
 
+        // While the debugger is paused only its commands run the CPU
+        if let Some(ref mut dbg) = self.debugger {
+            if !dbg.should_run() {
+                dbg.step(&mut self.gameboy);
+                return;
+            }
+            dbg.record(&self.gameboy);
+        }
+
+        // Likewise while GDB has the game stopped
+        if let Some(ref mut gdb) = self.gdb {
+            gdb.poll(&mut self.gameboy);
+        }
+        if self.gdb.as_ref().map_or(false, |gdb| gdb.killed()) {
+            info!("Program exiting!");
+            self.flush_save_file();
+            std::process::exit(0);
+        }
+        if self.gdb.as_ref().map_or(false, |gdb| !gdb.should_run()) {
+            return;
+        }
+
         let current_op_time = if self.gameboy.state != cpu::constants::CpuState::Crashed {
             self.gameboy.dispatch_opcode() as u64
         } else {
             10 // FIXME think about what to return here or refactor code around this
         };
 
+        if let Some(ref mut dbg) = self.debugger {
+            dbg.check_breakpoints(&mut self.gameboy);
+        }
+        if let Some(ref mut gdb) = self.gdb {
+            gdb.after_instruction(&mut self.gameboy);
+        }
+
         self.cycle_count += current_op_time;
-        self.clock_cycles += current_op_time;
-        let timer_khz = self.gameboy.timer_frequency();
-        let time_in_ms_per_cycle = (1000.0 / ((timer_khz as f64) * 1000.0)) as u64;
-        self.clock_cycles += self.cycle_count;
 
         // TODO: remove prev_time
         let prev_time = self.prev_time;
         let ticks = self.cycle_count - prev_time;
 
-        let time_in_cpu_cycle_per_cycle =
-            ((time_in_ms_per_cycle as f64) / (1.0 / (4.19 * 1000.0 * 1000.0))) as u64;
-
-        if self.clock_cycles >= time_in_cpu_cycle_per_cycle {
-            //           std::thread::sleep_ms(16);
-            // trace!("Incrementing the timer!");
-            self.gameboy.timer_cycle();
-            self.clock_cycles = 0;
-        }
-
-        let fake_display_hsync = true;
-        if fake_display_hsync {
-            // update LY respective to cycles spent execing instruction
-            let cycle_count = self.cycle_count;
-            loop {
-                if cycle_count < self.prev_hsync_cycles {
-                    break;
-                }
-                self.gameboy.inc_ly();
-                self.prev_hsync_cycles += CYCLES_PER_HSYNC;
-            }
-        }
-
         // Gameboy screen is 256x256
         // only 160x144 are displayed at a time
         //
@@ -382,7 +518,6 @@ impl ApplicationState {
         // These numbers index the tile data table
         //
 
-        // 16384hz, call inc_div
         // CPU is at 4.194304MHz (or 1.05MHz) 105000000hz
         // hsync at 9198KHz = 9198000hz
         // vsync at 59.73Hz
@@ -394,10 +529,17 @@ impl ApplicationState {
             Err(_) => error!("Could not set render scale"),
         }
 
-        // 1ms before drawing in terms of CPU time we must throw a vblank interrupt
-        // TODO make this variable based on whether it's GB, SGB, etc.
+        if self.cycle_count - self.prev_save_flush_cycles >= SAVE_FILE_FLUSH_CYCLES {
+            self.prev_save_flush_cycles = self.cycle_count;
+            self.flush_save_file();
+        }
+
+        // The PPU produces a frame at every VBlank, but none while
+        // the LCD is off
+        let frame_ready = self.gameboy.take_frame_ready();
+        let lcd_off_frame = !self.gameboy.lcdc_on() && ticks >= CPU_CYCLES_PER_VBLANK;
 
-        if ticks >= CPU_CYCLES_PER_VBLANK {
+        if frame_ready || lcd_off_frame {
             if let Some(ref mut dbg) = self.debugger {
                 dbg.step(&mut self.gameboy);
             }
@@ -425,19 +567,8 @@ impl ApplicationState {
                 self.screenshot_frame_num += Wrapping(1);
             }
 
-            if self.gameboy.get_sound1() {
-                self.sound_system.resume();
-            } else {
-                self.sound_system.pause();
-            }
-
-            let mut sound_system = self.sound_system.lock();
-            sound_system.wave_duty = self.gameboy.channel1_wave_pattern_duty();
-            sound_system.phase_inc = 1.0 /
-                                     (131072.0 / (2048 - self.gameboy.channel1_frequency()) as f32);
-            sound_system.add = self.gameboy.channel1_sweep_increase();
-            //            131072 / (2048 - gb)
-
+            let samples = self.gameboy.apu.take_samples();
+            queue_samples(&self.sound_system, &samples[..]);
 
             self.renderer.present();
         }
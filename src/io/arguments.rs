@@ -17,12 +17,92 @@ pub fn read_arguments<'input>() -> ArgMatches<'input> {
             .required(true)
             .index(1)
             .takes_value(true))
+        .arg(Arg::with_name("boot-rom")
+            .long("boot-rom")
+            .value_name("FILE")
+            .help("Runs the given 256 byte boot ROM before the game")
+            .takes_value(true))
+        .arg(Arg::with_name("per-access-timing")
+            .long("per-access-timing")
+            .help("Runs the timer, PPU and DMA at each memory access of an instruction \
+                   instead of after it, slower but needed by timing sensitive ROMs")
+            .takes_value(false))
+        .arg(Arg::with_name("link-listen")
+            .long("link-listen")
+            .value_name("ADDRESS")
+            .help("Waits for another emulator to connect a link cable on a TCP address or \
+                   unix:PATH")
+            .conflicts_with_all(&["link-connect", "serial-out", "headless"])
+            .takes_value(true))
+        .arg(Arg::with_name("link-connect")
+            .long("link-connect")
+            .value_name("ADDRESS")
+            .help("Connects a link cable to another emulator listening on a TCP address or \
+                   unix:PATH")
+            .conflicts_with_all(&["serial-out", "headless"])
+            .takes_value(true))
+        .arg(Arg::with_name("serial-out")
+            .long("serial-out")
+            .value_name("FILE")
+            .help("Writes bytes sent over the serial port to FILE, - for stdout")
+            .conflicts_with("headless")
+            .takes_value(true))
+        .arg(Arg::with_name("headless")
+            .long("headless")
+            .help("Runs without a window until the ROM reports a result over serial or \
+                   with LD B,B, printing its serial output.  Exits with 0 if it passed, \
+                   1 if it failed, 2 if the CPU crashed or the ROM could not be read and \
+                   3 if the limit was reached without a result")
+            .conflicts_with_all(&["debug", "visualize"])
+            .takes_value(false))
+        .arg(Arg::with_name("info")
+            .long("info")
+            .help("Prints the cartridge header of the ROM and warns about bad checksums \
+                   instead of running it.  Exits with 0 if the header is fine, 1 if there \
+                   were warnings and 2 if the ROM could not be read")
+            .conflicts_with_all(&["headless", "debug", "visualize"])
+            .takes_value(false))
+        .arg(Arg::with_name("frames")
+            .long("frames")
+            .value_name("N")
+            .help("Stops a headless run after N frames (default 3600 without --cycles)")
+            .requires("headless")
+            .validator(is_number)
+            .takes_value(true))
+        .arg(Arg::with_name("cycles")
+            .long("cycles")
+            .value_name("N")
+            .help("Stops a headless run after N cycles")
+            .requires("headless")
+            .validator(is_number)
+            .takes_value(true))
+        .arg(Arg::with_name("png")
+            .long("png")
+            .value_name("FILE")
+            .help("Saves the screen at the end of a headless run as a PNG image")
+            .requires("headless")
+            .takes_value(true))
         .arg(Arg::with_name("debug")
             .short("d")
             .multiple(true)
             .long("debug")
             .help("Runs ncurses debugger in the background")
             .takes_value(false))
+        .arg(Arg::with_name("gdb")
+            .long("gdb")
+            .value_name("PORT")
+            .help("Waits for GDB to connect on a local TCP port and lets it control the \
+                   game, which starts out stopped")
+            .conflicts_with_all(&["debug", "headless", "info"])
+            .validator(is_port)
+            .takes_value(true))
+        .arg(Arg::with_name("symbols")
+            .long("symbols")
+            .value_name("FILE")
+            .help("Loads labels for the debugger from an RGBDS or no$gmb .sym file, by \
+                   default the ROM's path with .sym instead of its extension if it exists")
+            .requires("debug")
+            .takes_value(true))
         .arg(Arg::with_name("trace")
             .short("t")
             .multiple(true)
@@ -36,3 +116,11 @@ pub fn read_arguments<'input>() -> ArgMatches<'input> {
              .takes_value(false))
         .get_matches()
 }
+
+fn is_number(value: String) -> Result<(), String> {
+    value.parse::<u64>().map(|_| ()).map_err(|_| format!("{} is not a number", value))
+}
+
+fn is_port(value: String) -> Result<(), String> {
+    value.parse::<u16>().map(|_| ()).map_err(|_| format!("{} is not a port number", value))
+}
@@ -12,8 +12,6 @@ pub const SCALE: f32 = 2.0;
 pub const MEM_DISP_WIDTH: i32 = 0x0100;
 pub const MEM_DISP_HEIGHT: i32 = 0xFFFF / MEM_DISP_WIDTH + 1; // TODO check this?
 
-pub const CYCLES_PER_HSYNC: u64 = 114; // FIXME this is probably wrong
-
 pub const CPU_CYCLES_PER_SECOND: u64 = 4194304;
 pub const VERT_SYNC_RATE: f32 = 59.73;
 pub const CPU_CYCLES_PER_VBLANK: u64 = ((CPU_CYCLES_PER_SECOND as f32) / VERT_SYNC_RATE) as u64;
@@ -24,6 +22,9 @@ pub const FADE_DELAY: u64 = CPU_CYCLES_PER_VBLANK * 10;
 
 pub const FRAME_SLEEP: u64 = 1000 / 120;
 
+/// How often battery-backed cartridge RAM is written to disk (~5s)
+pub const SAVE_FILE_FLUSH_CYCLES: u64 = CPU_CYCLES_PER_SECOND * 5;
+
 // These are selected by $FF40 (LCDC) special register
 // Pixel data is stored here
 pub const TILE_PATTERN_TABLE_1_START: MemAddr = 0x8000;
@@ -63,6 +64,11 @@ pub const TILE_PALETTE: [Color; 4] = [Color::RGB(4, 5, 7),
                                       Color::RGB(235, 135, 140),
                                       Color::RGB(156, 146, 244),
                                       Color::RGB(252, 250, 175)];
+/// Shades of the emulated screen, lightest first
+pub const SCREEN_PALETTE: [Color; 4] = [Color::RGB(224, 248, 208),
+                                        Color::RGB(136, 192, 112),
+                                        Color::RGB(52, 104, 86),
+                                        Color::RGB(8, 24, 32)];
 pub const OBJECT_PALETTE: [Color; 4] = [Color::RGB(184, 248, 24),
                                         Color::RGB(174, 124, 9),
                                         Color::RGB(248, 184, 0),
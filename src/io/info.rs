@@ -0,0 +1,33 @@
+//! Printing what a ROM's header says about it without running it
+
+use std::fs::File;
+use std::io;
+use std::io::{Read, Write};
+
+use cpu::header::CartridgeHeader;
+
+/// Prints the header of `rom_file_name` and any problems found in it,
+/// returning the exit status: 0 for a good header, 1 if there were
+/// warnings and 2 if the ROM couldn't be read.  Errors go to stderr,
+/// this runs before logging is set up.
+pub fn print_rom_info(rom_file_name: &str) -> i32 {
+    let mut rom = vec![];
+    if let Err(e) = File::open(rom_file_name).and_then(|mut file| file.read_to_end(&mut rom)) {
+        let _ = writeln!(io::stderr(), "Could not read {}: {}", rom_file_name, e);
+        return 2;
+    }
+    let header = match CartridgeHeader::parse(&rom[..]) {
+        Ok(header) => header,
+        Err(e) => {
+            let _ = writeln!(io::stderr(), "{}: {}", rom_file_name, e);
+            return 2;
+        }
+    };
+
+    println!("{}", header);
+    let warnings = header.warnings();
+    for warning in &warnings {
+        println!("Warning: {}", warning);
+    }
+    if warnings.is_empty() { 0 } else { 1 }
+}
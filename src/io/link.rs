@@ -0,0 +1,247 @@
+//! Link cable between two emulators over a local socket
+//!
+//! Each byte clocked over the cable is sent as a frame of two bytes, a
+//! kind and the value.  The side using the internal clock sends a
+//! `FRAME_CLOCKED` frame and waits for the `FRAME_REPLY` frame holding
+//! the other side's SB.  Addresses starting with `unix:` are Unix
+//! socket paths, anything else is a TCP address such as
+//! `localhost:8765`.
+
+use std::fs::File;
+use std::io;
+use std::io::{Read, Write};
+use std::net::{TcpListener, TcpStream};
+#[cfg(unix)]
+use std::os::unix::net::{UnixListener, UnixStream};
+use std::time::{Duration, Instant};
+
+use cpu::constants::*;
+use cpu::serial::*;
+
+const FRAME_CLOCKED: u8 = 0;
+const FRAME_REPLY: u8 = 1;
+
+/// How long a transfer waits for the other side to answer before the
+/// cable is treated as unplugged
+const REPLY_TIMEOUT_MS: u64 = 500;
+
+const UNIX_PREFIX: &'static str = "unix:";
+
+/// Where the serial port is connected to, as given on the command line
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum LinkTarget<'a> {
+    /// Wait for another emulator to connect to this address
+    Listen(&'a str),
+    /// Connect to another emulator listening on this address
+    Connect(&'a str),
+    /// Write bytes sent over serial to this file, `-` for stdout
+    Output(&'a str),
+}
+
+/// Sets up the other end of the link cable
+pub fn open_link(target: LinkTarget) -> io::Result<Box<SerialLink>> {
+    match target {
+        LinkTarget::Listen(addr) => {
+            info!("Waiting for the other Game Boy to connect on {}", addr);
+            let link = match unix_path(addr) {
+                Some(path) => listen_unix(path)?,
+                None => {
+                    let (stream, peer) = TcpListener::bind(addr)?.accept()?;
+                    info!("Link cable connected to {}", peer);
+                    stream.set_nodelay(true)?;
+                    SocketLink::new(Box::new(stream))
+                }
+            };
+            Ok(Box::new(link))
+        }
+        LinkTarget::Connect(addr) => {
+            let link = match unix_path(addr) {
+                Some(path) => connect_unix(path)?,
+                None => {
+                    let stream = TcpStream::connect(addr)?;
+                    stream.set_nodelay(true)?;
+                    SocketLink::new(Box::new(stream))
+                }
+            };
+            info!("Link cable connected to {}", addr);
+            Ok(Box::new(link))
+        }
+        LinkTarget::Output("-") => Ok(Box::new(SerialSink::new(io::stdout()))),
+        LinkTarget::Output(path) => Ok(Box::new(SerialSink::new(File::create(path)?))),
+    }
+}
+
+fn unix_path(addr: &str) -> Option<&str> {
+    if addr.starts_with(UNIX_PREFIX) {
+        Some(&addr[UNIX_PREFIX.len()..])
+    } else {
+        None
+    }
+}
+
+#[cfg(unix)]
+fn listen_unix(path: &str) -> io::Result<SocketLink> {
+    let (stream, _) = UnixListener::bind(path)?.accept()?;
+    info!("Link cable connected");
+    Ok(SocketLink::new(Box::new(stream)))
+}
+
+#[cfg(unix)]
+fn connect_unix(path: &str) -> io::Result<SocketLink> {
+    Ok(SocketLink::new(Box::new(UnixStream::connect(path)?)))
+}
+
+#[cfg(not(unix))]
+fn listen_unix(_: &str) -> io::Result<SocketLink> {
+    Err(io::Error::new(io::ErrorKind::Other, "Unix sockets are not supported here"))
+}
+
+#[cfg(not(unix))]
+fn connect_unix(_: &str) -> io::Result<SocketLink> {
+    Err(io::Error::new(io::ErrorKind::Other, "Unix sockets are not supported here"))
+}
+
+/// The parts of TCP and Unix streams the link needs
+pub trait Socket: Read + Write {
+    fn set_read_timeout(&self, timeout: Option<Duration>) -> io::Result<()>;
+    fn set_nonblocking(&self, nonblocking: bool) -> io::Result<()>;
+}
+
+impl Socket for TcpStream {
+    fn set_read_timeout(&self, timeout: Option<Duration>) -> io::Result<()> {
+        TcpStream::set_read_timeout(self, timeout)
+    }
+
+    fn set_nonblocking(&self, nonblocking: bool) -> io::Result<()> {
+        TcpStream::set_nonblocking(self, nonblocking)
+    }
+}
+
+#[cfg(unix)]
+impl Socket for UnixStream {
+    fn set_read_timeout(&self, timeout: Option<Duration>) -> io::Result<()> {
+        UnixStream::set_read_timeout(self, timeout)
+    }
+
+    fn set_nonblocking(&self, nonblocking: bool) -> io::Result<()> {
+        UnixStream::set_nonblocking(self, nonblocking)
+    }
+}
+
+/// A link cable to another emulator
+pub struct SocketLink {
+    socket: Box<Socket + Send>,
+    /// Start of a frame which hasn't fully arrived yet
+    partial: Vec<u8>,
+    /// Cleared once the other side hangs up or the socket fails
+    connected: bool,
+}
+
+impl SocketLink {
+    pub fn new(socket: Box<Socket + Send>) -> SocketLink {
+        SocketLink {
+            socket: socket,
+            partial: vec![],
+            connected: true,
+        }
+    }
+
+    fn disconnect(&mut self, reason: &str) {
+        if self.connected {
+            error!("Link cable disconnected: {}", reason);
+            self.connected = false;
+        }
+    }
+
+    fn send_frame(&mut self, kind: u8, value: byte) {
+        if !self.connected {
+            return;
+        }
+        if let Err(e) = self.socket.write_all(&[kind, value]) {
+            self.disconnect(&e.to_string());
+        }
+    }
+
+    /// Reads the next frame, waiting until `deadline` for it to arrive
+    /// if given
+    fn read_frame(&mut self, deadline: Option<Instant>) -> Option<(u8, byte)> {
+        while self.connected && self.partial.len() < 2 {
+            let result = match deadline {
+                Some(deadline) => {
+                    let now = Instant::now();
+                    if now >= deadline {
+                        return None;
+                    }
+                    self.socket
+                        .set_nonblocking(false)
+                        .and_then(|_| self.socket.set_read_timeout(Some(deadline - now)))
+                }
+                None => self.socket.set_nonblocking(true),
+            };
+            if let Err(e) = result {
+                self.disconnect(&e.to_string());
+                return None;
+            }
+
+            let mut buffer = [0; 2];
+            let wanted = 2 - self.partial.len();
+            match self.socket.read(&mut buffer[..wanted]) {
+                Ok(0) => self.disconnect("the other side hung up"),
+                Ok(n) => self.partial.extend_from_slice(&buffer[..n]),
+                Err(ref e) if e.kind() == io::ErrorKind::WouldBlock ||
+                              e.kind() == io::ErrorKind::TimedOut => {
+                    if deadline.is_none() {
+                        return None;
+                    }
+                }
+                Err(ref e) if e.kind() == io::ErrorKind::Interrupted => (),
+                Err(e) => self.disconnect(&e.to_string()),
+            }
+        }
+        if self.partial.len() < 2 {
+            return None;
+        }
+        let frame = (self.partial[0], self.partial[1]);
+        self.partial.clear();
+        Some(frame)
+    }
+}
+
+impl SerialLink for SocketLink {
+    fn transfer(&mut self, value: byte) -> byte {
+        // A reply which arrived after its transfer timed out would be
+        // taken for the answer to this one
+        while let Some((kind, _)) = self.read_frame(None) {
+            if kind == FRAME_CLOCKED {
+                self.send_frame(FRAME_REPLY, 0xFF);
+            }
+        }
+        self.send_frame(FRAME_CLOCKED, value);
+        let deadline = Instant::now() + Duration::from_millis(REPLY_TIMEOUT_MS);
+        loop {
+            match self.read_frame(Some(deadline)) {
+                Some((FRAME_REPLY, received)) => return received,
+                Some(_) => {
+                    // Both sides used the internal clock at once, the
+                    // other one gets nothing back either
+                    self.send_frame(FRAME_REPLY, 0xFF);
+                }
+                None => return 0xFF,
+            }
+        }
+    }
+
+    fn clocked_by_peer(&mut self, reply: byte) -> Option<byte> {
+        loop {
+            match self.read_frame(None) {
+                Some((FRAME_CLOCKED, received)) => {
+                    self.send_frame(FRAME_REPLY, reply);
+                    return Some(received);
+                }
+                // A late reply to a transfer which already timed out
+                Some(_) => (),
+                None => return None,
+            }
+        }
+    }
+}
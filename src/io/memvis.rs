@@ -201,7 +201,7 @@ pub fn draw_memory_values(renderer: &mut sdl2::render::Renderer, gameboy: &Cpu)
     let mut x = 0;
     let mut y = 0;
 
-    for &p in gameboy.mem.iter() {
+    for p in gameboy.memory_snapshot() {
 
         use sdl2::pixels::*;
 
@@ -320,21 +320,30 @@ pub fn draw_memory_events(renderer: &mut sdl2::render::Renderer, gameboy: &mut C
 
     renderer.set_blend_mode(sdl2::render::BlendMode::Add);
     
-    let mut event_logger = match gameboy.event_logger {
-        Some(ref mut logger) => logger,
-        None => return,
-    };
+    {
+        let event_logger = match gameboy.event_logger {
+            Some(ref mut logger) => logger,
+            None => return,
+        };
 
-    // Remove events that are too old
-    while !event_logger.events_deq.is_empty() {
-        let timestamp = event_logger.events_deq.front().unwrap().timestamp;
-        if (Wrapping(gameboy.cycles) - Wrapping(timestamp)).0 >= FADE_DELAY {
-            event_logger.events_deq.pop_front();
-        } else {
-            break;
+        // Remove events that are too old
+        while !event_logger.events_deq.is_empty() {
+            let timestamp = event_logger.events_deq.front().unwrap().timestamp;
+            if (Wrapping(gameboy.cycles) - Wrapping(timestamp)).0 >= FADE_DELAY {
+                event_logger.events_deq.pop_front();
+            } else {
+                break;
+            }
         }
     }
 
+    // Memory is read while drawing
+    let gameboy = &*gameboy;
+    let event_logger = match gameboy.event_logger {
+        Some(ref logger) => logger,
+        None => return,
+    };
+
     // Draw current events with color determined by age
     for entry in &event_logger.events_deq {
         let timestamp = entry.timestamp;
@@ -346,7 +355,7 @@ pub fn draw_memory_events(renderer: &mut sdl2::render::Renderer, gameboy: &mut C
                 let colval = (time_norm * 255.0) as u8;
                 match *event {
                     CpuEvent::Read { from: addr } => {
-                        let val = gameboy.mem[addr as usize] as u8;
+                        let val = gameboy.peek(addr) as u8;
                         let (r, g, b) = mix_color(0, colval, 0, scale_col(colval, val / 2), 0, val);
                         renderer.set_draw_color(Color::RGB(r, g, b));
                         match renderer.draw_point(addr_to_point(addr)) {
@@ -355,7 +364,7 @@ pub fn draw_memory_events(renderer: &mut sdl2::render::Renderer, gameboy: &mut C
                         }
                     }
                     CpuEvent::Write { to: addr } => {
-                        let val = gameboy.mem[addr as usize] as u8;
+                        let val = gameboy.peek(addr) as u8;
                         let (r, g, b) = mix_color(colval, 0, 0, 0, scale_col(colval, val / 2), val);
                         renderer.set_draw_color(Color::RGB(r, g, b));
                         match renderer.draw_point(addr_to_point(addr)) {
@@ -364,7 +373,7 @@ pub fn draw_memory_events(renderer: &mut sdl2::render::Renderer, gameboy: &mut C
                         }
                     }
                     CpuEvent::Execute(addr) => {
-                        let val = gameboy.mem[addr as usize] as u8;
+                        let val = gameboy.peek(addr) as u8;
                         let (r, g, b) = mix_color(colval, colval, scale_col(colval, val), 0, 0, 0);
                         renderer.set_draw_color(Color::RGB(r, g, b));
                         match renderer.draw_point(addr_to_point(addr)) {
@@ -401,16 +410,15 @@ pub fn draw_memory_events(renderer: &mut sdl2::render::Renderer, gameboy: &mut C
 }
 
 fn print_address_info(pc: MemAddr, cpu: &Cpu) {
-    let pc = pc as usize;
-    let mem = cpu.mem;
-    let b1 = mem[pc + 1];
-    let b2 = mem[pc + 2];
-    let (mnem, _) = disasm::pp_opcode(mem[pc] as u8, b1 as u8, b2 as u8, pc as u16);
+    let b0 = cpu.peek(pc);
+    let b1 = cpu.peek(pc.wrapping_add(1));
+    let b2 = cpu.peek(pc.wrapping_add(2));
+    let (mnem, _) = disasm::pp_opcode(b0 as u8, b1 as u8, b2 as u8, pc);
     let nn = byte_to_u16(b1, b2);
     println!("${:04X} {:16} 0x{:02X} 0x{:02X} 0x{:02X} 0x{:04X}",
              pc,
              mnem,
-             mem[pc],
+             b0,
              b1,
              b2,
              nn);
@@ -6,6 +6,11 @@ pub mod input;
 pub mod graphics;
 pub mod memvis;
 pub mod vidram;
+pub mod screen;
 pub mod arguments;
+pub mod link;
+pub mod headless;
+pub mod info;
+pub mod png;
 pub mod events;
 pub mod applicationstate;
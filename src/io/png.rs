@@ -0,0 +1,74 @@
+//! Minimal PNG encoder for screenshots
+//!
+//! Writes 8 bit RGB images without compression, the image data is
+//! split into stored deflate blocks.
+
+use std::io;
+use std::io::Write;
+
+use checksum::{adler32, crc32};
+
+const PNG_SIGNATURE: &'static [u8] = b"\x89PNG\r\n\x1a\n";
+/// Largest stored deflate block
+const MAX_STORED_BLOCK: usize = 0xFFFF;
+
+/// Writes an image of `width`x`height` pixels given as RGB24 in `rgb`,
+/// row by row
+pub fn write_png<W: Write>(out: &mut W, width: u32, height: u32, rgb: &[u8]) -> io::Result<()> {
+    assert_eq!(rgb.len(), (width * height * 3) as usize);
+    out.write_all(PNG_SIGNATURE)?;
+
+    let mut header = vec![];
+    push_u32(&mut header, width);
+    push_u32(&mut header, height);
+    // Bit depth 8, color type RGB, default compression, filter and no
+    // interlacing
+    header.extend_from_slice(&[8, 2, 0, 0, 0]);
+    write_chunk(out, b"IHDR", &header[..])?;
+
+    // Every row starts with its filter type, 0 for none
+    let row_len = width as usize * 3;
+    let mut raw = Vec::with_capacity((row_len + 1) * height as usize);
+    for row in rgb.chunks(row_len) {
+        raw.push(0);
+        raw.extend_from_slice(row);
+    }
+    write_chunk(out, b"IDAT", &zlib_stored(&raw[..])[..])?;
+    write_chunk(out, b"IEND", &[])
+}
+
+fn push_u32(data: &mut Vec<u8>, value: u32) {
+    data.extend_from_slice(&[(value >> 24) as u8, (value >> 16) as u8, (value >> 8) as u8,
+                             value as u8]);
+}
+
+fn write_chunk<W: Write>(out: &mut W, kind: &[u8], data: &[u8]) -> io::Result<()> {
+    let mut chunk = vec![];
+    push_u32(&mut chunk, data.len() as u32);
+    chunk.extend_from_slice(kind);
+    chunk.extend_from_slice(data);
+    // The CRC covers the type and data, not the length
+    let crc = crc32(&chunk[4..]);
+    push_u32(&mut chunk, crc);
+    out.write_all(&chunk[..])
+}
+
+/// `data` as a zlib stream of uncompressed blocks
+fn zlib_stored(data: &[u8]) -> Vec<u8> {
+    // Deflate with a 32K window, no preset dictionary
+    let mut stream = vec![0x78, 0x01];
+    let mut blocks = data.chunks(MAX_STORED_BLOCK).peekable();
+    if blocks.peek().is_none() {
+        // An empty stream still needs a final block
+        stream.extend_from_slice(&[0x01, 0x00, 0x00, 0xFF, 0xFF]);
+    }
+    while let Some(block) = blocks.next() {
+        let last = blocks.peek().is_none();
+        let len = block.len() as u16;
+        stream.extend_from_slice(&[last as u8, len as u8, (len >> 8) as u8, !len as u8,
+                                   (!len >> 8) as u8]);
+        stream.extend_from_slice(block);
+    }
+    push_u32(&mut stream, adler32(data));
+    stream
+}
@@ -0,0 +1,64 @@
+//! The Gameboy's screen as rendered by the PPU
+
+use sdl2;
+use sdl2::rect::{Point, Rect};
+
+use cpu::Cpu;
+use cpu::cgb::rgb555_to_rgb888;
+use cpu::ppu::{SCREEN_WIDTH, SCREEN_HEIGHT};
+use io::constants::*;
+use io::graphics::Drawable;
+
+/// Bytes per pixel of `texture`
+pub const BYTES_PER_PIXEL: usize = 3;
+
+/// Fills `pixels` with the colors of the screen as RGB24, row by row
+pub fn screen_pixels(cpu: &Cpu, pixels: &mut [u8]) {
+    for i in 0..SCREEN_WIDTH * SCREEN_HEIGHT {
+        let (r, g, b) = if cpu.cgb_mode() {
+            rgb555_to_rgb888(cpu.cgb_frame_buffer()[i])
+        } else {
+            SCREEN_PALETTE[cpu.frame_buffer()[i] as usize].rgb()
+        };
+        pixels[i * BYTES_PER_PIXEL] = r;
+        pixels[i * BYTES_PER_PIXEL + 1] = g;
+        pixels[i * BYTES_PER_PIXEL + 2] = b;
+    }
+}
+
+pub struct ScreenDisplay {
+    /// RGB24 streaming texture of `SCREEN_WIDTH`x`SCREEN_HEIGHT`
+    pub texture: sdl2::render::Texture,
+    pixels: Vec<u8>,
+}
+
+impl ScreenDisplay {
+    pub fn new(texture: sdl2::render::Texture) -> ScreenDisplay {
+        ScreenDisplay {
+            texture: texture,
+            pixels: vec![0; SCREEN_WIDTH * SCREEN_HEIGHT * BYTES_PER_PIXEL],
+        }
+    }
+}
+
+impl Drawable for ScreenDisplay {
+    fn get_initial_size(&self) -> (u32, u32) {
+        (SCREEN_WIDTH as u32, SCREEN_HEIGHT as u32)
+    }
+
+    fn draw(&mut self, renderer: &mut sdl2::render::Renderer, cpu: &mut Cpu) {
+        screen_pixels(cpu, &mut self.pixels[..]);
+
+        let pitch = SCREEN_WIDTH * BYTES_PER_PIXEL;
+        match self.texture.update(None, &self.pixels[..], pitch) {
+            Ok(_) => (),
+            Err(_) => error!("Could not update screen texture"),
+        }
+        let dst_rect = Rect::new(0, 0, SCREEN_WIDTH as u32, SCREEN_HEIGHT as u32);
+        renderer.copy(&self.texture, None, Some(dst_rect)).unwrap();
+    }
+
+    fn click(&mut self, button: sdl2::mouse::MouseButton, position: Point, _: &mut Cpu) {
+        debug!("Clicked screen @ {:?} with {:?}", position, button);
+    }
+}
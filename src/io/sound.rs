@@ -1,71 +1,36 @@
 //! Everything for making sound play
+//!
+//! Sound is generated by `cpu::apu::Apu`, this only hands its samples
+//! to SDL.
 use sdl2;
-use sdl2::audio::{AudioCallback, AudioSpecDesired, AudioDevice};
+use sdl2::audio::{AudioQueue, AudioSpecDesired};
 
-/// Contains information for a single channel of audio
-///
-/// Envelopes and sweeps should be implemented as traits to allow this to
-/// remain generic
-pub struct Wave {
-    /// The amount by which the `phase` is changed at each callback
-    pub phase_inc: f32,
+use cpu::apu::APU_SAMPLE_RATE;
 
-    /// The "current" value of the wave
-    phase: f32,
+/// Samples are dropped when more than this many bytes are waiting to
+/// be played, to keep the latency down (~0.1s)
+pub const MAX_QUEUED_AUDIO_BYTES: u32 = APU_SAMPLE_RATE / 10 * 2 * 4;
 
-    /// Multiplier for wave between 0 and 1 (functions as volume (0 is off))
-    volume: f32,
-
-    /// TODO: document this
-    pub wave_duty: f32,
-
-    /// A flag indicating the direction the phase will be changed
-    pub add: bool,
-}
-
-impl AudioCallback for Wave {
-    type Channel = f32;
-
-    fn callback(&mut self, out: &mut [f32]) {
-        for x in out.iter_mut() {
-
-            *x = match self.phase {
-                v @ 0.0...1.0 if v <= self.wave_duty => v.sin() * self.volume,
-                _ => -self.volume,
-            };
-            self.phase = if self.add {
-                (self.phase + self.phase_inc)
-            } else {
-                (self.phase - self.phase_inc)
-            } % 1.0;
-        }
-    }
-}
-
-/// Creates a device from a context
-/// May have to be changed to allow each GB channel to have its own `Wave`
-pub fn setup_audio(sdl_context: &sdl2::Sdl) -> AudioDevice<Wave> {
+/// Creates a stereo queue for the samples produced by the APU
+pub fn setup_audio(sdl_context: &sdl2::Sdl) -> AudioQueue<f32> {
     // set up audio
     let audio_subsystem = sdl_context.audio().unwrap();
 
     let desired_spec = AudioSpecDesired {
-        freq: Some(44100),
-        channels: Some(1),
+        freq: Some(APU_SAMPLE_RATE as i32),
+        channels: Some(2),
         samples: None,
     };
 
-    audio_subsystem.open_playback(None, &desired_spec, |spec| {
-            // Show obtained AudioSpec
-            println!("{:?}", spec);
+    let queue = audio_subsystem.open_queue::<f32>(None, &desired_spec).unwrap();
+    debug!("Audio: {:?}", queue.spec());
+    queue.resume();
+    queue
+}
 
-            // initialize the audio callback
-            Wave {
-                phase_inc: 440.0 / spec.freq as f32,
-                phase: 0.0,
-                volume: 0.01,
-                wave_duty: 0.25,
-                add: true,
-            }
-        })
-        .unwrap()
+/// Queues samples unless too many are already waiting
+pub fn queue_samples(queue: &AudioQueue<f32>, samples: &[f32]) {
+    if queue.size() < MAX_QUEUED_AUDIO_BYTES {
+        queue.queue(samples);
+    }
 }
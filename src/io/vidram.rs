@@ -4,6 +4,8 @@ use sdl2;
 use io::constants::*;
 use cpu;
 use cpu::*;
+use cpu::constants::DISPLAY_RAM_START;
+use cpu::ppu::BG_ATTR_BANK;
 
 use sdl2::rect::Point;
 use sdl2::rect::Rect;
@@ -65,11 +67,14 @@ impl Drawable for VidRamBGDisplay {
 
 pub struct VidRamTileDisplay {
     pub tile_data_select: TileDataSelect,
+    /// VRAM bank shown, the second one only exists on the Game Boy
+    /// Color
+    pub bank: usize,
 }
 
 
 /// Display for tile data. Display tiles in `TILE_COLUMNS` with
-/// `BORDER_PX` spacing.
+/// `BORDER_PX` spacing.  Clicking switches between the VRAM banks.
 impl Drawable for VidRamTileDisplay {
     fn get_initial_size(&self) -> (u32, u32) {
         let cell_size = TILE_SIZE_PX + BORDER_PX;
@@ -79,18 +84,21 @@ impl Drawable for VidRamTileDisplay {
     }
     
     fn draw(&mut self, renderer: &mut sdl2::render::Renderer, cpu: &mut Cpu) {
-        draw_tile_patterns(renderer, cpu);
+        draw_tile_patterns(renderer, cpu, self.bank);
     }
     
     fn click(&mut self, button: sdl2::mouse::MouseButton, position: Point, _: &mut Cpu) {
         debug!("Clicked tile display @ {:?} with {:?}", position, button);
+        self.bank = (self.bank + 1) % 2;
+        debug!("Tile display VRAM bank: {}", self.bank);
     }
 }
 
 
-/// Draw single tile at given screen position
+/// Draw single tile from VRAM bank `bank` at given screen position
 pub fn draw_tile(renderer: &mut sdl2::render::Renderer,
                  gameboy: &Cpu,
+                 bank: usize,
                  mem_offset: u16,
                  tile_idx: u16, // technically when used by GB it's only 8bit
                  screen_offset_x: i32,
@@ -100,12 +108,13 @@ pub fn draw_tile(renderer: &mut sdl2::render::Renderer,
         (n >> (7 - offset)) & 1u8
     }
     
+    let vram = gameboy.vram.bank(bank);
     for px in 0..TILE_SIZE_PX {
         for py in 0..TILE_SIZE_PX {
             let col_byte_off = py * 2;
-            let offset = mem_offset + (tile_idx * TILE_SIZE_BYTES);
-            let col_byte1_v = gameboy.mem[(offset + col_byte_off) as usize];
-            let col_byte2_v = gameboy.mem[(offset + col_byte_off + 1) as usize];
+            let offset = mem_offset + (tile_idx * TILE_SIZE_BYTES) - TILE_PATTERN_TABLE_1_START;
+            let col_byte1_v = vram[(offset + col_byte_off) as usize];
+            let col_byte2_v = vram[(offset + col_byte_off + 1) as usize];
             let col_bit_1 = get_bit(col_byte1_v, px as u8);
             let col_bit_2 = get_bit(col_byte2_v, px as u8);
             let px_color = (col_bit_2 << 1) | col_bit_1;
@@ -127,55 +136,12 @@ pub fn draw_tile(renderer: &mut sdl2::render::Renderer,
     }
 }
 
-// TODO cache tiles into texture and use blending options (?) to make
-// desired color "trasnparent".
-/// Draw single tile at given screen position without drawing "0" color.
-pub fn draw_tile_transparent(renderer: &mut sdl2::render::Renderer,
-                             gameboy: &Cpu,
-                             mem_offset: u16,
-                             tile_idx: u16, // technically when used by GB it's only 8bit
-                             screen_offset_x: i32,
-                             screen_offset_y: i32) {
-
-    #[inline]
-    fn get_bit(n: u8, offset: u8) -> u8 {
-        (n >> (7 - offset)) & 1u8
-    }
-    
-    for px in 0..TILE_SIZE_PX {
-        for py in 0..TILE_SIZE_PX {
-            let col_byte_off = py * 2;
-            let offset = mem_offset + (tile_idx * TILE_SIZE_BYTES);
-            let col_byte1_v = gameboy.mem[(offset + col_byte_off) as usize];
-            let col_byte2_v = gameboy.mem[(offset + col_byte_off + 1) as usize];
-            let col_bit_1 = get_bit(col_byte1_v, px as u8);
-            let col_bit_2 = get_bit(col_byte2_v, px as u8);
-            let px_color = (col_bit_2 << 1) | col_bit_1;
-
-            // let d = 255/4;
-            // let px_val = px_color*d;
-            // renderer.set_draw_color(Color::RGB(px_val, px_val, px_val));
-
-            // TODO add toggle for this or remove it
-            const DEBUG_OAM_BG: bool = false;
-            
-            if DEBUG_OAM_BG || px_color != 0 {
-                let px_pal_col = OBJECT_PALETTE[px_color as usize];
-                renderer.set_draw_color(px_pal_col);
-                
-                let point = Point::new((screen_offset_x as u8).wrapping_add(px as u8) as i32,
-                                       (screen_offset_y as u8).wrapping_add(py as u8) as i32);
-                renderer.draw_point(point).unwrap();
-            }
-        }
-    }
-}
-
 /// This is the dumbest and straightforward code for displaying Tile
 /// Patterns. It displays both background and sprite "tiles" as they
 /// overlap in memory.
 pub fn draw_tile_patterns(renderer: &mut sdl2::render::Renderer,
-                          gameboy: &Cpu) {
+                          gameboy: &Cpu,
+                          bank: usize) {
 
     for tile_idx in 0..(TILE_PATTERN_TABLES_SIZE / TILE_SIZE_BYTES) + 1 {
 
@@ -185,6 +151,7 @@ pub fn draw_tile_patterns(renderer: &mut sdl2::render::Renderer,
 
         draw_tile(renderer,
                   gameboy,
+                  bank,
                   TILE_PATTERN_TABLE_1_START,
                   tile_idx,
                   tile_start_x as i32,
@@ -208,13 +175,15 @@ pub fn draw_background_buffer(renderer: &mut sdl2::render::Renderer,
     match tile_patterns_offset {
         TILE_PATTERN_TABLE_1_ORIGIN => {
             for tile in 0..(SCREEN_BUFFER_TILES_X * SCREEN_BUFFER_TILES_Y) {
-                let tile_index = gameboy.mem[(tile_map_offset + tile as u16) as usize];
+                let map_addr = (tile_map_offset + tile as u16) as usize;
+                let tile_index = gameboy.vram.bank(0)[map_addr - DISPLAY_RAM_START];
 
                 let tile_x = tile % SCREEN_BUFFER_TILES_X;
                 let tile_y = tile / SCREEN_BUFFER_TILES_Y;
 
                 draw_tile(renderer,
                           gameboy,
+                          tile_bank(gameboy, map_addr),
                           TILE_PATTERN_TABLE_1_START,
                           tile_index as u16, // use index as unsigned 8bit
                           screen_offset_x + (tile_x * TILE_SIZE_PX as u32) as i32,
@@ -225,13 +194,15 @@ pub fn draw_background_buffer(renderer: &mut sdl2::render::Renderer,
         },
         TILE_PATTERN_TABLE_2_ORIGIN => {
             for tile in 0..(SCREEN_BUFFER_TILES_X * SCREEN_BUFFER_TILES_Y) {
-                let tile_index = gameboy.mem[(tile_map_offset + tile as u16) as usize];
+                let map_addr = (tile_map_offset + tile as u16) as usize;
+                let tile_index = gameboy.vram.bank(0)[map_addr - DISPLAY_RAM_START];
                 
                 let tile_x = tile % SCREEN_BUFFER_TILES_X;
                 let tile_y = tile / SCREEN_BUFFER_TILES_Y;
 
                 draw_tile(renderer,
                           gameboy,
+                          tile_bank(gameboy, map_addr),
                           TILE_PATTERN_TABLE_2_START,             // reposition origin
                           add_u16_i8(128u16, (tile_index as i8)), // index is signed 8bit
                           screen_offset_x + (tile_x * TILE_SIZE_PX as u32) as i32,
@@ -248,6 +219,13 @@ pub fn draw_background_buffer(renderer: &mut sdl2::render::Renderer,
 }
 
 
+/// VRAM bank holding the tile at `map_addr` in the tile map, as given
+/// by its attributes in CGB mode
+fn tile_bank(gameboy: &Cpu, map_addr: usize) -> usize {
+    if gameboy.bg_attributes(map_addr) & BG_ATTR_BANK != 0 { 1 } else { 0 }
+}
+
+
 /// Draw rectangle showing values of SCX and SCY registers,
 /// i.e. visible screen area.
 fn draw_screen_border(renderer: &mut sdl2::render::Renderer,
@@ -278,61 +256,36 @@ fn draw_screen_border(renderer: &mut sdl2::render::Renderer,
 }
 
 
-/// Draw "sprites" (something gameboy calls "Objects").
+/// Draw "sprites" (something gameboy calls "Objects") as they
+/// appear on the screen, which is at the given offset in the
+/// background buffer.
 pub fn draw_objects(renderer: &mut sdl2::render::Renderer,
                     gameboy: &Cpu,
                     screen_offset_x: i32,
                     screen_offset_y: i32) {
+    if !gameboy.lcdc_sprite_display() {
+        return;
+    }
 
-    // TODO sprites should use palletes
-    // TODO sprites can have transparent color
-    // TODO sprites are sorted by x/y/idx
-    
-    for obj_idx in 0..40+1 {
-        let offset = OBJECT_ATTRIBUTE_START + obj_idx * OBJECT_ATTRIBUTE_BLOCK_SIZE;
-        let sprite_y: u8 = gameboy.mem[offset as usize];
-        let sprite_x: u8 = gameboy.mem[offset as usize + 1];
-        let tile_index: u8 = gameboy.mem[offset as usize + 2];
-        // TODO implement flag handling (priority, flips...)
-        let flags: u8 = gameboy.mem[offset as usize + 3];
-        if sprite_x == 0 && sprite_y == 0 {
-            // sprite is "hidden"
-            continue
-        }
-        let screen_x = sprite_x.wrapping_sub(8);
-        let screen_y = sprite_y.wrapping_sub(16);
+    for ly in 0..ppu::SCREEN_HEIGHT {
+        let bg_y = (screen_offset_y as u8).wrapping_add(ly as u8);
+        let pixels = gameboy.object_line(ly);
 
-        // This table is fixed for OAM
-        let pattern_table = TILE_PATTERN_TABLE_1_START;
-        
-        if gameboy.lcdc_sprite_size() {
-            // "Tall 8x16 sprites" mode
-            // FIXME Not sure this is how 8x16 sprites work
-            let tile_16 = tile_index & !1;
-            draw_tile_transparent(renderer,
-                                  gameboy,
-                                  pattern_table,
-                                  tile_16 as u16,
-                                  screen_offset_x + screen_x as i32,
-                                  screen_offset_y + screen_y as i32);
-            // Draw second sprite below the first one
-            draw_tile_transparent(renderer,
-                                  gameboy,
-                                  pattern_table,
-                                  tile_16 as u16 + 1,
-                                  screen_offset_x + screen_x as i32,
-                                  screen_offset_y + screen_y.wrapping_add(8) as i32);
-        } else {
-            // 8x8 sprites mode
-            draw_tile_transparent(renderer,
-                                  gameboy,
-                                  pattern_table,
-                                  tile_index as u16,
-                                  screen_offset_x + screen_x as i32,
-                                  screen_offset_y + screen_y as i32);
+        for (x, pixel) in pixels.iter().enumerate() {
+            if let Some(pixel) = *pixel {
+                let bg_x = (screen_offset_x as u8).wrapping_add(x as u8);
+                if pixel.behind_bg && gameboy.lcdc_bg_win_display() &&
+                   gameboy.bg_color_at(bg_x, bg_y) != 0 {
+                    continue;
+                }
 
+                renderer.set_draw_color(OBJECT_PALETTE[pixel.shade as usize]);
+                let point = Point::new(bg_x as i32, bg_y as i32);
+                match renderer.draw_point(point) {
+                    Ok(_) => (),
+                    Err(_) => error!("Could not draw point at {:?}", point),
+                };
+            }
         }
-        
     }
-       
 }
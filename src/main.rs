@@ -15,14 +15,19 @@ extern crate log;
 extern crate log4rs;
 extern crate sdl2;
 extern crate ncurses;
+extern crate lalrpop_util;
 
 /// Simple Gameboy-flavored Z80 assembler
 pub mod assembler;
 
+/// Checksums shared by save states and screenshots
+pub mod checksum;
+
 /// The bulk of the hardware emulation
 pub mod cpu;
 
-/// Ncurses-based text debugger and parser for debugging language
+/// Ncurses-based text debugger and parser for debugging language, and a
+/// GDB remote stub
 pub mod debugger;
 
 /// Naive disassembler
@@ -42,9 +47,51 @@ fn main() {
     let rom_file = arguments.value_of("game").expect("Could not open specified rom");
     let debug_mode = arguments.is_present("debug");
     let trace_mode = arguments.is_present("trace");
+    let boot_rom = arguments.value_of("boot-rom");
+    let timing_mode = if arguments.is_present("per-access-timing") {
+        cpu::constants::TimingMode::PerAccess
+    } else {
+        cpu::constants::TimingMode::PerInstruction
+    };
+
+    if arguments.is_present("info") {
+        std::process::exit(io::info::print_rom_info(rom_file));
+    }
+
+    if arguments.is_present("headless") {
+        // Validated by clap
+        let frames = arguments.value_of("frames").map(|n| n.parse().unwrap());
+        let cycles = arguments.value_of("cycles").map(|n| n.parse().unwrap());
+        let status = io::headless::run_headless(trace_mode,
+                                                rom_file,
+                                                boot_rom,
+                                                timing_mode,
+                                                frames,
+                                                cycles,
+                                                arguments.value_of("png"));
+        std::process::exit(status);
+    }
+
+    // Validated by clap
+    let gdb_port = arguments.value_of("gdb").map(|port| port.parse().unwrap());
+
+    let link = if let Some(addr) = arguments.value_of("link-listen") {
+        Some(io::link::LinkTarget::Listen(addr))
+    } else if let Some(addr) = arguments.value_of("link-connect") {
+        Some(io::link::LinkTarget::Connect(addr))
+    } else {
+        arguments.value_of("serial-out").map(io::link::LinkTarget::Output)
+    };
 
     // Set up gameboy and app state
-    let mut appstate = ApplicationState::new(trace_mode, debug_mode, rom_file);
+    let mut appstate = ApplicationState::new(trace_mode,
+                                             debug_mode,
+                                             rom_file,
+                                             boot_rom,
+                                             timing_mode,
+                                             link,
+                                             gdb_port,
+                                             arguments.value_of("symbols"));
 /*
     let mut scale = SCALE;
 